@@ -39,10 +39,11 @@ fn main() {
             .atleast_version(MINIMUM_VERSION)
             .probe("tss2-sys")
             .expect("Failed to find tss2-sys library.");
-        pkg_config::Config::new()
+        let tss2_esys = pkg_config::Config::new()
             .atleast_version(MINIMUM_VERSION)
             .probe("tss2-esys")
             .expect("Failed to find tss2-esys library.");
+        export_version(&tss2_esys.version);
         pkg_config::Config::new()
             .atleast_version(MINIMUM_VERSION)
             .probe("tss2-tctildr")
@@ -79,6 +80,7 @@ pub fn generate_from_system(esapi_out: PathBuf) {
         '3' => println!("cargo:rustc-cfg=tpm2_tss_version=\"3\""),
         major => panic!("Unsupported TSS version: {}", major),
     }
+    export_version(&tss2_esys.version);
 
     // These three pkg-config files should contain only one include/lib path.
     let tss2_esys_include_path = tss2_esys.include_paths[0]
@@ -114,3 +116,9 @@ pub fn generate_from_system(esapi_out: PathBuf) {
         .write_to_file(esapi_out)
         .expect("Couldn't write ESYS bindings!");
 }
+
+/// Exports the version of the tss2-esys library to the crates depending
+/// on this one, through the `DEP_TSS2_ESYS_VERSION` environment variable.
+fn export_version(version: &str) {
+    println!("cargo:version={}", version);
+}
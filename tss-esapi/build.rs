@@ -0,0 +1,17 @@
+// Copyright 2021 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+
+fn main() {
+    println!("cargo:rustc-check-cfg=cfg(tpm2_tss_version, values(\"2\", \"3\"))");
+    println!("cargo:rerun-if-env-changed=DEP_TSS2_ESYS_VERSION");
+
+    // The version of the tss2-esys library is exported by tss-esapi-sys,
+    // which is the crate linking to it, through its `links` metadata.
+    if let Ok(version) = std::env::var("DEP_TSS2_ESYS_VERSION") {
+        match version.chars().next() {
+            Some('2') => println!("cargo:rustc-cfg=tpm2_tss_version=\"2\""),
+            Some('3') => println!("cargo:rustc-cfg=tpm2_tss_version=\"3\""),
+            _ => panic!("Unsupported TSS version: {}", version),
+        }
+    }
+}
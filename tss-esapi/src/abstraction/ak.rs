@@ -16,7 +16,7 @@ use crate::{
         session_handles::PolicySession,
     },
     structures::{
-        Auth, CreateKeyResult, EccScheme, KeyDerivationFunctionScheme, Private, Public,
+        Auth, CreateKeyResult, EccPoint, EccScheme, KeyDerivationFunctionScheme, Private, Public,
         PublicBuilder, PublicEccParametersBuilder, PublicKeyRsa, PublicRsaParametersBuilder,
         RsaExponent, RsaScheme, SymmetricDefinitionObject,
     },
@@ -81,10 +81,18 @@ fn create_ak_public<IKC: IntoKeyCustomization>(
                         Some(hash_alg),
                         Some(0),
                     )?)
-                    .with_curve(EccCurve::NistP192)
+                    .with_curve(match sign_alg {
+                        // The SM2 signatures are defined over the SM2 curve.
+                        SignatureSchemeAlgorithm::Sm2 => EccCurve::Sm2P256,
+                        _ => EccCurve::NistP256,
+                    })
                     .with_key_derivation_function_scheme(KeyDerivationFunctionScheme::Null)
+                    .with_is_signing_key(obj_attrs.sign_encrypt())
+                    .with_is_decryption_key(obj_attrs.decrypt())
+                    .with_restricted(obj_attrs.restricted())
                     .build()?,
             )
+            .with_ecc_unique_identifier(&EccPoint::default())
             .build(),
         AsymmetricAlgorithm::Null => {
             // TODO: Figure out what to with Null.
@@ -94,6 +102,16 @@ fn create_ak_public<IKC: IntoKeyCustomization>(
 }
 
 /// This loads an Attestation Key previously generated under the Endorsement hierarchy
+///
+/// # Arguments
+/// * `parent` - The handle of the Endorsement Key the AK was created under.
+/// * `ak_auth_value` - The optional authorization value of the AK, set on the loaded key.
+/// * `private` - The private area of the AK, as returned by [create_ak].
+/// * `public` - The public area of the AK, as returned by [create_ak].
+///
+/// # Details
+/// The policy of the Endorsement Key is satisfied with `TPM2_PolicySecret(TPM_RH_ENDORSEMENT)`
+/// in a policy session which is flushed once the AK has been loaded.
 pub fn load_ak(
     context: &mut Context,
     parent: KeyHandle,
@@ -150,6 +168,26 @@ pub fn load_ak(
 }
 
 /// This creates an Attestation Key in the Endorsement hierarchy
+///
+/// # Arguments
+/// * `parent` - The handle of the Endorsement Key, used as the parent of the AK.
+/// * `hash_alg` - The name hashing algorithm of the AK, also used in its signing scheme.
+/// * `sign_alg` - The signing scheme of the AK, which determines the type of the key
+///   (RSA 2048 bits, or ECC NIST P-256 and SM2 P-256 for the SM2 scheme).
+/// * `ak_auth_value` - The optional authorization value of the AK.
+/// * `key_customization` - Customization of the attributes and template of the AK.
+///
+/// # Details
+/// The AK is a restricted signing key, that can be used for quoting or certifying.
+/// The policy of the Endorsement Key is satisfied with `TPM2_PolicySecret(TPM_RH_ENDORSEMENT)`
+/// in a policy session which is flushed once the AK has been created.
+///
+/// The AK is not loaded: [load_ak] has to be called with the returned public and
+/// private areas, the name of the AK is then available through `Context::read_public`.
+///
+/// # Errors
+/// * if the signing scheme is not an RSA or ECC scheme, an `InvalidParam`
+///   wrapper error is returned.
 pub fn create_ak<IKC: IntoKeyCustomization>(
     context: &mut Context,
     parent: KeyHandle,
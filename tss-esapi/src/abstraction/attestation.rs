@@ -0,0 +1,251 @@
+// Copyright 2021 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Module for processing the attestation structures produced by the TPM.
+#[cfg(feature = "verify-quote")]
+use crate::{
+    interface_types::{algorithm::HashingAlgorithm, ecc::EccCurve},
+    structures::{EccSignature, Public, RsaSignature, Signature},
+    traits::Marshall,
+};
+use crate::{
+    structures::{Attest, AttestInfo, ClockInfo, Data, Digest, Name, PcrSelectionList},
+    Error, Result, WrapperErrorKind,
+};
+use log::error;
+#[cfg(feature = "verify-quote")]
+use p256::ecdsa::{signature::hazmat::PrehashVerifier, Signature as P256Signature, VerifyingKey};
+#[cfg(feature = "verify-quote")]
+use rsa::{BigUint, Pkcs1v15Sign, Pss, RsaPublicKey};
+#[cfg(feature = "verify-quote")]
+use sha2::{Digest as _, Sha256, Sha384, Sha512};
+
+/// The contents of a quote.
+///
+/// # Details
+/// Collects the fields of the `TPMS_ATTEST` produced by
+/// `TPM2_Quote` together with the attested `TPMS_QUOTE_INFO`.
+#[derive(Debug, Clone)]
+pub struct QuoteInfo {
+    qualified_signer: Name,
+    extra_data: Data,
+    clock_info: ClockInfo,
+    firmware_version: u64,
+    pcr_select: PcrSelectionList,
+    pcr_digest: Digest,
+}
+
+impl QuoteInfo {
+    /// Returns the qualified name of the key that signed the quote.
+    pub const fn qualified_signer(&self) -> &Name {
+        &self.qualified_signer
+    }
+
+    /// Returns the qualifying data provided by the caller of the quote.
+    pub const fn extra_data(&self) -> &Data {
+        &self.extra_data
+    }
+
+    /// Returns the clock information of the TPM at the time of the quote.
+    pub const fn clock_info(&self) -> &ClockInfo {
+        &self.clock_info
+    }
+
+    /// Returns the firmware version of the TPM.
+    pub const fn firmware_version(&self) -> u64 {
+        self.firmware_version
+    }
+
+    /// Returns the selection of the quoted PCRs.
+    pub const fn pcr_select(&self) -> &PcrSelectionList {
+        &self.pcr_select
+    }
+
+    /// Returns the digest of the concatenated values of the quoted PCRs.
+    pub const fn pcr_digest(&self) -> &Digest {
+        &self.pcr_digest
+    }
+}
+
+/// Extracts the contents of a quote from the attestation structure.
+///
+/// # Errors
+/// * if the attestation structure was not produced by a quote, an
+///   `InvalidParam` wrapper error is returned
+pub fn parse_quote(attest: &Attest) -> Result<QuoteInfo> {
+    match attest.attested() {
+        AttestInfo::Quote { info } => Ok(QuoteInfo {
+            qualified_signer: attest.qualified_signer().clone(),
+            extra_data: attest.extra_data().clone(),
+            clock_info: *attest.clock_info(),
+            firmware_version: attest.firmware_version(),
+            pcr_select: info.pcr_selection().clone(),
+            pcr_digest: info.pcr_digest().clone(),
+        }),
+        _ => {
+            error!(
+                "Expected a quote, found attestation type {:?}",
+                attest.attestation_type()
+            );
+            Err(Error::local_error(WrapperErrorKind::InvalidParam))
+        }
+    }
+}
+
+/// Verifies the signature of a quote without using the TPM.
+///
+/// # Details
+/// The qualifying data of the quote is compared to the `nonce`,
+/// then the signature of the marshalled `TPMS_ATTEST` is verified
+/// with the public key. RSASSA, RSAPSS and NIST P-256 ECDSA
+/// signatures using SHA-256, SHA-384 or SHA-512 are supported.
+///
+/// Returns `false` if the signature is not valid for the quote.
+///
+/// # Errors
+/// * if the attestation structure was not produced by a quote, an
+///   `InvalidParam` wrapper error is returned
+/// * if the qualifying data of the quote does not match the nonce, an
+///   `InconsistentParams` wrapper error is returned
+/// * if the key or the signature scheme is not supported, an
+///   `UnsupportedParam` wrapper error is returned
+#[cfg(feature = "verify-quote")]
+pub fn verify_quote(
+    public: &Public,
+    attest: &Attest,
+    signature: &Signature,
+    nonce: &Data,
+) -> Result<bool> {
+    let _ = parse_quote(attest)?;
+    if attest.extra_data() != nonce {
+        error!("The qualifying data of the quote does not match the nonce");
+        return Err(Error::local_error(WrapperErrorKind::InconsistentParams));
+    }
+    let message = attest.marshall()?;
+
+    match (public, signature) {
+        (Public::Rsa { .. }, Signature::RsaSsa(rsa_signature)) => {
+            verify_rsa_signature(public, &message, rsa_signature, false)
+        }
+        (Public::Rsa { .. }, Signature::RsaPss(rsa_signature)) => {
+            verify_rsa_signature(public, &message, rsa_signature, true)
+        }
+        (
+            Public::Ecc {
+                parameters, unique, ..
+            },
+            Signature::EcDsa(ecc_signature),
+        ) if parameters.ecc_curve() == EccCurve::NistP256 => {
+            let mut sec1_point = vec![0x04];
+            sec1_point.extend(left_pad(unique.x().value(), 32)?);
+            sec1_point.extend(left_pad(unique.y().value(), 32)?);
+            let verifying_key = VerifyingKey::from_sec1_bytes(&sec1_point).map_err(|e| {
+                error!("Invalid ECC public key: {}", e);
+                Error::local_error(WrapperErrorKind::InvalidParam)
+            })?;
+            verify_ecdsa_p256_signature(&verifying_key, &message, ecc_signature)
+        }
+        _ => {
+            error!(
+                "Verifying {:?} signatures with the provided key is not supported",
+                signature.algorithm()
+            );
+            Err(Error::local_error(WrapperErrorKind::UnsupportedParam))
+        }
+    }
+}
+
+#[cfg(feature = "verify-quote")]
+fn verify_rsa_signature(
+    public: &Public,
+    message: &[u8],
+    rsa_signature: &RsaSignature,
+    pss: bool,
+) -> Result<bool> {
+    let (modulus, exponent) = match public {
+        Public::Rsa {
+            parameters, unique, ..
+        } => (unique.value(), parameters.exponent().value()),
+        _ => return Err(Error::local_error(WrapperErrorKind::InvalidParam)),
+    };
+    // An exponent of zero is the shorthand for the default exponent.
+    let exponent = if exponent == 0 { 65537 } else { exponent };
+    let public_key = RsaPublicKey::new(BigUint::from_bytes_be(modulus), BigUint::from(exponent))
+        .map_err(|e| {
+            error!("Invalid RSA public key: {}", e);
+            Error::local_error(WrapperErrorKind::InvalidParam)
+        })?;
+    let hashing_algorithm = rsa_signature.hashing_algorithm();
+    let hashed = hash(hashing_algorithm, message)?;
+    let signature = rsa_signature.signature().value();
+    let result = match (hashing_algorithm, pss) {
+        (HashingAlgorithm::Sha256, false) => {
+            public_key.verify(Pkcs1v15Sign::new::<Sha256>(), &hashed, signature)
+        }
+        (HashingAlgorithm::Sha384, false) => {
+            public_key.verify(Pkcs1v15Sign::new::<Sha384>(), &hashed, signature)
+        }
+        (HashingAlgorithm::Sha512, false) => {
+            public_key.verify(Pkcs1v15Sign::new::<Sha512>(), &hashed, signature)
+        }
+        (HashingAlgorithm::Sha256, true) => {
+            public_key.verify(Pss::new::<Sha256>(), &hashed, signature)
+        }
+        (HashingAlgorithm::Sha384, true) => {
+            public_key.verify(Pss::new::<Sha384>(), &hashed, signature)
+        }
+        (HashingAlgorithm::Sha512, true) => {
+            public_key.verify(Pss::new::<Sha512>(), &hashed, signature)
+        }
+        _ => return Err(Error::local_error(WrapperErrorKind::UnsupportedParam)),
+    };
+    Ok(result.is_ok())
+}
+
+#[cfg(feature = "verify-quote")]
+fn verify_ecdsa_p256_signature(
+    verifying_key: &VerifyingKey,
+    message: &[u8],
+    ecc_signature: &EccSignature,
+) -> Result<bool> {
+    let hashed = hash(ecc_signature.hashing_algorithm(), message)?;
+    let mut r = p256::FieldBytes::default();
+    r.copy_from_slice(&left_pad(ecc_signature.signature_r().value(), 32)?);
+    let mut s = p256::FieldBytes::default();
+    s.copy_from_slice(&left_pad(ecc_signature.signature_s().value(), 32)?);
+    let signature = match P256Signature::from_scalars(r, s) {
+        Ok(signature) => signature,
+        // Scalars out of range can never form a valid signature.
+        Err(_) => return Ok(false),
+    };
+    Ok(verifying_key.verify_prehash(&hashed, &signature).is_ok())
+}
+
+/// Hashes the message in software.
+#[cfg(feature = "verify-quote")]
+fn hash(hashing_algorithm: HashingAlgorithm, message: &[u8]) -> Result<Vec<u8>> {
+    match hashing_algorithm {
+        HashingAlgorithm::Sha256 => Ok(Sha256::digest(message).to_vec()),
+        HashingAlgorithm::Sha384 => Ok(Sha384::digest(message).to_vec()),
+        HashingAlgorithm::Sha512 => Ok(Sha512::digest(message).to_vec()),
+        _ => {
+            error!(
+                "Hashing algorithm {:?} is not supported for verifying quotes",
+                hashing_algorithm
+            );
+            Err(Error::local_error(WrapperErrorKind::UnsupportedParam))
+        }
+    }
+}
+
+/// Left pads the big endian value with zeros up to the size.
+#[cfg(feature = "verify-quote")]
+fn left_pad(value: &[u8], size: usize) -> Result<Vec<u8>> {
+    if value.len() > size {
+        error!("Value is larger than {} bytes", size);
+        return Err(Error::local_error(WrapperErrorKind::InvalidParam));
+    }
+    let mut padded = vec![0; size - value.len()];
+    padded.extend_from_slice(value);
+    Ok(padded)
+}
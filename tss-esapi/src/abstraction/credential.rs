@@ -0,0 +1,301 @@
+// Copyright 2021 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Module for creating credentials without a TPM.
+use crate::{
+    interface_types::{
+        algorithm::{HashingAlgorithm, SymmetricMode},
+        ecc::EccCurve,
+        key_bits::AesKeyBits,
+    },
+    structures::{Digest, EncryptedSecret, IDObject, Name, Public, SymmetricDefinitionObject},
+    Error, Result, WrapperErrorKind,
+};
+use aes::{Aes128, Aes192, Aes256};
+use cfb_mode::{
+    cipher::{AsyncStreamCipher, BlockCipher, BlockEncryptMut, KeyInit, KeyIvInit},
+    Encryptor,
+};
+use hmac::{Mac, SimpleHmac};
+use log::error;
+use p256::{
+    elliptic_curve::{point::AffineCoordinates, sec1::ToEncodedPoint},
+    PublicKey, SecretKey,
+};
+use rand_core::{OsRng, RngCore};
+use rsa::{BigUint, Oaep, RsaPublicKey};
+use sha2::{
+    digest::{self, core_api::BlockSizeUser, DynDigest},
+    Sha256, Sha384, Sha512,
+};
+use std::convert::TryFrom;
+
+/// Size in bytes of the NIST P-256 coordinates.
+const P256_COORDINATE_SIZE: usize = 32;
+
+/// Creates a credential for activation with `TPM2_ActivateCredential`, without a TPM.
+///
+/// # Arguments
+/// * `public` - The public area of the key protecting the credential, usually the
+///   Endorsement Key.
+/// * `credential` - The credential, which has to fit in a [Digest].
+/// * `object_name` - The name of the object the credential is bound to, usually
+///   an Attestation Key.
+///
+/// # Details
+/// This performs the same computations as `TPM2_MakeCredential`, as described in
+/// the section 24 of the part 1 of the TPM 2.0 specification, so that a
+/// credential can be issued by a party that does not have a TPM.
+///
+/// The protecting key has to be a restricted decryption key, either an RSA key
+/// or a NIST P-256 key, with a SHA-256, SHA-384 or SHA-512 name hashing
+/// algorithm and an AES CFB symmetric definition.
+///
+/// # Errors
+/// * if the credential does not fit in a [Digest], a `WrongParamSize` wrapper
+///   error is returned.
+/// * if the protecting key is not a restricted decryption key, an `InvalidParam`
+///   wrapper error is returned.
+/// * if the algorithms of the protecting key are not supported, an
+///   `UnsupportedParam` wrapper error is returned.
+pub fn make_credential_local(
+    public: &Public,
+    credential: &[u8],
+    object_name: &Name,
+) -> Result<(IDObject, EncryptedSecret)> {
+    if credential.len() > Digest::MAX_SIZE {
+        error!(
+            "The credential is too large ({} bytes), the maximum is {} bytes",
+            credential.len(),
+            Digest::MAX_SIZE
+        );
+        return Err(Error::local_error(WrapperErrorKind::WrongParamSize));
+    }
+    let object_attributes = public.object_attributes();
+    if !object_attributes.restricted() || !object_attributes.decrypt() {
+        error!("The key protecting the credential is not a restricted decryption key");
+        return Err(Error::local_error(WrapperErrorKind::InvalidParam));
+    }
+    match public.name_hashing_algorithm() {
+        HashingAlgorithm::Sha256 => make_credential::<Sha256>(public, credential, object_name),
+        HashingAlgorithm::Sha384 => make_credential::<Sha384>(public, credential, object_name),
+        HashingAlgorithm::Sha512 => make_credential::<Sha512>(public, credential, object_name),
+        hashing_algorithm => {
+            error!(
+                "Hashing algorithm {:?} is not supported for making credentials",
+                hashing_algorithm
+            );
+            Err(Error::local_error(WrapperErrorKind::UnsupportedParam))
+        }
+    }
+}
+
+/// Makes the credential with `D` being the name hashing algorithm of the protecting key.
+fn make_credential<D>(
+    public: &Public,
+    credential: &[u8],
+    object_name: &Name,
+) -> Result<(IDObject, EncryptedSecret)>
+where
+    D: digest::Digest + DynDigest + BlockSizeUser + Clone + Send + Sync + 'static,
+{
+    let (seed, secret) = create_seed::<D>(public)?;
+
+    // The symmetric key protecting the credential is bound to the name of the object.
+    let symmetric = match public {
+        Public::Rsa { parameters, .. } => parameters.symmetric_definition_object(),
+        Public::Ecc { parameters, .. } => parameters.symmetric_definition_object(),
+        _ => return Err(Error::local_error(WrapperErrorKind::InvalidParam)),
+    };
+    let mut identity = (credential.len() as u16).to_be_bytes().to_vec();
+    identity.extend_from_slice(credential);
+    match symmetric {
+        SymmetricDefinitionObject::Aes {
+            key_bits,
+            mode: SymmetricMode::Cfb,
+        } => {
+            let key_size = match key_bits {
+                AesKeyBits::Aes128 => 16,
+                AesKeyBits::Aes192 => 24,
+                AesKeyBits::Aes256 => 32,
+            };
+            let key = kdfa::<D>(&seed, b"STORAGE", object_name.value(), &[], key_size);
+            match key_bits {
+                AesKeyBits::Aes128 => cfb_encrypt::<Aes128>(&key, &mut identity),
+                AesKeyBits::Aes192 => cfb_encrypt::<Aes192>(&key, &mut identity),
+                AesKeyBits::Aes256 => cfb_encrypt::<Aes256>(&key, &mut identity),
+            }
+        }
+        _ => {
+            error!("Only AES CFB symmetric definitions are supported for making credentials");
+            return Err(Error::local_error(WrapperErrorKind::UnsupportedParam));
+        }
+    }
+
+    // The integrity of the encrypted credential is protected by an outer HMAC.
+    let hmac_key = kdfa::<D>(
+        &seed,
+        b"INTEGRITY",
+        &[],
+        &[],
+        <D as digest::Digest>::output_size(),
+    );
+    let mut hmac =
+        <SimpleHmac<D> as Mac>::new_from_slice(&hmac_key).expect("HMAC can take keys of any size");
+    hmac.update(&identity);
+    hmac.update(object_name.value());
+    let integrity = hmac.finalize().into_bytes();
+
+    let mut id_object = (integrity.len() as u16).to_be_bytes().to_vec();
+    id_object.extend_from_slice(&integrity);
+    id_object.extend_from_slice(&identity);
+    Ok((
+        IDObject::try_from(id_object)?,
+        EncryptedSecret::try_from(secret)?,
+    ))
+}
+
+/// Creates the seed and the secret protecting it with the key.
+fn create_seed<D>(public: &Public) -> Result<(Vec<u8>, Vec<u8>)>
+where
+    D: digest::Digest + DynDigest + Send + Sync + 'static,
+{
+    match public {
+        Public::Rsa {
+            parameters, unique, ..
+        } => {
+            let exponent = parameters.exponent().value();
+            // An exponent of zero is the shorthand for the default exponent.
+            let exponent = if exponent == 0 { 65537 } else { exponent };
+            let public_key = RsaPublicKey::new(
+                BigUint::from_bytes_be(unique.value()),
+                BigUint::from(exponent),
+            )
+            .map_err(|e| {
+                error!("Invalid RSA public key: {}", e);
+                Error::local_error(WrapperErrorKind::InvalidParam)
+            })?;
+            let mut seed = vec![0u8; <D as digest::Digest>::output_size()];
+            OsRng.fill_bytes(&mut seed);
+            let secret = public_key
+                .encrypt(
+                    &mut OsRng,
+                    Oaep::new_with_label::<D, _>("IDENTITY\0"),
+                    &seed,
+                )
+                .map_err(|e| {
+                    error!("Failed to encrypt the seed: {}", e);
+                    Error::local_error(WrapperErrorKind::InvalidParam)
+                })?;
+            Ok((seed, secret))
+        }
+        Public::Ecc {
+            parameters, unique, ..
+        } if parameters.ecc_curve() == EccCurve::NistP256 => {
+            let ek_x = left_pad(unique.x().value(), P256_COORDINATE_SIZE)?;
+            let mut sec1_point = vec![0x04];
+            sec1_point.extend_from_slice(&ek_x);
+            sec1_point.extend(left_pad(unique.y().value(), P256_COORDINATE_SIZE)?);
+            let public_key = PublicKey::from_sec1_bytes(&sec1_point).map_err(|e| {
+                error!("Invalid ECC public key: {}", e);
+                Error::local_error(WrapperErrorKind::InvalidParam)
+            })?;
+
+            let ephemeral_key = SecretKey::random(&mut OsRng);
+            let shared_point =
+                (public_key.to_projective() * *ephemeral_key.to_nonzero_scalar()).to_affine();
+            let ephemeral_point = ephemeral_key.public_key().to_encoded_point(false);
+            // The uncompressed encoding of a point other than the identity has both coordinates.
+            let ephemeral_x = ephemeral_point.x().expect("Missing x coordinate").to_vec();
+            let ephemeral_y = ephemeral_point.y().expect("Missing y coordinate").to_vec();
+            let seed = kdfe::<D>(
+                &shared_point.x(),
+                b"IDENTITY",
+                &ephemeral_x,
+                &ek_x,
+                <D as digest::Digest>::output_size(),
+            );
+
+            // The secret is the marshalled ephemeral point.
+            let mut secret = Vec::new();
+            for coordinate in [ephemeral_x, ephemeral_y].iter() {
+                secret.extend_from_slice(&(coordinate.len() as u16).to_be_bytes());
+                secret.extend_from_slice(coordinate);
+            }
+            Ok((seed, secret))
+        }
+        _ => {
+            error!("Only RSA and NIST P-256 keys are supported for making credentials");
+            Err(Error::local_error(WrapperErrorKind::UnsupportedParam))
+        }
+    }
+}
+
+/// Key derivation function KDFa, in counter mode with HMAC, as defined in
+/// the section 11.4.10.2 of the part 1 of the TPM 2.0 specification.
+fn kdfa<D>(key: &[u8], label: &[u8], context_u: &[u8], context_v: &[u8], size: usize) -> Vec<u8>
+where
+    D: digest::Digest + BlockSizeUser + Clone,
+{
+    let mut derived = Vec::new();
+    let mut counter: u32 = 0;
+    while derived.len() < size {
+        counter += 1;
+        let mut hmac =
+            <SimpleHmac<D> as Mac>::new_from_slice(key).expect("HMAC can take keys of any size");
+        hmac.update(&counter.to_be_bytes());
+        hmac.update(label);
+        hmac.update(&[0]);
+        hmac.update(context_u);
+        hmac.update(context_v);
+        hmac.update(&((size * 8) as u32).to_be_bytes());
+        derived.extend_from_slice(&hmac.finalize().into_bytes());
+    }
+    derived.truncate(size);
+    derived
+}
+
+/// Key derivation function KDFe, as defined in the section 11.4.10.3
+/// of the part 1 of the TPM 2.0 specification.
+fn kdfe<D>(z: &[u8], label: &[u8], party_u: &[u8], party_v: &[u8], size: usize) -> Vec<u8>
+where
+    D: digest::Digest,
+{
+    let mut derived = Vec::new();
+    let mut counter: u32 = 0;
+    while derived.len() < size {
+        counter += 1;
+        let mut hasher = D::new();
+        hasher.update(counter.to_be_bytes());
+        hasher.update(z);
+        hasher.update(label);
+        hasher.update([0]);
+        hasher.update(party_u);
+        hasher.update(party_v);
+        derived.extend_from_slice(&hasher.finalize());
+    }
+    derived.truncate(size);
+    derived
+}
+
+/// Encrypts the data in place in CFB mode, with an IV of zeros.
+fn cfb_encrypt<C>(key: &[u8], data: &mut [u8])
+where
+    C: BlockEncryptMut + BlockCipher + KeyInit,
+{
+    let iv = vec![0u8; C::block_size()];
+    Encryptor::<C>::new_from_slices(key, &iv)
+        .expect("The key and IV sizes match the cipher")
+        .encrypt(data);
+}
+
+/// Left pads the big endian value with zeros up to the size.
+fn left_pad(value: &[u8], size: usize) -> Result<Vec<u8>> {
+    if value.len() > size {
+        error!("Value is larger than {} bytes", size);
+        return Err(Error::local_error(WrapperErrorKind::InvalidParam));
+    }
+    let mut padded = vec![0; size - value.len()];
+    padded.extend_from_slice(value);
+    Ok(padded)
+}
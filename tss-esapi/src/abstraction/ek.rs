@@ -2,8 +2,9 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::{
-    abstraction::{nv, IntoKeyCustomization, KeyCustomization},
+    abstraction::{nv, DefaultKey, IntoKeyCustomization, KeyCustomization},
     attributes::ObjectAttributesBuilder,
+    constants::response_code::TpmResponseCode,
     handles::{KeyHandle, NvIndexTpmHandle, TpmHandle},
     interface_types::{
         algorithm::{AsymmetricAlgorithm, HashingAlgorithm, PublicAlgorithm},
@@ -18,16 +19,46 @@ use crate::{
     },
     Context, Error, Result, WrapperErrorKind,
 };
+use log::error;
 use std::convert::TryFrom;
 // Source: TCG EK Credential Profile for TPM Family 2.0; Level 0 Version 2.3 Revision 2
 // Section 2.2.1.4 (Low Range) for Windows compatibility
 const RSA_2048_EK_CERTIFICATE_NV_INDEX: u32 = 0x01c00002;
 const ECC_P256_EK_CERTIFICATE_NV_INDEX: u32 = 0x01c0000a;
 
+// Source: TCG EK Credential Profile for TPM Family 2.0; Level 0 Version 2.3 Revision 2
+// Section 2.2.1.5.1
+const RSA_2048_EK_UNIQUE_SIZE: usize = 256;
+const ECC_P256_EK_UNIQUE_SIZE: usize = 32;
+
+/// Create the public area of the Endorsement Key from the specification templates
+///
+/// # Arguments
+/// * `alg` - The algorithm of the key, either RSA (2048 bits) or ECC (NIST P-256).
+/// * `nonce` - The optional EK nonce, that is placed at the start of the unique
+///   field of the template (the `x` coordinate for ECC keys).
+///
+/// # Details
+/// The templates are the default templates L-1 and L-2 of the
+/// TCG EK Credential Profile, with the auth policy being the
+/// digest of `TPM2_PolicySecret(TPM_RH_ENDORSEMENT)`.
+///
+/// # Errors
+/// * if the nonce is larger than the unique field of the template, a
+///   `WrongParamSize` wrapper error is returned.
+/// * if the algorithm is `Null`, an `UnsupportedParam` wrapper error is returned.
+pub fn create_ek_public_from_default_template(
+    alg: AsymmetricAlgorithm,
+    nonce: Option<&[u8]>,
+) -> Result<Public> {
+    ek_public_template(alg, nonce, DefaultKey)
+}
+
 // Source: TCG EK Credential Profile for TPM Family 2.0; Level 0 Version 2.3 Revision 2
 // Appendix B.3.3 and B.3.4
-fn create_ek_public_from_default_template<IKC: IntoKeyCustomization>(
+fn ek_public_template<IKC: IntoKeyCustomization>(
     alg: AsymmetricAlgorithm,
+    nonce: Option<&[u8]>,
     key_customization: IKC,
 ) -> Result<Public> {
     let key_customization = key_customization.into_key_customization();
@@ -79,7 +110,10 @@ fn create_ek_public_from_default_template<IKC: IntoKeyCustomization>(
                     .with_restricted(obj_attrs.decrypt())
                     .build()?,
             )
-            .with_rsa_unique_identifier(&PublicKeyRsa::new_empty_with_size(RsaKeyBits::Rsa2048)),
+            .with_rsa_unique_identifier(&PublicKeyRsa::try_from(ek_unique(
+                nonce,
+                RSA_2048_EK_UNIQUE_SIZE,
+            )?)?),
         AsymmetricAlgorithm::Ecc => PublicBuilder::new()
             .with_public_algorithm(PublicAlgorithm::Ecc)
             .with_name_hashing_algorithm(HashingAlgorithm::Sha256)
@@ -97,8 +131,8 @@ fn create_ek_public_from_default_template<IKC: IntoKeyCustomization>(
                     .build()?,
             )
             .with_ecc_unique_identifier(&EccPoint::new(
-                EccParameter::try_from(vec![0u8; 32])?,
-                EccParameter::try_from(vec![0u8; 32])?,
+                EccParameter::try_from(ek_unique(nonce, ECC_P256_EK_UNIQUE_SIZE)?)?,
+                EccParameter::try_from(vec![0u8; ECC_P256_EK_UNIQUE_SIZE])?,
             )),
         AsymmetricAlgorithm::Null => {
             // TDOD: Figure out what to with Null.
@@ -120,7 +154,7 @@ pub fn create_ek_object<IKC: IntoKeyCustomization>(
     alg: AsymmetricAlgorithm,
     key_customization: IKC,
 ) -> Result<KeyHandle> {
-    let ek_public = create_ek_public_from_default_template(alg, key_customization)?;
+    let ek_public = ek_public_template(alg, None, key_customization)?;
 
     Ok(context
         .execute_with_nullauth_session(|ctx| {
@@ -130,7 +164,28 @@ pub fn create_ek_object<IKC: IntoKeyCustomization>(
 }
 
 /// Retreive the Endorsement Key public certificate from the TPM
+///
+/// # Details
+/// This is the same as [retrieve_ek_public_cert].
 pub fn retrieve_ek_pubcert(context: &mut Context, alg: AsymmetricAlgorithm) -> Result<Vec<u8>> {
+    retrieve_ek_public_cert(context, alg)
+}
+
+/// Retrieve the Endorsement Key public certificate from the TPM
+///
+/// # Arguments
+/// * `alg` - The algorithm of the Endorsement Key, either RSA (2048 bits) or ECC (NIST P-256).
+///
+/// # Details
+/// The DER encoded certificate is read from the NV index specified by the
+/// TCG EK Credential Profile (`0x01c00002` for RSA and `0x01c0000a` for ECC).
+/// Certificates larger than the NV buffer of the TPM are read in several chunks.
+///
+/// # Errors
+/// * if the NV index is not defined, the TSS error returned by the TPM, of kind
+///   [Handle](crate::constants::response_code::TpmResponseCode::Handle), is returned.
+/// * if the algorithm is `Null`, an `UnsupportedParam` wrapper error is returned.
+pub fn retrieve_ek_public_cert(context: &mut Context, alg: AsymmetricAlgorithm) -> Result<Vec<u8>> {
     let nv_idx = match alg {
         AsymmetricAlgorithm::Rsa => RSA_2048_EK_CERTIFICATE_NV_INDEX,
         AsymmetricAlgorithm::Ecc => ECC_P256_EK_CERTIFICATE_NV_INDEX,
@@ -143,10 +198,37 @@ pub fn retrieve_ek_pubcert(context: &mut Context, alg: AsymmetricAlgorithm) -> R
     let nv_idx = NvIndexTpmHandle::new(nv_idx).unwrap();
 
     let nv_auth_handle = TpmHandle::NvIndex(nv_idx);
-    let nv_auth_handle = context.execute_without_session(|ctx| {
+    let nv_auth_handle = match context.execute_without_session(|ctx| {
         ctx.tr_from_tpm_public(nv_auth_handle)
             .map(|v| NvAuth::NvIndex(v.into()))
-    })?;
+    }) {
+        Ok(nv_auth_handle) => nv_auth_handle,
+        Err(e) => {
+            if e.tpm_response_code() == Some(TpmResponseCode::Handle) {
+                error!(
+                    "The EK certificate NV index {:#010x} is not defined",
+                    u32::from(nv_idx)
+                );
+            }
+            return Err(e);
+        }
+    };
 
     context.execute_with_nullauth_session(|ctx| nv::read_full(ctx, nv_auth_handle, nv_idx))
 }
+
+/// Pads the nonce with zeros up to the size of the unique field
+fn ek_unique(nonce: Option<&[u8]>, size: usize) -> Result<Vec<u8>> {
+    let nonce = nonce.unwrap_or_default();
+    if nonce.len() > size {
+        error!(
+            "The EK nonce is too large ({} bytes) for the template, the maximum is {} bytes",
+            nonce.len(),
+            size
+        );
+        return Err(Error::local_error(WrapperErrorKind::WrongParamSize));
+    }
+    let mut unique = nonce.to_vec();
+    unique.resize(size, 0);
+    Ok(unique)
+}
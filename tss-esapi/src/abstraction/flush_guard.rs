@@ -0,0 +1,67 @@
+// Copyright 2021 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Module for flushing transient objects when they go out of scope.
+use crate::{
+    handles::{KeyHandle, ObjectHandle},
+    structures::{Private, Public},
+    Context, Result,
+};
+use log::error;
+
+/// Guard flushing a transient object from the TPM when dropped.
+///
+/// # Details
+/// The guard holds the context for as long as it lives, and the
+/// context can be accessed through [FlushGuard::context] in order
+/// to use the object.
+///
+/// Failing to flush the object when the guard is dropped does not
+/// panic, the error is logged instead.
+#[derive(Debug)]
+pub struct FlushGuard<'a> {
+    context: &'a mut Context,
+    handle: ObjectHandle,
+}
+
+impl<'a> FlushGuard<'a> {
+    /// Creates a guard flushing the object with the provided handle.
+    pub fn new(context: &'a mut Context, handle: ObjectHandle) -> Self {
+        FlushGuard { context, handle }
+    }
+
+    /// Returns the handle of the guarded object.
+    pub fn handle(&self) -> ObjectHandle {
+        self.handle
+    }
+
+    /// Returns the context holding the guarded object.
+    pub fn context(&mut self) -> &mut Context {
+        self.context
+    }
+}
+
+impl Drop for FlushGuard<'_> {
+    fn drop(&mut self) {
+        if let Err(e) = self.context.flush_context(self.handle) {
+            error!("Error when flushing guarded object: {}", e);
+        }
+    }
+}
+
+impl Context {
+    /// Loads an object into the TPM and returns a guard flushing
+    /// it when dropped.
+    ///
+    /// # Details
+    /// See [Context::load] for the arguments.
+    pub fn load_guarded(
+        &mut self,
+        parent_handle: KeyHandle,
+        private: Private,
+        public: &Public,
+    ) -> Result<FlushGuard<'_>> {
+        let key_handle = self.load(parent_handle, private, public)?;
+        Ok(FlushGuard::new(self, key_handle.into()))
+    }
+}
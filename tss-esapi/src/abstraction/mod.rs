@@ -2,11 +2,25 @@
 // SPDX-License-Identifier: Apache-2.0
 
 pub mod ak;
+pub mod attestation;
 pub mod cipher;
+#[cfg(feature = "make-credential")]
+pub mod credential;
 pub mod ek;
+pub mod flush_guard;
 pub mod nv;
+#[cfg(feature = "rng")]
+pub mod rng;
+pub mod sequence;
+pub mod session_pool;
+#[cfg(feature = "ecdsa-signer")]
+pub mod signer;
 pub mod transient;
 
+pub use flush_guard::FlushGuard;
+pub use sequence::{HashSequence, HmacSequence};
+pub use session_pool::{PooledSession, SessionPool};
+
 use crate::{attributes::ObjectAttributesBuilder, structures::PublicBuilder};
 
 /// KeyCustomizaion allows to adjust how a key is going to be created
@@ -0,0 +1,70 @@
+// Copyright 2021 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Module for using the TPM random number generator
+//! through the `rand_core` traits.
+use crate::{structures::Digest, Context, Error, Result, WrapperErrorKind};
+use log::error;
+use rand_core::{impls, CryptoRng, RngCore};
+
+/// Random number generator backed by the TPM.
+///
+/// # Details
+/// The random bytes are obtained with `TPM2_GetRandom`. Requests
+/// larger than what the TPM returns in a single call are split
+/// into several calls.
+///
+/// The methods of [RngCore] that cannot return errors panic
+/// if the TPM fails to provide random bytes. Use
+/// [RngCore::try_fill_bytes] in order to handle the errors.
+#[derive(Debug)]
+pub struct TpmRng<'a> {
+    context: &'a mut Context,
+}
+
+impl<'a> TpmRng<'a> {
+    /// Creates a random number generator that uses the TPM of the context.
+    pub fn new(context: &'a mut Context) -> Self {
+        TpmRng { context }
+    }
+
+    /// Fills `dest` with random bytes from the TPM.
+    fn fill_from_tpm(&mut self, dest: &mut [u8]) -> Result<()> {
+        let mut filled = 0;
+        while filled < dest.len() {
+            let requested = std::cmp::min(dest.len() - filled, Digest::MAX_SIZE);
+            let random = self.context.get_random(requested)?;
+            if random.is_empty() {
+                error!("The TPM did not return any random bytes");
+                return Err(Error::local_error(WrapperErrorKind::WrongValueFromTpm));
+            }
+            // The TPM may return fewer bytes than requested.
+            let received = std::cmp::min(random.len(), requested);
+            dest[filled..filled + received].copy_from_slice(&random[..received]);
+            filled += received;
+        }
+        Ok(())
+    }
+}
+
+impl RngCore for TpmRng<'_> {
+    fn next_u32(&mut self) -> u32 {
+        impls::next_u32_via_fill(self)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        impls::next_u64_via_fill(self)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        if let Err(e) = self.fill_from_tpm(dest) {
+            panic!("Failed to get random bytes from the TPM: {}", e);
+        }
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> std::result::Result<(), rand_core::Error> {
+        self.fill_from_tpm(dest).map_err(rand_core::Error::new)
+    }
+}
+
+impl CryptoRng for TpmRng<'_> {}
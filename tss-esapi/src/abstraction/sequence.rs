@@ -0,0 +1,182 @@
+// Copyright 2021 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Module for hashing streams of data with the TPM.
+use crate::{
+    handles::{KeyHandle, ObjectHandle},
+    interface_types::{
+        algorithm::HashingAlgorithm, resource_handles::Hierarchy, session_handles::AuthSession,
+    },
+    structures::{Auth, Digest, HashcheckTicket, MaxBuffer},
+    Context, Result,
+};
+use std::convert::TryFrom;
+
+/// Hash sequence computing the digest of data provided in several parts.
+///
+/// # Details
+/// The data is buffered and sent to the TPM in chunks of the size
+/// of a [MaxBuffer], so the parts passed to [HashSequence::update]
+/// can be of any size.
+///
+/// The sequence object is flushed if the sequence is dropped
+/// without being finished.
+#[derive(Debug)]
+pub struct HashSequence<'a> {
+    sequence: Sequence<'a>,
+}
+
+impl<'a> HashSequence<'a> {
+    /// Starts a hash sequence with the hashing algorithm.
+    ///
+    /// # Details
+    /// The ticket returned when the sequence is finished is
+    /// produced for the provided hierarchy.
+    pub fn new(
+        context: &'a mut Context,
+        hashing_algorithm: HashingAlgorithm,
+        hierarchy: Hierarchy,
+    ) -> Result<Self> {
+        let sequence_handle = context.execute_without_session(|ctx| {
+            ctx.hash_sequence_start(Auth::default(), hashing_algorithm)
+        })?;
+        Ok(HashSequence {
+            sequence: Sequence::new(context, sequence_handle.into(), hierarchy),
+        })
+    }
+
+    /// Adds data to the sequence.
+    pub fn update(&mut self, data: &[u8]) -> Result<()> {
+        self.sequence.update(data)
+    }
+
+    /// Finishes the sequence.
+    ///
+    /// # Returns
+    /// The digest of the data, and the ticket indicating that the
+    /// data did not start with `TPM_GENERATED_VALUE`.
+    pub fn finish(self) -> Result<(Digest, Option<HashcheckTicket>)> {
+        let (digest, ticket) = self.sequence.finish()?;
+        Ok((digest, Some(ticket)))
+    }
+}
+
+/// HMAC sequence computing the HMAC of data provided in several parts.
+///
+/// # Details
+/// The data is buffered and sent to the TPM in chunks of the size
+/// of a [MaxBuffer], so the parts passed to [HmacSequence::update]
+/// can be of any size.
+///
+/// The sequence object is flushed if the sequence is dropped
+/// without being finished.
+#[derive(Debug)]
+pub struct HmacSequence<'a> {
+    sequence: Sequence<'a>,
+}
+
+impl<'a> HmacSequence<'a> {
+    /// Starts an HMAC sequence using the key with the provided handle.
+    ///
+    /// # Details
+    /// The context has to be set up with the session needed for
+    /// authorizing the use of the key.
+    pub fn new(
+        context: &'a mut Context,
+        key_handle: KeyHandle,
+        hashing_algorithm: HashingAlgorithm,
+    ) -> Result<Self> {
+        let sequence_handle = context.hmac_start(key_handle, Auth::default(), hashing_algorithm)?;
+        Ok(HmacSequence {
+            sequence: Sequence::new(context, sequence_handle.into(), Hierarchy::Null),
+        })
+    }
+
+    /// Adds data to the sequence.
+    pub fn update(&mut self, data: &[u8]) -> Result<()> {
+        self.sequence.update(data)
+    }
+
+    /// Finishes the sequence.
+    ///
+    /// # Returns
+    /// The HMAC of the data. No ticket is produced for HMAC sequences.
+    pub fn finish(self) -> Result<(Digest, Option<HashcheckTicket>)> {
+        let (digest, _) = self.sequence.finish()?;
+        Ok((digest, None))
+    }
+}
+
+/// Sequence object with the data not yet sent to the TPM.
+#[derive(Debug)]
+struct Sequence<'a> {
+    context: &'a mut Context,
+    sequence_handle: Option<ObjectHandle>,
+    hierarchy: Hierarchy,
+    pending: Vec<u8>,
+}
+
+impl<'a> Sequence<'a> {
+    fn new(context: &'a mut Context, sequence_handle: ObjectHandle, hierarchy: Hierarchy) -> Self {
+        Sequence {
+            context,
+            sequence_handle: Some(sequence_handle),
+            hierarchy,
+            pending: Vec::new(),
+        }
+    }
+
+    /// Sends the complete chunks of data to the TPM, keeping
+    /// the rest for the next update or for completing the sequence.
+    fn update(&mut self, data: &[u8]) -> Result<()> {
+        let sequence_handle = self.handle();
+        let mut pending = std::mem::take(&mut self.pending);
+        let mut data = data;
+        // The sequences are started with an empty authorization value.
+        let result = self
+            .context
+            .execute_with_session(Some(AuthSession::Password), |ctx| {
+                while pending.len() + data.len() > MaxBuffer::MAX_SIZE {
+                    let (chunk_end, rest) = data.split_at(MaxBuffer::MAX_SIZE - pending.len());
+                    pending.extend_from_slice(chunk_end);
+                    ctx.sequence_update(
+                        sequence_handle,
+                        MaxBuffer::try_from(std::mem::take(&mut pending))?,
+                    )?;
+                    data = rest;
+                }
+                pending.extend_from_slice(data);
+                Ok(())
+            });
+        self.pending = pending;
+        result
+    }
+
+    /// Completes the sequence with the remaining data.
+    fn finish(mut self) -> Result<(Digest, HashcheckTicket)> {
+        let sequence_handle = self.handle();
+        let buffer = MaxBuffer::try_from(std::mem::take(&mut self.pending))?;
+        let hierarchy = self.hierarchy;
+        let result = self
+            .context
+            .execute_with_session(Some(AuthSession::Password), |ctx| {
+                ctx.sequence_complete(sequence_handle, buffer, hierarchy)
+            })?;
+        // The sequence object is flushed by the TPM once completed.
+        self.sequence_handle = None;
+        Ok(result)
+    }
+
+    fn handle(&self) -> ObjectHandle {
+        self.sequence_handle
+            .expect("The sequence handle is only removed once the sequence is finished")
+    }
+}
+
+impl Drop for Sequence<'_> {
+    fn drop(&mut self) {
+        if let Some(sequence_handle) = self.sequence_handle.take() {
+            let _ = self.context.flush_context(sequence_handle);
+        }
+    }
+}
@@ -0,0 +1,194 @@
+// Copyright 2021 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Module for reusing HMAC sessions across commands.
+use crate::{
+    attributes::SessionAttributesBuilder,
+    constants::SessionType,
+    handles::SessionHandle,
+    interface_types::{algorithm::HashingAlgorithm, session_handles::AuthSession},
+    structures::SymmetricDefinition,
+    Context, Error, Result, WrapperErrorKind,
+};
+use log::error;
+use std::{cell::RefCell, collections::VecDeque};
+
+/// Pool of HMAC sessions.
+///
+/// # Details
+/// The sessions are started lazily, with the configured symmetric
+/// definition and hashing algorithm, the first time they are needed.
+/// Acquired sessions are returned to the pool with [SessionPool::release],
+/// instead of being flushed.
+///
+/// At most `max_size` sessions are kept in the pool. When more sessions
+/// are returned, the oldest ones are evicted and flushed straight away.
+/// A [PooledSession] that is dropped without being released cannot reach
+/// the context, so the sessions it evicts are only flushed on the next
+/// call to [SessionPool::acquire], [SessionPool::release] or
+/// [SessionPool::flush]. The sessions still held by the pool are not
+/// flushed when the pool is dropped, so [SessionPool::flush] needs to be
+/// called in order to free them.
+#[derive(Debug)]
+pub struct SessionPool {
+    symmetric: SymmetricDefinition,
+    auth_hash: HashingAlgorithm,
+    max_size: usize,
+    idle: RefCell<VecDeque<AuthSession>>,
+    evicted: RefCell<Vec<AuthSession>>,
+}
+
+impl SessionPool {
+    /// Creates an empty pool keeping at most `max_size` sessions.
+    pub fn new(
+        symmetric: SymmetricDefinition,
+        auth_hash: HashingAlgorithm,
+        max_size: usize,
+    ) -> Self {
+        SessionPool {
+            symmetric,
+            auth_hash,
+            max_size,
+            idle: RefCell::new(VecDeque::new()),
+            evicted: RefCell::new(Vec::new()),
+        }
+    }
+
+    /// Returns the maximum number of sessions kept in the pool.
+    pub fn max_size(&self) -> usize {
+        self.max_size
+    }
+
+    /// Returns the number of sessions currently available in the pool.
+    pub fn idle_sessions(&self) -> usize {
+        self.idle.borrow().len()
+    }
+
+    /// Acquires a session from the pool.
+    ///
+    /// # Details
+    /// The most recently returned session is handed out. If the pool
+    /// is empty a new session is started.
+    ///
+    /// # Errors
+    /// * if the TPM fails to start a session, or to flush an evicted
+    ///   session, the error is returned.
+    pub fn acquire(&self, context: &mut Context) -> Result<PooledSession<'_>> {
+        self.flush_evicted(context)?;
+        let pooled = self.idle.borrow_mut().pop_back();
+        let session = match pooled {
+            Some(session) => session,
+            None => self.start_session(context)?,
+        };
+        Ok(PooledSession {
+            pool: self,
+            session,
+        })
+    }
+
+    /// Returns an acquired session to the pool.
+    ///
+    /// # Details
+    /// If the pool is full, the oldest sessions are evicted and
+    /// flushed from the TPM.
+    ///
+    /// # Errors
+    /// * if the session was acquired from another pool, an `InvalidParam`
+    ///   wrapper error is returned.
+    /// * if the TPM fails to flush an evicted session, the error is returned.
+    pub fn release(&self, context: &mut Context, pooled: PooledSession<'_>) -> Result<()> {
+        if !std::ptr::eq(self, pooled.pool) {
+            error!("The session was acquired from another pool");
+            return Err(Error::local_error(WrapperErrorKind::InvalidParam));
+        }
+        let session = pooled.session;
+        std::mem::forget(pooled);
+        self.return_session(session);
+        self.flush_evicted(context)
+    }
+
+    /// Flushes all the sessions held by the pool.
+    ///
+    /// # Details
+    /// The sessions that are currently acquired are not affected and
+    /// are returned to the pool when released.
+    pub fn flush(&self, context: &mut Context) -> Result<()> {
+        self.flush_evicted(context)?;
+        while let Some(session) = self.idle.borrow_mut().pop_front() {
+            context.flush_context(SessionHandle::from(session).into())?;
+        }
+        Ok(())
+    }
+
+    /// Starts a new HMAC session that is kept alive after being used.
+    fn start_session(&self, context: &mut Context) -> Result<AuthSession> {
+        let session = context
+            .start_auth_session(
+                None,
+                None,
+                None,
+                SessionType::Hmac,
+                self.symmetric,
+                self.auth_hash,
+            )?
+            .ok_or_else(|| {
+                error!("Received unexpected NONE handle from the TPM");
+                Error::local_error(WrapperErrorKind::WrongValueFromTpm)
+            })?;
+        let (session_attributes, session_attributes_mask) = SessionAttributesBuilder::new()
+            .with_continue_session(true)
+            .build();
+        if let Err(e) =
+            context.tr_sess_set_attributes(session, session_attributes, session_attributes_mask)
+        {
+            let _ = context.flush_context(SessionHandle::from(session).into());
+            return Err(e);
+        }
+        Ok(session)
+    }
+
+    /// Flushes the sessions that were evicted from the pool.
+    fn flush_evicted(&self, context: &mut Context) -> Result<()> {
+        while let Some(session) = self.evicted.borrow_mut().pop() {
+            context.flush_context(SessionHandle::from(session).into())?;
+        }
+        Ok(())
+    }
+
+    /// Returns a session to the pool, evicting the oldest
+    /// sessions if the pool is full.
+    fn return_session(&self, session: AuthSession) {
+        let mut idle = self.idle.borrow_mut();
+        idle.push_back(session);
+        while idle.len() > self.max_size {
+            if let Some(oldest) = idle.pop_front() {
+                self.evicted.borrow_mut().push(oldest);
+            }
+        }
+    }
+}
+
+/// Session acquired from a [SessionPool].
+///
+/// # Details
+/// The session should be returned with [SessionPool::release]. If it is
+/// dropped instead, it is still returned to the pool, but any session it
+/// evicts is only flushed on the next use of the pool.
+#[derive(Debug)]
+pub struct PooledSession<'a> {
+    pool: &'a SessionPool,
+    session: AuthSession,
+}
+
+impl PooledSession<'_> {
+    /// Returns the session, to be used in the session slots of the context.
+    pub fn session(&self) -> AuthSession {
+        self.session
+    }
+}
+
+impl Drop for PooledSession<'_> {
+    fn drop(&mut self) {
+        self.pool.return_session(self.session);
+    }
+}
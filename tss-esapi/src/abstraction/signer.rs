@@ -0,0 +1,143 @@
+// Copyright 2021 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Module for using TPM keys through the `signature` traits.
+use crate::{
+    constants::tss::{TPM2_ALG_NULL, TPM2_RH_NULL, TPM2_ST_HASHCHECK},
+    handles::KeyHandle,
+    interface_types::{algorithm::HashingAlgorithm, ecc::EccCurve},
+    structures::{Digest, EccScheme, HashcheckTicket, Public, Signature},
+    tss2_esys::{TPMT_SIG_SCHEME, TPMT_TK_HASHCHECK},
+    Context, Error, Result, WrapperErrorKind,
+};
+use log::error;
+use p256::{
+    ecdsa::{signature::Signer, Signature as P256Signature},
+    FieldBytes,
+};
+use sha2::{Digest as _, Sha256, Sha384, Sha512};
+use std::{cell::RefCell, convert::TryFrom};
+
+/// Size in bytes of the NIST P-256 scalars.
+const P256_SCALAR_SIZE: usize = 32;
+
+/// ECDSA signer using a NIST P-256 key loaded in the TPM.
+///
+/// # Details
+/// The message is hashed in software with the hashing algorithm
+/// of the ECDSA scheme of the key, and the digest is signed with
+/// `TPM2_Sign`. The key must therefore be an unrestricted signing
+/// key with an ECDSA scheme.
+///
+/// The context has to be set up with the sessions needed for
+/// authorizing the use of the key.
+#[derive(Debug)]
+pub struct TpmEcdsaSigner<'a> {
+    context: RefCell<&'a mut Context>,
+    key_handle: KeyHandle,
+    hashing_algorithm: HashingAlgorithm,
+}
+
+impl<'a> TpmEcdsaSigner<'a> {
+    /// Creates a signer using the key with the provided handle.
+    ///
+    /// # Errors
+    /// * if the key is not a NIST P-256 key with an ECDSA scheme, an
+    ///   `InvalidParam` wrapper error is returned.
+    /// * if the hashing algorithm of the scheme is not one of SHA-256,
+    ///   SHA-384 and SHA-512, an `UnsupportedParam` wrapper error is returned.
+    pub fn new(context: &'a mut Context, key_handle: KeyHandle) -> Result<Self> {
+        let (public, _, _) = context.read_public(key_handle)?;
+        let hashing_algorithm = match public {
+            Public::Ecc { parameters, .. } if parameters.ecc_curve() == EccCurve::NistP256 => {
+                match parameters.ecc_scheme() {
+                    EccScheme::EcDsa(hash_scheme) => hash_scheme.hashing_algorithm(),
+                    _ => {
+                        error!("The key does not have an ECDSA scheme");
+                        return Err(Error::local_error(WrapperErrorKind::InvalidParam));
+                    }
+                }
+            }
+            _ => {
+                error!("The key is not a NIST P-256 key");
+                return Err(Error::local_error(WrapperErrorKind::InvalidParam));
+            }
+        };
+        match hashing_algorithm {
+            HashingAlgorithm::Sha256 | HashingAlgorithm::Sha384 | HashingAlgorithm::Sha512 => {
+                Ok(TpmEcdsaSigner {
+                    context: RefCell::new(context),
+                    key_handle,
+                    hashing_algorithm,
+                })
+            }
+            _ => {
+                error!(
+                    "Hashing algorithm {:?} is not supported for hashing messages",
+                    hashing_algorithm
+                );
+                Err(Error::local_error(WrapperErrorKind::UnsupportedParam))
+            }
+        }
+    }
+
+    /// Hashes the message with the hashing algorithm of the key scheme.
+    fn hash(&self, msg: &[u8]) -> Result<Digest> {
+        match self.hashing_algorithm {
+            HashingAlgorithm::Sha256 => Digest::try_from(Sha256::digest(msg).to_vec()),
+            HashingAlgorithm::Sha384 => Digest::try_from(Sha384::digest(msg).to_vec()),
+            HashingAlgorithm::Sha512 => Digest::try_from(Sha512::digest(msg).to_vec()),
+            _ => Err(Error::local_error(WrapperErrorKind::UnsupportedParam)),
+        }
+    }
+
+    /// Signs the message with the TPM key.
+    fn sign_message(&self, msg: &[u8]) -> Result<P256Signature> {
+        let digest = self.hash(msg)?;
+        let scheme = TPMT_SIG_SCHEME {
+            scheme: TPM2_ALG_NULL,
+            details: Default::default(),
+        };
+        let validation = HashcheckTicket::try_from(TPMT_TK_HASHCHECK {
+            tag: TPM2_ST_HASHCHECK,
+            hierarchy: TPM2_RH_NULL,
+            digest: Default::default(),
+        })?;
+        let mut context = self.context.try_borrow_mut().map_err(|_| {
+            error!("The context of the signer is already in use");
+            Error::local_error(WrapperErrorKind::InvalidHandleState)
+        })?;
+        match context.sign(self.key_handle, &digest, scheme, validation)? {
+            Signature::EcDsa(signature) => P256Signature::from_scalars(
+                p256_scalar(signature.signature_r().value())?,
+                p256_scalar(signature.signature_s().value())?,
+            )
+            .map_err(|e| {
+                error!("The TPM produced an invalid ECDSA signature: {}", e);
+                Error::local_error(WrapperErrorKind::WrongValueFromTpm)
+            }),
+            _ => {
+                error!("The TPM did not produce an ECDSA signature");
+                Err(Error::local_error(WrapperErrorKind::WrongValueFromTpm))
+            }
+        }
+    }
+}
+
+impl Signer<P256Signature> for TpmEcdsaSigner<'_> {
+    fn try_sign(&self, msg: &[u8]) -> std::result::Result<P256Signature, p256::ecdsa::Error> {
+        self.sign_message(msg)
+            .map_err(p256::ecdsa::Error::from_source)
+    }
+}
+
+/// Left pads the scalar with zeros up to the size of the P-256 scalars.
+fn p256_scalar(value: &[u8]) -> Result<FieldBytes> {
+    if value.len() > P256_SCALAR_SIZE {
+        error!("The ECDSA signature scalar is too large for P-256");
+        return Err(Error::local_error(WrapperErrorKind::WrongValueFromTpm));
+    }
+    let mut scalar = FieldBytes::default();
+    scalar[P256_SCALAR_SIZE - value.len()..].copy_from_slice(value);
+    Ok(scalar)
+}
@@ -38,7 +38,8 @@ use crate::{
 };
 
 use log::error;
-use serde::{Deserialize, Serialize};
+#[cfg(feature = "der-signature")]
+use picky_asn1::wrapper::IntegerAsn1;
 use std::convert::{TryFrom, TryInto};
 use zeroize::Zeroize;
 
@@ -74,7 +75,8 @@ pub enum KeyParams {
 ///
 /// If the Owner hierarchy is cleared, any key material generated
 /// prior to that event will become unusable.
-#[derive(Debug, Serialize, Deserialize, Clone, Zeroize)]
+#[derive(Debug, Clone, Zeroize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct KeyMaterial {
     public: PublicKey,
     private: Vec<u8>,
@@ -301,6 +303,54 @@ impl TransientKeyContext {
         Ok(signature)
     }
 
+    /// Sign a digest with an existing key and return the DER-encoded signature.
+    ///
+    /// # Details
+    /// ECDSA signatures are encoded as the `SEQUENCE` of the `r` and `s`
+    /// integers defined in RFC 3279. RSA signatures are returned as the
+    /// raw signature blob, which is already the standard encoding.
+    ///
+    /// The key is passed in the same way as to [TransientKeyContext::sign],
+    /// rather than as a single key material argument, because the
+    /// [KeyMaterial] does not hold the parameters and the authentication
+    /// value needed to load the key.
+    ///
+    /// # Errors
+    /// * if the digest is larger than [Digest::MAX_SIZE], a `WrongParamSize`
+    ///   wrapper error is returned
+    /// * if the key produces signatures other than RSA or ECDSA signatures,
+    ///   an `UnsupportedParam` wrapper error is returned
+    #[cfg(feature = "der-signature")]
+    pub fn sign_der(
+        &mut self,
+        key_material: KeyMaterial,
+        key_params: KeyParams,
+        key_auth: Option<Auth>,
+        digest: &[u8],
+    ) -> Result<Vec<u8>> {
+        let digest = Digest::try_from(digest)?;
+        match self.sign(key_material, key_params, key_auth, digest)? {
+            Signature::RsaSsa(signature) | Signature::RsaPss(signature) => {
+                Ok(signature.signature().value().to_vec())
+            }
+            Signature::EcDsa(signature) => picky_asn1_der::to_vec(&(
+                der_integer(signature.signature_r().value()),
+                der_integer(signature.signature_s().value()),
+            ))
+            .map_err(|e| {
+                error!("Error when encoding the ECDSA signature: {}", e);
+                Error::local_error(ErrorKind::InvalidParam)
+            }),
+            signature => {
+                error!(
+                    "Signatures of type {:?} cannot be DER-encoded",
+                    signature.algorithm()
+                );
+                Err(Error::local_error(ErrorKind::UnsupportedParam))
+            }
+        }
+    }
+
     /// Verify a signature against a digest.
     ///
     /// Given a digest, a key and a signature, this method returns a `Verified` ticket if the
@@ -723,3 +773,14 @@ impl Default for TransientKeyContextBuilder {
         TransientKeyContextBuilder::new()
     }
 }
+
+/// Creates a DER `INTEGER` from the big endian unsigned integer.
+#[cfg(feature = "der-signature")]
+fn der_integer(value: &[u8]) -> IntegerAsn1 {
+    // DER integers must be minimally encoded.
+    let value = match value.iter().position(|byte| *byte != 0) {
+        Some(first_non_zero) => value[first_non_zero..].to_vec(),
+        None => vec![0],
+    };
+    IntegerAsn1::from_bytes_be_unsigned(value)
+}
@@ -1,5 +1,10 @@
 //! Module for representation of attributes
 
+/// Representation of the attributes defined in the
+/// Attribute structures -> TPMA_ALGORITHM section of
+/// the specfication.
+pub mod algorithm;
+
 /// Representation of the attributes defined in the
 /// Attribute structures -> TPMA_OBJECT section of
 /// the specfication
@@ -15,6 +20,7 @@ pub mod session;
 /// the specfication.
 pub mod nv_index;
 
+pub use algorithm::AlgorithmAttributes;
 pub use nv_index::{NvIndexAttributes, NvIndexAttributesBuilder};
 pub use object::{ObjectAttributes, ObjectAttributesBuilder};
 pub use session::{SessionAttributes, SessionAttributesBuilder, SessionAttributesMask};
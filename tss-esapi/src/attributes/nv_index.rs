@@ -131,6 +131,9 @@ impl TryFrom<NvIndexAttributes> for TPMA_NV {
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub struct NvIndexAttributesBuilder {
     nv_index_attributes: NvIndexAttributes,
+    nv_counter: bool,
+    nv_bits: bool,
+    nv_extend: bool,
 }
 
 impl NvIndexAttributesBuilder {
@@ -138,6 +141,9 @@ impl NvIndexAttributesBuilder {
     pub const fn new() -> Self {
         NvIndexAttributesBuilder {
             nv_index_attributes: NvIndexAttributes(0),
+            nv_counter: false,
+            nv_bits: false,
+            nv_extend: false,
         }
     }
 
@@ -145,6 +151,9 @@ impl NvIndexAttributesBuilder {
     pub const fn with_attributes(nv_index_attributes: NvIndexAttributes) -> Self {
         NvIndexAttributesBuilder {
             nv_index_attributes,
+            nv_counter: false,
+            nv_bits: false,
+            nv_extend: false,
         }
     }
 
@@ -197,6 +206,58 @@ impl NvIndexAttributesBuilder {
         self
     }
 
+    /// Controls whether the nv index is a counter index.
+    ///
+    /// # Details
+    /// The counter, bits and extend index types are mutually
+    /// exclusive, building fails if more than one of them is set.
+    ///
+    /// # Arguments
+    /// * `set` - `true` indicates that the index type should be `Counter`.
+    ///           `false` indicates that the index type should not be `Counter`.
+    pub fn with_nv_counter(mut self, set: bool) -> Self {
+        self.nv_counter = set;
+        self.with_exclusive_index_type(NvIndexType::Counter, set)
+    }
+
+    /// Controls whether the nv index is a bit field index.
+    ///
+    /// # Details
+    /// The counter, bits and extend index types are mutually
+    /// exclusive, building fails if more than one of them is set.
+    ///
+    /// # Arguments
+    /// * `set` - `true` indicates that the index type should be `Bits`.
+    ///           `false` indicates that the index type should not be `Bits`.
+    pub fn with_nv_bits(mut self, set: bool) -> Self {
+        self.nv_bits = set;
+        self.with_exclusive_index_type(NvIndexType::Bits, set)
+    }
+
+    /// Controls whether the nv index is an extend index.
+    ///
+    /// # Details
+    /// The counter, bits and extend index types are mutually
+    /// exclusive, building fails if more than one of them is set.
+    ///
+    /// # Arguments
+    /// * `set` - `true` indicates that the index type should be `Extend`.
+    ///           `false` indicates that the index type should not be `Extend`.
+    pub fn with_nv_extend(mut self, set: bool) -> Self {
+        self.nv_extend = set;
+        self.with_exclusive_index_type(NvIndexType::Extend, set)
+    }
+
+    /// Makes the index ordinary again when an index type that cannot
+    /// be combined with another index type than `Ordinary` is cleared.
+    fn with_exclusive_index_type(mut self, nv_index_type: NvIndexType, set: bool) -> Self {
+        if !set && self.nv_index_attributes.index_type().ok() == Some(nv_index_type) {
+            self.nv_index_attributes
+                .set_index_type(NvIndexType::Ordinary);
+        }
+        self
+    }
+
     /// Controls the `policy delete` attribute
     ///
     /// # Arguments
@@ -372,7 +433,33 @@ impl NvIndexAttributesBuilder {
     /// # Errors
     /// Returns an error if some attributes are missing
     /// or are in conflict with eachother.
-    pub fn build(self) -> Result<NvIndexAttributes> {
+    pub fn build(mut self) -> Result<NvIndexAttributes> {
+        let exclusive_index_types: Vec<NvIndexType> = [
+            (self.nv_counter, NvIndexType::Counter),
+            (self.nv_bits, NvIndexType::Bits),
+            (self.nv_extend, NvIndexType::Extend),
+        ]
+        .iter()
+        .filter(|(set, _)| *set)
+        .map(|(_, nv_index_type)| *nv_index_type)
+        .collect();
+        if exclusive_index_types.len() > 1 {
+            error!("More than one of the counter, bits and extend index types have been set");
+            return Err(Error::local_error(WrapperErrorKind::InconsistentParams));
+        }
+        if let Some(&nv_index_type) = exclusive_index_types.first() {
+            match self.nv_index_attributes.index_type()? {
+                NvIndexType::Ordinary => self.nv_index_attributes.set_index_type(nv_index_type),
+                index_type if index_type == nv_index_type => (),
+                index_type => {
+                    error!(
+                        "The {:?} index type conflicts with the {:?} index type",
+                        nv_index_type, index_type
+                    );
+                    return Err(Error::local_error(WrapperErrorKind::InconsistentParams));
+                }
+            }
+        }
         self.nv_index_attributes.validate()?;
         Ok(self.nv_index_attributes)
     }
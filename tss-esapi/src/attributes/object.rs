@@ -4,6 +4,7 @@ use bitfield::bitfield;
 bitfield! {
     /// Butfield representing the object attributes.
     #[derive(Copy, Clone, Eq, PartialEq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     pub struct ObjectAttributes(TPMA_OBJECT);
     impl Debug;
     // Object attribute flags
@@ -0,0 +1,51 @@
+// Copyright 2021 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use crate::{
+    constants::tss::{TPM2_AT_ANY, TPM2_AT_ERROR, TPM2_AT_PV1, TPM2_AT_VEND},
+    tss2_esys::TPM_AT,
+    Error, Result, WrapperErrorKind,
+};
+use log::error;
+use std::convert::TryFrom;
+
+/// Enum representing the different attached component
+/// capabilities (TPM_AT values).
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum AcCapability {
+    /// In a command, a non-specific request for AC information.
+    /// In a response, it indicates that the data is not meaningful.
+    Any,
+    /// A TCG defined, device specific, error.
+    Error,
+    /// The most significant 32 bits of a pairing value for the AC.
+    Pv1,
+    /// A vendor specific value, must be at least TPM2_AT_VEND.
+    Vendor(TPM_AT),
+}
+
+impl From<AcCapability> for TPM_AT {
+    fn from(ac_capability: AcCapability) -> TPM_AT {
+        match ac_capability {
+            AcCapability::Any => TPM2_AT_ANY,
+            AcCapability::Error => TPM2_AT_ERROR,
+            AcCapability::Pv1 => TPM2_AT_PV1,
+            AcCapability::Vendor(value) => value,
+        }
+    }
+}
+
+impl TryFrom<TPM_AT> for AcCapability {
+    type Error = Error;
+    fn try_from(tpm_at: TPM_AT) -> Result<AcCapability> {
+        match tpm_at {
+            TPM2_AT_ANY => Ok(AcCapability::Any),
+            TPM2_AT_ERROR => Ok(AcCapability::Error),
+            TPM2_AT_PV1 => Ok(AcCapability::Pv1),
+            value if value >= TPM2_AT_VEND => Ok(AcCapability::Vendor(value)),
+            _ => {
+                error!("Error: value = {} did not match any AcCapability.", tpm_at);
+                Err(Error::local_error(WrapperErrorKind::InvalidParam))
+            }
+        }
+    }
+}
@@ -0,0 +1,51 @@
+// Copyright 2021 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use crate::{
+    constants::tss::{
+        TPM2_ACTIVE_SESSION_FIRST, TPM2_LOADED_SESSION_FIRST, TPM2_NV_INDEX_FIRST, TPM2_PCR_FIRST,
+        TPM2_PERSISTENT_FIRST, TPM2_TRANSIENT_FIRST,
+    },
+    tss2_esys::TPM2_HC,
+    Error, Result, WrapperErrorKind,
+};
+use log::error;
+use num_derive::{FromPrimitive, ToPrimitive};
+use num_traits::{FromPrimitive, ToPrimitive};
+use std::convert::TryFrom;
+
+/// Enum representing the areas of handles that can
+/// be listed with the handles capability.
+///
+/// # Details
+/// Each value is the first handle of the area, which is the
+/// property used when getting the handles capability.
+#[derive(FromPrimitive, ToPrimitive, Debug, Copy, Clone, PartialEq, Eq)]
+#[repr(u32)]
+pub enum HandleArea {
+    PcrSession = TPM2_PCR_FIRST,
+    NvIndex = TPM2_NV_INDEX_FIRST,
+    LoadedSession = TPM2_LOADED_SESSION_FIRST,
+    SavedSession = TPM2_ACTIVE_SESSION_FIRST,
+    Transient = TPM2_TRANSIENT_FIRST,
+    Persistent = TPM2_PERSISTENT_FIRST,
+}
+
+impl From<HandleArea> for TPM2_HC {
+    fn from(handle_area: HandleArea) -> TPM2_HC {
+        // The values are well defined so this cannot fail.
+        handle_area.to_u32().unwrap()
+    }
+}
+
+impl TryFrom<TPM2_HC> for HandleArea {
+    type Error = Error;
+    fn try_from(tpm_handle_area: TPM2_HC) -> Result<HandleArea> {
+        HandleArea::from_u32(tpm_handle_area).ok_or_else(|| {
+            error!(
+                "Error: value = {} did not match any HandleArea.",
+                tpm_handle_area
+            );
+            Error::local_error(WrapperErrorKind::InvalidParam)
+        })
+    }
+}
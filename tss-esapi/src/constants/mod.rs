@@ -40,6 +40,10 @@ pub mod session_type;
 /// Constants -> TPM_CAP section of the specfication
 pub mod capabilities;
 
+/// Representation of the constants defined in the
+/// Constants -> TPM_CC section of the specfication
+pub mod command_code;
+
 /// Representation of the return code TSS2_RC (TPM_RC)
 pub mod response_code;
 
@@ -47,10 +51,26 @@ pub mod response_code;
 /// NV Storage -> TPM_NT section of the specfication
 pub mod nv_index_type;
 
+/// Representation of the constants defined in the
+/// Constants -> TPM_EO section of the specfication
+pub mod arithmetic_operation;
+
+/// Representation of the constants defined in the
+/// Constants -> TPM_AT section of the specfication
+pub mod ac_capability;
+
+/// Representation of the handle ranges used with the
+/// Constants -> TPM_CAP_HANDLES capability
+pub mod handle_area;
+
+pub use ac_capability::AcCapability;
+pub use arithmetic_operation::ArithmeticOperation;
 pub use capabilities::CapabilityType;
+pub use command_code::CommandCode;
+pub use handle_area::HandleArea;
 pub use nv_index_type::NvIndexType;
 pub use property_tag::PropertyTag;
-pub use response_code::{ResponseCode, Tss2ResponseCode, Tss2ResponseCodeKind};
+pub use response_code::{ResponseCode, TpmResponseCode, Tss2ResponseCode, Tss2ResponseCodeKind};
 pub use session_type::SessionType;
 pub use startup_type::StartupType;
 pub use structure_tags::StructureTag;
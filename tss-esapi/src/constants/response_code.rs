@@ -82,7 +82,8 @@ impl Tss2ResponseCode {
         self == Tss2ResponseCode::Success
     }
 
-    fn is_warning(self) -> bool {
+    /// Check whether the response code is a warning
+    pub(crate) fn is_warning(self) -> bool {
         match self {
             Tss2ResponseCode::Success => false,
             Tss2ResponseCode::FormatZero(rc) => rc.severity(),
@@ -90,6 +91,15 @@ impl Tss2ResponseCode {
         }
     }
 
+    /// Get the raw value of the response code
+    pub(crate) fn value(self) -> TSS2_RC {
+        match self {
+            Tss2ResponseCode::Success => 0,
+            Tss2ResponseCode::FormatZero(rc) => rc.0,
+            Tss2ResponseCode::FormatOne(rc) => rc.0,
+        }
+    }
+
     fn error_number(self) -> u32 {
         match self {
             Tss2ResponseCode::Success => 0,
@@ -449,6 +459,218 @@ impl std::fmt::Display for Tss2ResponseCode {
     }
 }
 
+/// Named TPM response codes that are frequently returned by the TPM.
+///
+/// # Details
+/// The response codes that do not have a named variant are returned as
+/// [TpmResponseCode::Unknown], holding the raw value of the response code.
+#[derive(PartialEq, Eq, Copy, Clone, Debug)]
+pub enum TpmResponseCode {
+    // Format-zero errors
+    /// TPM_RC_INITIALIZE
+    Initialize,
+    /// TPM_RC_FAILURE
+    Failure,
+    /// TPM_RC_SEQUENCE
+    Sequence,
+    /// TPM_RC_DISABLED
+    Disabled,
+    /// TPM_RC_AUTH_MISSING
+    AuthMissing,
+    /// TPM_RC_POLICY
+    Policy,
+    /// TPM_RC_PCR
+    Pcr,
+    /// TPM_RC_PCR_CHANGED
+    PcrChanged,
+    /// TPM_RC_AUTH_UNAVAILABLE
+    AuthUnavailable,
+    /// TPM_RC_REBOOT
+    Reboot,
+    /// TPM_RC_COMMAND_SIZE
+    CommandSize,
+    /// TPM_RC_COMMAND_CODE
+    CommandCode,
+    /// TPM_RC_NV_RANGE
+    NvRange,
+    /// TPM_RC_NV_SIZE
+    NvSize,
+    /// TPM_RC_NV_LOCKED
+    NvLocked,
+    /// TPM_RC_NV_AUTHORIZATION
+    NvAuthorization,
+    /// TPM_RC_NV_UNINITIALIZED
+    NvUninitialized,
+    /// TPM_RC_NV_SPACE
+    NvSpace,
+    /// TPM_RC_NV_DEFINED
+    NvDefined,
+    /// TPM_RC_BAD_CONTEXT
+    BadContext,
+    /// TPM_RC_CPHASH
+    CpHash,
+    /// TPM_RC_PARENT
+    Parent,
+    /// TPM_RC_SENSITIVE
+    Sensitive,
+    // Format-one errors
+    /// TPM_RC_ASYMMETRIC
+    Asymmetric,
+    /// TPM_RC_ATTRIBUTES
+    AttributesMismatch,
+    /// TPM_RC_HASH
+    Hash,
+    /// TPM_RC_VALUE
+    Value,
+    /// TPM_RC_HIERARCHY
+    Hierarchy,
+    /// TPM_RC_KEY_SIZE
+    KeySize,
+    /// TPM_RC_TYPE
+    Type,
+    /// TPM_RC_HANDLE
+    Handle,
+    /// TPM_RC_RANGE
+    Range,
+    /// TPM_RC_AUTH_FAIL
+    AuthFail,
+    /// TPM_RC_NONCE
+    Nonce,
+    /// TPM_RC_SCHEME
+    Scheme,
+    /// TPM_RC_SIZE
+    Size,
+    /// TPM_RC_SYMMETRIC
+    Symmetric,
+    /// TPM_RC_TAG
+    Tag,
+    /// TPM_RC_SELECTOR
+    Selector,
+    /// TPM_RC_INSUFFICIENT
+    Insufficient,
+    /// TPM_RC_SIGNATURE
+    Signature,
+    /// TPM_RC_KEY
+    Key,
+    /// TPM_RC_POLICY_FAIL
+    PolicyFail,
+    /// TPM_RC_INTEGRITY
+    Integrity,
+    /// TPM_RC_TICKET
+    Ticket,
+    /// TPM_RC_BAD_AUTH
+    BadAuth,
+    /// TPM_RC_EXPIRED
+    Expired,
+    /// TPM_RC_POLICY_CC
+    PolicyCc,
+    /// TPM_RC_CURVE
+    Curve,
+    /// TPM_RC_ECC_POINT
+    EccPoint,
+    // Warnings
+    /// TPM_RC_CONTEXT_GAP
+    ContextGap,
+    /// TPM_RC_OBJECT_MEMORY
+    ObjectMemory,
+    /// TPM_RC_SESSION_MEMORY
+    SessionMemory,
+    /// TPM_RC_MEMORY
+    Memory,
+    /// TPM_RC_LOCALITY
+    Locality,
+    /// TPM_RC_YIELDED
+    Yielded,
+    /// TPM_RC_CANCELED
+    Canceled,
+    /// TPM_RC_TESTING
+    Testing,
+    /// TPM_RC_NV_RATE
+    NvRate,
+    /// TPM_RC_LOCKOUT
+    Lockout,
+    /// TPM_RC_RETRY
+    Retry,
+    /// TPM_RC_NV_UNAVAILABLE
+    NvUnavailable,
+    /// A response code without a named variant, holding the raw value
+    /// of the response code.
+    Unknown(TSS2_RC),
+}
+
+impl TpmResponseCode {
+    /// Get the named TPM response code, or `None` for the success code.
+    pub(crate) fn from_response_code(response_code: Tss2ResponseCode) -> Option<Self> {
+        let tpm_response_code = match response_code.kind() {
+            Some(Tss2ResponseCodeKind::Success) => return None,
+            Some(Tss2ResponseCodeKind::Initialize) => TpmResponseCode::Initialize,
+            Some(Tss2ResponseCodeKind::Failure) => TpmResponseCode::Failure,
+            Some(Tss2ResponseCodeKind::Sequence) => TpmResponseCode::Sequence,
+            Some(Tss2ResponseCodeKind::Disabled) => TpmResponseCode::Disabled,
+            Some(Tss2ResponseCodeKind::AuthMissing) => TpmResponseCode::AuthMissing,
+            Some(Tss2ResponseCodeKind::Policy) => TpmResponseCode::Policy,
+            Some(Tss2ResponseCodeKind::Pcr) => TpmResponseCode::Pcr,
+            Some(Tss2ResponseCodeKind::PcrChanged) => TpmResponseCode::PcrChanged,
+            Some(Tss2ResponseCodeKind::AuthUnavailable) => TpmResponseCode::AuthUnavailable,
+            Some(Tss2ResponseCodeKind::Reboot) => TpmResponseCode::Reboot,
+            Some(Tss2ResponseCodeKind::CommandSize) => TpmResponseCode::CommandSize,
+            Some(Tss2ResponseCodeKind::CommandCode) => TpmResponseCode::CommandCode,
+            Some(Tss2ResponseCodeKind::NvRange) => TpmResponseCode::NvRange,
+            Some(Tss2ResponseCodeKind::NvSize) => TpmResponseCode::NvSize,
+            Some(Tss2ResponseCodeKind::NvLocked) => TpmResponseCode::NvLocked,
+            Some(Tss2ResponseCodeKind::NvAuthorization) => TpmResponseCode::NvAuthorization,
+            Some(Tss2ResponseCodeKind::NvUninitialized) => TpmResponseCode::NvUninitialized,
+            Some(Tss2ResponseCodeKind::NvSpace) => TpmResponseCode::NvSpace,
+            Some(Tss2ResponseCodeKind::NvDefined) => TpmResponseCode::NvDefined,
+            Some(Tss2ResponseCodeKind::BadContext) => TpmResponseCode::BadContext,
+            Some(Tss2ResponseCodeKind::CpHash) => TpmResponseCode::CpHash,
+            Some(Tss2ResponseCodeKind::Parent) => TpmResponseCode::Parent,
+            Some(Tss2ResponseCodeKind::Sensitive) => TpmResponseCode::Sensitive,
+            Some(Tss2ResponseCodeKind::Asymmetric) => TpmResponseCode::Asymmetric,
+            Some(Tss2ResponseCodeKind::Attributes) => TpmResponseCode::AttributesMismatch,
+            Some(Tss2ResponseCodeKind::Hash) => TpmResponseCode::Hash,
+            Some(Tss2ResponseCodeKind::Value) => TpmResponseCode::Value,
+            Some(Tss2ResponseCodeKind::Hierarchy) => TpmResponseCode::Hierarchy,
+            Some(Tss2ResponseCodeKind::KeySize) => TpmResponseCode::KeySize,
+            Some(Tss2ResponseCodeKind::Type) => TpmResponseCode::Type,
+            Some(Tss2ResponseCodeKind::Handle) => TpmResponseCode::Handle,
+            Some(Tss2ResponseCodeKind::Range) => TpmResponseCode::Range,
+            Some(Tss2ResponseCodeKind::AuthFail) => TpmResponseCode::AuthFail,
+            Some(Tss2ResponseCodeKind::Nonce) => TpmResponseCode::Nonce,
+            Some(Tss2ResponseCodeKind::Scheme) => TpmResponseCode::Scheme,
+            Some(Tss2ResponseCodeKind::Size) => TpmResponseCode::Size,
+            Some(Tss2ResponseCodeKind::Symmetric) => TpmResponseCode::Symmetric,
+            Some(Tss2ResponseCodeKind::Tag) => TpmResponseCode::Tag,
+            Some(Tss2ResponseCodeKind::Selector) => TpmResponseCode::Selector,
+            Some(Tss2ResponseCodeKind::Insufficient) => TpmResponseCode::Insufficient,
+            Some(Tss2ResponseCodeKind::Signature) => TpmResponseCode::Signature,
+            Some(Tss2ResponseCodeKind::Key) => TpmResponseCode::Key,
+            Some(Tss2ResponseCodeKind::PolicyFail) => TpmResponseCode::PolicyFail,
+            Some(Tss2ResponseCodeKind::Integrity) => TpmResponseCode::Integrity,
+            Some(Tss2ResponseCodeKind::Ticket) => TpmResponseCode::Ticket,
+            Some(Tss2ResponseCodeKind::BadAuth) => TpmResponseCode::BadAuth,
+            Some(Tss2ResponseCodeKind::Expired) => TpmResponseCode::Expired,
+            Some(Tss2ResponseCodeKind::PolicyCc) => TpmResponseCode::PolicyCc,
+            Some(Tss2ResponseCodeKind::Curve) => TpmResponseCode::Curve,
+            Some(Tss2ResponseCodeKind::EccPoint) => TpmResponseCode::EccPoint,
+            Some(Tss2ResponseCodeKind::ContextGap) => TpmResponseCode::ContextGap,
+            Some(Tss2ResponseCodeKind::ObjectMemory) => TpmResponseCode::ObjectMemory,
+            Some(Tss2ResponseCodeKind::SessionMemory) => TpmResponseCode::SessionMemory,
+            Some(Tss2ResponseCodeKind::Memory) => TpmResponseCode::Memory,
+            Some(Tss2ResponseCodeKind::Locality) => TpmResponseCode::Locality,
+            Some(Tss2ResponseCodeKind::Yielded) => TpmResponseCode::Yielded,
+            Some(Tss2ResponseCodeKind::Canceled) => TpmResponseCode::Canceled,
+            Some(Tss2ResponseCodeKind::Testing) => TpmResponseCode::Testing,
+            Some(Tss2ResponseCodeKind::NvRate) => TpmResponseCode::NvRate,
+            Some(Tss2ResponseCodeKind::Lockout) => TpmResponseCode::Lockout,
+            Some(Tss2ResponseCodeKind::Retry) => TpmResponseCode::Retry,
+            Some(Tss2ResponseCodeKind::NvUnavailable) => TpmResponseCode::NvUnavailable,
+            _ => TpmResponseCode::Unknown(response_code.value()),
+        };
+        Some(tpm_response_code)
+    }
+}
+
 impl From<TSS2_RC> for Tss2ResponseCode {
     fn from(rc: TSS2_RC) -> Self {
         Tss2ResponseCode::from_tss_rc(rc)
@@ -172,7 +172,8 @@ pub const TPM2_CC_EncryptDecrypt2: TPM2_CC = 0x00000193;
 pub const TPM2_CC_AC_GetCapability: TPM2_CC = 0x00000194;
 pub const TPM2_CC_AC_Send: TPM2_CC = 0x00000195;
 pub const TPM2_CC_Policy_AC_SendSelect: TPM2_CC = 0x00000196;
-pub const TPM2_CC_LAST: TPM2_CC = 0x00000196;
+pub const TPM2_CC_ACT_SetTimeout: TPM2_CC = 0x00000198;
+pub const TPM2_CC_LAST: TPM2_CC = 0x00000198;
 pub const TPM2_CC_Vendor_TCG_Test: TPM2_CC = 0x20000000;
 
 pub const TPM2_SPEC_FAMILY: TPM2_SPEC = 0x322E3000; /* ASCII 2.0 with null terminator */
@@ -326,6 +327,11 @@ pub const TPM2_EO_UNSIGNED_LE: TPM2_EO = 0x0009; /* A  B unsigned */
 pub const TPM2_EO_BITSET: TPM2_EO = 0x000A; /* All bits SET in B are SET in A. ABB */
 pub const TPM2_EO_BITCLEAR: TPM2_EO = 0x000B; /* All bits SET in B are CLEAR in A. AB0 */
 
+pub const TPM2_AT_ANY: TPM_AT = 0x00000000; /* in a command, a non-specific request for AC information; in a response, indicates that outputData is not meaningful */
+pub const TPM2_AT_ERROR: TPM_AT = 0x00000001; /* indicates a TCG defined, device-specific error */
+pub const TPM2_AT_PV1: TPM_AT = 0x00000002; /* indicates the most significant 32 bits of a pairing value for the AC */
+pub const TPM2_AT_VEND: TPM_AT = 0x80000000; /* value added to a TPM_AT to indicate a vendor-specific tag value */
+
 pub const TPM2_ST_RSP_COMMAND: TPM2_ST = 0x00C4; /* Tag value for a response used when there is an error in the tag. This is also the value returned from a TPM 1.2 when an error occurs. This value is used in this specification because an error in the command tag may prevent determination of the family. When this tag is used in the response the response code will be TPM2_RC_BAD_TAG 0 1E16 which has the same numeric value as the TPM 1.2 response code for TPM_BADTAG. NOTE In a previously published version of this specification TPM2_RC_BAD_TAG was incorrectly assigned a value of 0x030 instead of 30 0x01e. Some implementations my return the old value instead of the new value. */
 pub const TPM2_ST_NULL: TPM2_ST = 0x8000; /* no structure type specified */
 pub const TPM2_ST_NO_SESSIONS: TPM2_ST = 0x8001; /* tag value for a command response for a command defined in this specification indicating that the command response has no attached sessions and no authorizationSizeparameterSize value is present. If the responseCode from the TPM is not TPM2_RC_SUCCESS then the response tag shall have this value. */
@@ -16,7 +16,13 @@ use log::{error, info};
 use mbox::MBox;
 use std::collections::HashMap;
 use std::convert::TryFrom;
+use std::panic::{self, AssertUnwindSafe};
 use std::ptr::null_mut;
+use std::thread;
+use std::time::Duration;
+
+/// Delay before retrying a command, multiplied by the number of failed attempts.
+const RETRY_BACKOFF: Duration = Duration::from_millis(10);
 
 /// Safe abstraction over an ESYS_CONTEXT.
 ///
@@ -66,6 +72,7 @@ pub struct Context {
 
 // Implementation of the TPM commands
 mod tpm_commands;
+pub use tpm_commands::CapabilityIterator;
 // Implementation of the ESAPI session administration
 // functions.
 mod session_administration;
@@ -231,6 +238,10 @@ impl Context {
     }
 
     /// Execute the closure in f with the specified set of sessions, and sets the original sessions back afterwards
+    ///
+    /// # Details
+    /// The original sessions are restored even if the closure panics,
+    /// in which case the panic is resumed once the sessions are restored.
     pub fn execute_with_sessions<F, T>(
         &mut self,
         session_handles: (
@@ -247,11 +258,14 @@ impl Context {
         let oldses = self.sessions();
         self.set_sessions(session_handles);
 
-        let res = f(self);
+        let res = panic::catch_unwind(AssertUnwindSafe(|| f(self)));
 
         self.set_sessions(oldses);
 
-        res
+        match res {
+            Ok(res) => res,
+            Err(payload) => panic::resume_unwind(payload),
+        }
     }
 
     /// Executes the closure with a single session set, and the others set to None
@@ -322,6 +336,39 @@ impl Context {
         res
     }
 
+    /// Execute the closure in f, and execute it again as long as it fails with an error
+    /// that is retryable, up to `max_attempts` times in total.
+    ///
+    /// # Details
+    /// The closure is executed again after a short delay, that increases with
+    /// the number of failed attempts, when it returns an error for which
+    /// [Error::is_retryable] is true. Any other result is returned directly.
+    ///
+    /// # Errors
+    /// * if `max_attempts` is zero, an `InvalidParam` wrapper error is returned.
+    /// * if every attempt fails with a retryable error, the error of the last
+    ///   attempt is returned.
+    pub fn execute_with_retry<F, T>(&mut self, max_attempts: u32, mut f: F) -> Result<T>
+    where
+        F: FnMut(&mut Context) -> Result<T>,
+    {
+        if max_attempts == 0 {
+            error!("The closure has to be executed at least once");
+            return Err(Error::local_error(ErrorKind::InvalidParam));
+        }
+        let mut attempt = 1;
+        loop {
+            match f(self) {
+                Err(e) if e.is_retryable() && attempt < max_attempts => {
+                    info!("Retrying after attempt {} failed: {}", attempt, e);
+                    thread::sleep(RETRY_BACKOFF * attempt);
+                    attempt += 1;
+                }
+                res => return res,
+            }
+        }
+    }
+
     /// Determine a TPM property
     ///
     /// # Details
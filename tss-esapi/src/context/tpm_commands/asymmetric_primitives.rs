@@ -1,13 +1,19 @@
 // Copyright 2021 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
 use crate::{
+    constants::Tss2ResponseCodeKind,
     handles::KeyHandle,
+    interface_types::{
+        algorithm::{EccSchemeAlgorithm, HashingAlgorithm},
+        ecc::EccCurve,
+    },
     structures::Data,
-    structures::{EccPoint, PublicKeyRsa, RsaDecryptionScheme},
+    structures::{AlgorithmDetailEcc, EccPoint, HashScheme, PublicKeyRsa, RsaDecryptionScheme},
     tss2_esys::*,
-    Context, Error, Result,
+    Context, Error, Result, WrapperErrorKind,
 };
 use log::error;
+use mbox::MBox;
 use std::convert::TryFrom;
 use std::convert::TryInto;
 use std::ptr::null_mut;
@@ -79,6 +85,72 @@ impl Context {
         }
     }
 
+    /// Perform an asymmetric RSA encryption with the OAEP scheme.
+    ///
+    /// # Arguments
+    /// * `key_handle` - The [KeyHandle] of the RSA key.
+    /// * `message` - The data to encrypt.
+    /// * `hashing_algorithm` - The hashing algorithm of the OAEP scheme.
+    /// * `label` - The optional label to associate with the message.
+    ///
+    /// # Details
+    /// The label is null-terminated in the same way as in [Context::rsa_oaep_decrypt].
+    ///
+    /// # Errors
+    /// * if the label, with the terminating null byte, is larger than
+    ///   [Data::MAX_SIZE], a `WrongParamSize` wrapper error is returned.
+    pub fn rsa_oaep_encrypt(
+        &mut self,
+        key_handle: KeyHandle,
+        message: PublicKeyRsa,
+        hashing_algorithm: HashingAlgorithm,
+        label: Option<&[u8]>,
+    ) -> Result<PublicKeyRsa> {
+        let label = oaep_label(label)?;
+        self.rsa_encrypt(
+            key_handle,
+            message,
+            RsaDecryptionScheme::Oaep(HashScheme::new(hashing_algorithm)),
+            label,
+        )
+    }
+
+    /// Perform an asymmetric RSA decryption with the OAEP scheme.
+    ///
+    /// # Arguments
+    /// * `key_handle` - The [KeyHandle] of the RSA decryption key.
+    /// * `cipher_text` - The data to decrypt.
+    /// * `hashing_algorithm` - The hashing algorithm of the OAEP scheme.
+    /// * `label` - The optional label that was associated with the message.
+    ///
+    /// # Details
+    /// The TPM requires OAEP labels to be null-terminated, and includes the
+    /// terminating null byte when computing the label digest. A null byte is
+    /// therefore appended to the label if it does not end with one. A message
+    /// encrypted outside the TPM must use the label with the null byte.
+    ///
+    /// # Errors
+    /// * if the label, with the terminating null byte, is larger than
+    ///   [Data::MAX_SIZE], a `WrongParamSize` wrapper error is returned.
+    /// * if the decrypted message is larger than [Data::MAX_SIZE], a
+    ///   `WrongParamSize` wrapper error is returned.
+    pub fn rsa_oaep_decrypt(
+        &mut self,
+        key_handle: KeyHandle,
+        cipher_text: PublicKeyRsa,
+        hashing_algorithm: HashingAlgorithm,
+        label: Option<&[u8]>,
+    ) -> Result<Data> {
+        let label = oaep_label(label)?;
+        let message = self.rsa_decrypt(
+            key_handle,
+            cipher_text,
+            RsaDecryptionScheme::Oaep(HashScheme::new(hashing_algorithm)),
+            label,
+        )?;
+        Data::try_from(message.value())
+    }
+
     /// Generate an ephemeral key pair.
     ///
     /// # Arguments
@@ -203,7 +275,9 @@ impl Context {
         let ret = Error::from_tss_rc(ret);
 
         if ret.is_success() {
-            Ok(unsafe { ((*z_point).point.try_into()?, (*pub_point).point.try_into()?) })
+            let z_point = unsafe { MBox::<TPM2B_ECC_POINT>::from_raw(z_point) };
+            let pub_point = unsafe { MBox::<TPM2B_ECC_POINT>::from_raw(pub_point) };
+            Ok((z_point.point.try_into()?, pub_point.point.try_into()?))
         } else {
             error!("Error when generating ECDH keypair: {}", ret);
             Err(ret)
@@ -222,6 +296,13 @@ impl Context {
     /// It will perform the multiplication of the provided `in_point` with the private key and
     /// return the coordinates of the resultant point.
     ///
+    /// The key referenced by `key_handle` must have the `decrypt` attribute set and an
+    /// ECDH scheme (or the Null scheme).
+    ///
+    /// # Errors
+    /// * if the TPM rejects `in_point` because it is not on the curve of the key
+    ///   referenced by `key_handle`, an `InvalidParam` wrapper error will be returned
+    ///
     /// # Example
     ///
     /// ```rust
@@ -335,13 +416,150 @@ impl Context {
         let ret = Error::from_tss_rc(ret);
 
         if ret.is_success() {
-            Ok(unsafe { (*point).point.try_into()? })
+            let point = unsafe { MBox::<TPM2B_ECC_POINT>::from_raw(point) };
+            Ok(point.point.try_into()?)
         } else {
             error!("Error when performing ECDH ZGen: {}", ret);
+            if let Error::Tss2Error(rc) = ret {
+                if rc.kind() == Some(Tss2ResponseCodeKind::EccPoint) {
+                    error!("The in_point is not on the curve of the key used for ECDH ZGen");
+                    return Err(Error::local_error(WrapperErrorKind::InvalidParam));
+                }
+            }
+            Err(ret)
+        }
+    }
+
+    /// Get the parameters of an ECC curve identified by its [EccCurve].
+    ///
+    /// # Details
+    /// This command returns the domain parameters the TPM uses for the
+    /// specified curve, which allows ECC arithmetic to be performed
+    /// outside of the TPM.
+    ///
+    /// # Arguments
+    /// * `curve_id` - The [EccCurve] for which the parameters are requested.
+    ///
+    /// ```rust
+    /// # use tss_esapi::{Context, TctiNameConf, interface_types::ecc::EccCurve};
+    /// # let mut context =
+    /// #     Context::new(
+    /// #         TctiNameConf::from_environment_variable().expect("Failed to get TCTI"),
+    /// #     ).expect("Failed to create Context");
+    /// let parameters = context
+    ///     .ecc_parameters(EccCurve::NistP256)
+    ///     .expect("Failed to get ECC parameters");
+    /// assert_eq!(parameters.key_size(), 256);
+    /// ```
+    pub fn ecc_parameters(&mut self, curve_id: EccCurve) -> Result<AlgorithmDetailEcc> {
+        let mut parameters_ptr = null_mut();
+        let ret = unsafe {
+            Esys_ECC_Parameters(
+                self.mut_context(),
+                self.optional_session_1(),
+                self.optional_session_2(),
+                self.optional_session_3(),
+                curve_id.into(),
+                &mut parameters_ptr,
+            )
+        };
+        let ret = Error::from_tss_rc(ret);
+
+        if ret.is_success() {
+            let parameters = unsafe { MBox::<TPMS_ALGORITHM_DETAIL_ECC>::from_raw(parameters_ptr) };
+            AlgorithmDetailEcc::try_from(*parameters)
+        } else {
+            error!("Error when getting ECC parameters: {}", ret);
+            Err(ret)
+        }
+    }
+
+    /// Perform the second part of a two-phase key exchange.
+    ///
+    /// # Details
+    /// This command supports two-phase key exchange protocols. It is used in combination
+    /// with [ec_ephemeral](Context::ec_ephemeral), which creates the ephemeral key on the
+    /// TPM, and computes the Z values using the static key referenced by `key_a`, the ephemeral
+    /// key referenced by `counter` and the public points provided by the other party.
+    ///
+    /// # Arguments
+    /// * `key_a` - A [KeyHandle] of an unrestricted decryption key of type ECC.
+    /// * `in_qs_b` - The static public key of the other party.
+    /// * `in_qe_b` - The ephemeral public key of the other party.
+    /// * `in_scheme` - The key exchange scheme. This must be one of
+    ///   [EccSchemeAlgorithm::EcDh], [EccSchemeAlgorithm::EcMqv] or [EccSchemeAlgorithm::Sm2].
+    /// * `counter` - The value returned by [ec_ephemeral](Context::ec_ephemeral) when the
+    ///   ephemeral key was created.
+    ///
+    /// # Returns
+    /// The command returns a tuple consisting of:
+    /// * `out_z1` - The X and Y coordinates of the first computed value (scheme dependent).
+    ///   For [EccSchemeAlgorithm::EcDh] this is the product of the static private key of
+    ///   `key_a` and `in_qs_b`.
+    /// * `out_z2` - The X and Y coordinates of the second computed value (scheme dependent).
+    ///   For [EccSchemeAlgorithm::EcDh] this is the product of the ephemeral private key
+    ///   and `in_qe_b`.
+    ///
+    /// # Errors
+    /// * if `in_scheme` is not a key exchange scheme a `InvalidParam` wrapper error is returned.
+    pub fn zgen_2phase(
+        &mut self,
+        key_a: KeyHandle,
+        in_qs_b: EccPoint,
+        in_qe_b: EccPoint,
+        in_scheme: EccSchemeAlgorithm,
+        counter: u16,
+    ) -> Result<(EccPoint, EccPoint)> {
+        match in_scheme {
+            EccSchemeAlgorithm::EcDh | EccSchemeAlgorithm::EcMqv | EccSchemeAlgorithm::Sm2 => {}
+            _ => {
+                error!(
+                    "Invalid scheme {:?} for two-phase key exchange, expected EcDh, EcMqv or Sm2",
+                    in_scheme
+                );
+                return Err(Error::local_error(WrapperErrorKind::InvalidParam));
+            }
+        }
+
+        let mut out_z1_ptr = null_mut();
+        let mut out_z2_ptr = null_mut();
+        let ret = unsafe {
+            Esys_ZGen_2Phase(
+                self.mut_context(),
+                key_a.into(),
+                self.required_session_1()?,
+                self.optional_session_2(),
+                self.optional_session_3(),
+                &in_qs_b.into(),
+                &in_qe_b.into(),
+                in_scheme.into(),
+                counter,
+                &mut out_z1_ptr,
+                &mut out_z2_ptr,
+            )
+        };
+        let ret = Error::from_tss_rc(ret);
+
+        if ret.is_success() {
+            let out_z1 = unsafe { MBox::<TPM2B_ECC_POINT>::from_raw(out_z1_ptr) };
+            let out_z2 = unsafe { MBox::<TPM2B_ECC_POINT>::from_raw(out_z2_ptr) };
+            Ok((out_z1.point.try_into()?, out_z2.point.try_into()?))
+        } else {
+            error!("Error when performing ZGen 2Phase: {}", ret);
             Err(ret)
         }
     }
+}
 
-    // Missing function: ECC_Parameters
-    // Missing function: ZGen_2Phase
+/// Creates the null-terminated label expected by the TPM for the OAEP scheme.
+fn oaep_label(label: Option<&[u8]>) -> Result<Data> {
+    match label {
+        Some(label) if !label.is_empty() && label.last() != Some(&0) => {
+            let mut null_terminated_label = label.to_vec();
+            null_terminated_label.push(0);
+            Data::try_from(null_terminated_label)
+        }
+        Some(label) => Data::try_from(label),
+        None => Ok(Data::default()),
+    }
 }
@@ -1,9 +1,170 @@
 // Copyright 2021 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
+//! The attached component commands were added to the ESAPI in version 3
+//! of the tpm2-tss library. They are therefore only available when the
+//! bindings are generated against that version of the library, i.e. when the
+//! `generate-bindings` feature is enabled and version 3 of the library is found,
+//! which sets `tpm2_tss_version = "3"`.
 use crate::Context;
+#[cfg(all(feature = "generate-bindings", tpm2_tss_version = "3"))]
+use crate::{
+    constants::AcCapability,
+    handles::{AttachedComponentHandle, AuthHandle, ObjectHandle, SessionHandle},
+    interface_types::session_handles::PolicySession,
+    structures::{AcOutput, MaxBuffer, Name},
+    tss2_esys::*,
+    Error, Result, WrapperErrorKind,
+};
+#[cfg(all(feature = "generate-bindings", tpm2_tss_version = "3"))]
+use log::error;
+#[cfg(all(feature = "generate-bindings", tpm2_tss_version = "3"))]
+use mbox::MBox;
+#[cfg(all(feature = "generate-bindings", tpm2_tss_version = "3"))]
+use std::convert::TryFrom;
+#[cfg(all(feature = "generate-bindings", tpm2_tss_version = "3"))]
+use std::ptr::null_mut;
 
 impl Context {
-    // Missing function: AC_GetCapability
-    // Missing function: AC_Send
-    // Missing function: Policy_AC_SendSelect
+    /// Gets the capabilities of an attached component.
+    ///
+    /// # Arguments
+    /// * `ac` - The [AttachedComponentHandle] of the attached component.
+    /// * `capability` - The [AcCapability] to start reporting from.
+    /// * `count` - The maximum number of values to return.
+    ///
+    /// # Returns
+    /// A tuple containing a boolean indicating if there is more data
+    /// available and the [AcOutput] values that were returned.
+    #[cfg(all(feature = "generate-bindings", tpm2_tss_version = "3"))]
+    pub fn ac_get_capability(
+        &mut self,
+        ac: AttachedComponentHandle,
+        capability: AcCapability,
+        count: u32,
+    ) -> Result<(bool, Vec<AcOutput>)> {
+        let mut more_data = 0;
+        let mut capabilities_data_ptr = null_mut();
+        let ret = unsafe {
+            Esys_AC_GetCapability(
+                self.mut_context(),
+                self.optional_session_1(),
+                self.optional_session_2(),
+                self.optional_session_3(),
+                ac.into(),
+                capability.into(),
+                count,
+                &mut more_data,
+                &mut capabilities_data_ptr,
+            )
+        };
+        let ret = Error::from_tss_rc(ret);
+        if ret.is_success() {
+            let capabilities_data =
+                unsafe { MBox::<TPML_AC_CAPABILITIES>::from_raw(capabilities_data_ptr) };
+            let count = capabilities_data.count as usize;
+            if count > capabilities_data.acCapabilities.len() {
+                error!("Error: Invalid TPML_AC_CAPABILITIES count {}", count);
+                return Err(Error::local_error(WrapperErrorKind::WrongValueFromTpm));
+            }
+            let ac_outputs = capabilities_data.acCapabilities[..count]
+                .iter()
+                .map(|ac_output| AcOutput::try_from(*ac_output))
+                .collect::<Result<Vec<AcOutput>>>()?;
+            Ok((more_data != 0, ac_outputs))
+        } else {
+            error!(
+                "Error when getting attached component capabilities: {}",
+                ret
+            );
+            Err(ret)
+        }
+    }
+
+    /// Sends an object to an attached component.
+    ///
+    /// # Arguments
+    /// * `send_object` - The [ObjectHandle] of the object to be sent.
+    /// * `auth_handle` - The [AuthHandle] of the hierarchy or NV index that
+    ///   authorizes sending the object to the attached component.
+    /// * `ac` - The [AttachedComponentHandle] of the attached component.
+    /// * `ac_data_in` - Optional non sensitive information related to the object.
+    ///
+    /// # Details
+    /// This method requires two authorization sessions, for the `send_object`
+    /// and the `auth_handle`.
+    #[cfg(all(feature = "generate-bindings", tpm2_tss_version = "3"))]
+    pub fn ac_send(
+        &mut self,
+        send_object: ObjectHandle,
+        auth_handle: AuthHandle,
+        ac: AttachedComponentHandle,
+        ac_data_in: MaxBuffer,
+    ) -> Result<AcOutput> {
+        let mut ac_data_out_ptr = null_mut();
+        let ret = unsafe {
+            Esys_AC_Send(
+                self.mut_context(),
+                send_object.into(),
+                auth_handle.into(),
+                ac.into(),
+                self.required_session_1()?,
+                self.required_session_2()?,
+                self.optional_session_3(),
+                &ac_data_in.into(),
+                &mut ac_data_out_ptr,
+            )
+        };
+        let ret = Error::from_tss_rc(ret);
+        if ret.is_success() {
+            let ac_data_out = unsafe { MBox::<TPMS_AC_OUTPUT>::from_raw(ac_data_out_ptr) };
+            AcOutput::try_from(*ac_data_out)
+        } else {
+            error!("Error when sending object to attached component: {}", ret);
+            Err(ret)
+        }
+    }
+
+    /// Cause conditional gating of a policy based on the names of the
+    /// object being sent, the authorizing handle and the attached component.
+    ///
+    /// The TPM will ensure that the current policy can only be used to
+    /// authorize TPM2_AC_Send for the object named object_name, authorized by
+    /// auth_handle_name, to the attached component named ac_name.
+    ///
+    /// # Arguments
+    /// * `policy_session` - The [policy session][PolicySession] being extended.
+    /// * `object_name` - The [Name] of the object to be sent.
+    /// * `auth_handle_name` - The [Name] of the handle authorizing the send.
+    /// * `ac_name` - The [Name] of the attached component.
+    /// * `include_object` - Whether or not the object name is included in the policy digest.
+    #[cfg(all(feature = "generate-bindings", tpm2_tss_version = "3"))]
+    pub fn policy_ac_send_select(
+        &mut self,
+        policy_session: PolicySession,
+        object_name: Name,
+        auth_handle_name: Name,
+        ac_name: Name,
+        include_object: bool,
+    ) -> Result<()> {
+        let ret = unsafe {
+            Esys_Policy_AC_SendSelect(
+                self.mut_context(),
+                SessionHandle::from(policy_session).into(),
+                self.optional_session_1(),
+                self.optional_session_2(),
+                self.optional_session_3(),
+                &TPM2B_NAME::try_from(object_name)?,
+                &TPM2B_NAME::try_from(auth_handle_name)?,
+                &TPM2B_NAME::try_from(ac_name)?,
+                if include_object { 1 } else { 0 },
+            )
+        };
+        let ret = Error::from_tss_rc(ret);
+        if ret.is_success() {
+            Ok(())
+        } else {
+            error!("Error when computing policy AC send select: {}", ret);
+            Err(ret)
+        }
+    }
 }
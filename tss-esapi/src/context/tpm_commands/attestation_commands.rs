@@ -1,19 +1,131 @@
 // Copyright 2021 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
 use crate::{
-    handles::KeyHandle,
-    structures::{Data, PcrSelectionList, Signature},
+    handles::{AuthHandle, KeyHandle, ObjectHandle, SessionHandle},
+    interface_types::session_handles::HmacSession,
+    structures::{
+        Attest, CreationTicket, Data, Digest, PcrSelectionList, Signature, SignatureScheme,
+    },
     tss2_esys::*,
     Context, Error, Result,
 };
 use log::error;
 use mbox::MBox;
-use std::convert::TryFrom;
+use std::convert::{TryFrom, TryInto};
 use std::ptr::null_mut;
 
 impl Context {
-    // Missing function: Certify
-    // Missing function: CertifyCreation
+    /// Certify that an object is loaded in the TPM.
+    ///
+    /// # Arguments
+    /// * `object_handle` - Handle of the object to be certified.
+    /// * `sign_handle` - Handle of the key used to sign the attestation structure.
+    /// * `qualifying_data` - User provided qualifying data.
+    /// * `signing_scheme` - Signing scheme to use if the scheme for `sign_handle` is `Null`.
+    ///
+    /// # Returns
+    /// The attestation structure, containing the names of the
+    /// certified object, together with the signature over it.
+    ///
+    /// # Errors
+    /// * if either of the slots `session_1` or `session_2` is not set, an
+    ///   error is returned, as both handles require an authorization session.
+    pub fn certify(
+        &mut self,
+        object_handle: ObjectHandle,
+        sign_handle: KeyHandle,
+        qualifying_data: Data,
+        signing_scheme: SignatureScheme,
+    ) -> Result<(Attest, Signature)> {
+        let mut certify_info_ptr = null_mut();
+        let mut signature_ptr = null_mut();
+        let ret = unsafe {
+            Esys_Certify(
+                self.mut_context(),
+                object_handle.into(),
+                sign_handle.into(),
+                self.required_session_1()?,
+                self.required_session_2()?,
+                self.optional_session_3(),
+                &qualifying_data.into(),
+                &signing_scheme.into(),
+                &mut certify_info_ptr,
+                &mut signature_ptr,
+            )
+        };
+        let ret = Error::from_tss_rc(ret);
+
+        if ret.is_success() {
+            let certify_info = unsafe { MBox::<TPM2B_ATTEST>::from_raw(certify_info_ptr) };
+            let signature = unsafe { MBox::from_raw(signature_ptr) };
+            Ok((
+                Attest::try_from(*certify_info)?,
+                Signature::try_from(*signature)?,
+            ))
+        } else {
+            error!("Error when certifying object: {}", ret);
+            Err(ret)
+        }
+    }
+
+    /// Certify that an object was created by the TPM.
+    ///
+    /// # Arguments
+    /// * `sign_handle` - Handle of the key used to sign the attestation structure.
+    /// * `object_handle` - Handle of the object associated with the creation data.
+    /// * `qualifying_data` - User provided qualifying data.
+    /// * `creation_hash` - Hash of the creation data produced when the object was created.
+    /// * `signing_scheme` - Signing scheme to use if the scheme for `sign_handle` is `Null`.
+    /// * `creation_ticket` - Ticket produced when the object was created.
+    ///
+    /// # Returns
+    /// The attestation structure, containing the name of the object
+    /// and its creation hash, together with the signature over it.
+    ///
+    /// # Errors
+    /// * if the slot `session_1` is not set, an error is returned, as the
+    ///   signing key requires an authorization session.
+    pub fn certify_creation(
+        &mut self,
+        sign_handle: KeyHandle,
+        object_handle: ObjectHandle,
+        qualifying_data: Data,
+        creation_hash: Digest,
+        signing_scheme: SignatureScheme,
+        creation_ticket: CreationTicket,
+    ) -> Result<(Attest, Signature)> {
+        let mut certify_info_ptr = null_mut();
+        let mut signature_ptr = null_mut();
+        let ret = unsafe {
+            Esys_CertifyCreation(
+                self.mut_context(),
+                sign_handle.into(),
+                object_handle.into(),
+                self.required_session_1()?,
+                self.optional_session_2(),
+                self.optional_session_3(),
+                &qualifying_data.into(),
+                &creation_hash.into(),
+                &signing_scheme.into(),
+                &creation_ticket.try_into()?,
+                &mut certify_info_ptr,
+                &mut signature_ptr,
+            )
+        };
+        let ret = Error::from_tss_rc(ret);
+
+        if ret.is_success() {
+            let certify_info = unsafe { MBox::<TPM2B_ATTEST>::from_raw(certify_info_ptr) };
+            let signature = unsafe { MBox::from_raw(signature_ptr) };
+            Ok((
+                Attest::try_from(*certify_info)?,
+                Signature::try_from(*signature)?,
+            ))
+        } else {
+            error!("Error when certifying creation: {}", ret);
+            Err(ret)
+        }
+    }
 
     /// Generate a quote on the selected PCRs
     ///
@@ -54,8 +166,169 @@ impl Context {
         }
     }
 
-    // Missing function: GetSessionAuditDigest
-    // Missing function: GestCommandAuditDigest
-    // Missing function: GetTime
+    /// Get the audit digest of a session, signed by the TPM.
+    ///
+    /// # Arguments
+    /// * `privacy_admin` - The endorsement hierarchy handle, used for authorization.
+    /// * `sign_handle` - Handle of the key used to sign the attestation structure.
+    /// * `session` - The audit session, started with the audit attribute set.
+    /// * `qualifying_data` - User provided qualifying data.
+    /// * `signing_scheme` - Signing scheme to use if the scheme for `sign_handle` is `Null`.
+    ///
+    /// # Returns
+    /// The attestation structure, containing the session audit
+    /// information, together with the signature over it.
+    ///
+    /// # Errors
+    /// * if either of the slots `session_1` or `session_2` is not set, an
+    ///   error is returned, as both handles require an authorization session.
+    pub fn get_session_audit_digest(
+        &mut self,
+        privacy_admin: AuthHandle,
+        sign_handle: KeyHandle,
+        session: HmacSession,
+        qualifying_data: Data,
+        signing_scheme: SignatureScheme,
+    ) -> Result<(Attest, Signature)> {
+        let mut audit_info_ptr = null_mut();
+        let mut signature_ptr = null_mut();
+        let ret = unsafe {
+            Esys_GetSessionAuditDigest(
+                self.mut_context(),
+                privacy_admin.into(),
+                sign_handle.into(),
+                SessionHandle::from(session).into(),
+                self.required_session_1()?,
+                self.required_session_2()?,
+                self.optional_session_3(),
+                &qualifying_data.into(),
+                &signing_scheme.into(),
+                &mut audit_info_ptr,
+                &mut signature_ptr,
+            )
+        };
+        let ret = Error::from_tss_rc(ret);
+
+        if ret.is_success() {
+            let audit_info = unsafe { MBox::<TPM2B_ATTEST>::from_raw(audit_info_ptr) };
+            let signature = unsafe { MBox::from_raw(signature_ptr) };
+            Ok((
+                Attest::try_from(*audit_info)?,
+                Signature::try_from(*signature)?,
+            ))
+        } else {
+            error!("Error when getting session audit digest: {}", ret);
+            Err(ret)
+        }
+    }
+
+    /// Get the digest of the commands that are being audited, signed
+    /// by the TPM.
+    ///
+    /// # Arguments
+    /// * `privacy_handle` - The endorsement hierarchy handle, used for authorization.
+    /// * `sign_handle` - Handle of the key used to sign the attestation structure.
+    /// * `qualifying_data` - User provided qualifying data.
+    /// * `signing_scheme` - Signing scheme to use if the scheme for `sign_handle` is `Null`.
+    ///
+    /// # Returns
+    /// The attestation structure, containing the command audit
+    /// information, together with the signature over it.
+    ///
+    /// # Errors
+    /// * if either of the slots `session_1` or `session_2` is not set, an
+    ///   error is returned, as both handles require an authorization session.
+    pub fn get_command_audit_digest(
+        &mut self,
+        privacy_handle: AuthHandle,
+        sign_handle: KeyHandle,
+        qualifying_data: Data,
+        signing_scheme: SignatureScheme,
+    ) -> Result<(Attest, Signature)> {
+        let mut audit_info_ptr = null_mut();
+        let mut signature_ptr = null_mut();
+        let ret = unsafe {
+            Esys_GetCommandAuditDigest(
+                self.mut_context(),
+                privacy_handle.into(),
+                sign_handle.into(),
+                self.required_session_1()?,
+                self.required_session_2()?,
+                self.optional_session_3(),
+                &qualifying_data.into(),
+                &signing_scheme.into(),
+                &mut audit_info_ptr,
+                &mut signature_ptr,
+            )
+        };
+        let ret = Error::from_tss_rc(ret);
+
+        if ret.is_success() {
+            let audit_info = unsafe { MBox::<TPM2B_ATTEST>::from_raw(audit_info_ptr) };
+            let signature = unsafe { MBox::from_raw(signature_ptr) };
+            Ok((
+                Attest::try_from(*audit_info)?,
+                Signature::try_from(*signature)?,
+            ))
+        } else {
+            error!("Error when getting command audit digest: {}", ret);
+            Err(ret)
+        }
+    }
+
+    /// Get the current time and clock values of the TPM, signed
+    /// by the TPM.
+    ///
+    /// # Arguments
+    /// * `privacy_admin` - The endorsement hierarchy handle, used for authorization.
+    /// * `sign_handle` - Handle of the key used to sign the attestation structure.
+    /// * `qualifying_data` - User provided qualifying data.
+    /// * `signing_scheme` - Signing scheme to use if the scheme for `sign_handle` is `Null`.
+    ///
+    /// # Returns
+    /// The attestation structure, containing the time information,
+    /// together with the signature over it.
+    ///
+    /// # Errors
+    /// * if either of the slots `session_1` or `session_2` is not set, an
+    ///   error is returned, as both handles require an authorization session.
+    pub fn get_time(
+        &mut self,
+        privacy_admin: AuthHandle,
+        sign_handle: KeyHandle,
+        qualifying_data: Data,
+        signing_scheme: SignatureScheme,
+    ) -> Result<(Attest, Signature)> {
+        let mut time_info_ptr = null_mut();
+        let mut signature_ptr = null_mut();
+        let ret = unsafe {
+            Esys_GetTime(
+                self.mut_context(),
+                privacy_admin.into(),
+                sign_handle.into(),
+                self.required_session_1()?,
+                self.required_session_2()?,
+                self.optional_session_3(),
+                &qualifying_data.into(),
+                &signing_scheme.into(),
+                &mut time_info_ptr,
+                &mut signature_ptr,
+            )
+        };
+        let ret = Error::from_tss_rc(ret);
+
+        if ret.is_success() {
+            let time_info = unsafe { MBox::<TPM2B_ATTEST>::from_raw(time_info_ptr) };
+            let signature = unsafe { MBox::from_raw(signature_ptr) };
+            Ok((
+                Attest::try_from(*time_info)?,
+                Signature::try_from(*signature)?,
+            ))
+        } else {
+            error!("Error when getting time: {}", ret);
+            Err(ret)
+        }
+    }
+
     // Missing function: CertifyX509
 }
@@ -1,7 +1,48 @@
 // Copyright 2021 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
+//! The authenticated countdown timer commands were added to the ESAPI in
+//! version 3 of the tpm2-tss library. They are therefore only available when
+//! the bindings are generated against that version of the library, i.e. when the
+//! `generate-bindings` feature is enabled and version 3 of the library is found,
+//! which sets `tpm2_tss_version = "3"`.
 use crate::Context;
+#[cfg(all(feature = "generate-bindings", tpm2_tss_version = "3"))]
+use crate::{handles::ActHandle, tss2_esys::*, Error, Result};
+#[cfg(all(feature = "generate-bindings", tpm2_tss_version = "3"))]
+use log::error;
 
 impl Context {
-    // Missing function: ACT_SetTimeout
+    /// Sets the timeout of an authenticated countdown timer.
+    ///
+    /// # Details
+    /// Setting the timeout to zero stops the timer and, if the timer
+    /// has expired, clears the signaled state.
+    ///
+    /// # Arguments
+    /// * `act_handle` - The [ActHandle] of the timer.
+    /// * `start_timeout` - The start timeout value of the timer, in seconds.
+    ///
+    /// # Errors
+    /// * if the slot `session_1` is not set, an error is returned, as the
+    ///   timer handle requires an authorization session.
+    #[cfg(all(feature = "generate-bindings", tpm2_tss_version = "3"))]
+    pub fn act_set_timeout(&mut self, act_handle: ActHandle, start_timeout: u32) -> Result<()> {
+        let ret = unsafe {
+            Esys_ACT_SetTimeout(
+                self.mut_context(),
+                act_handle.into(),
+                self.required_session_1()?,
+                self.optional_session_2(),
+                self.optional_session_3(),
+                start_timeout,
+            )
+        };
+        let ret = Error::from_tss_rc(ret);
+        if ret.is_success() {
+            Ok(())
+        } else {
+            error!("Error when setting the timeout of the ACT: {}", ret);
+            Err(ret)
+        }
+    }
 }
@@ -1,14 +1,19 @@
 // Copyright 2021 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
 use crate::{
-    constants::CapabilityType,
-    structures::{CapabilityData, PublicParameters},
+    constants::{
+        tss::{TPM2_ALG_FIRST, TPMA_CC_COMMANDINDEX_MASK},
+        CapabilityType, HandleArea,
+    },
+    handles::TpmHandle,
+    structures::{AlgorithmProperty, CapabilityData, PublicParameters},
     tss2_esys::*,
     Context, Error, Result, WrapperErrorKind as ErrorKind,
 };
 use log::{error, warn};
 use mbox::MBox;
 use std::convert::TryFrom;
+use std::mem::size_of;
 use std::ptr::null_mut;
 
 impl Context {
@@ -54,8 +59,113 @@ impl Context {
         }
     }
 
+    /// Get current capability information about the TPM, following
+    /// the `moreData` indication of the TPM.
+    ///
+    /// # Details
+    /// The returned iterator yields the capability data in chunks, as it
+    /// was returned by the TPM. Each time the TPM indicates that more data
+    /// is available, the next call to `next` requests the capability again,
+    /// starting from the property following the last one received.
+    ///
+    /// # Arguments
+    /// * `capability` - The [CapabilityType] that is to be requested.
+    /// * `property` - The first property that is to be returned.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use tss_esapi::{Context, tcti_ldr::TctiNameConf};
+    /// use tss_esapi::{
+    ///     constants::{tss::TPM2_PERSISTENT_FIRST, CapabilityType},
+    ///     structures::CapabilityData,
+    /// };
+    /// # // Create context
+    /// # let mut context =
+    /// #     Context::new(
+    /// #         TctiNameConf::from_environment_variable().expect("Failed to get TCTI"),
+    /// #     ).expect("Failed to create Context");
+    /// let mut persistent_handles = Vec::new();
+    /// let capability_iter = context.capability_iter(CapabilityType::Handles, TPM2_PERSISTENT_FIRST);
+    /// for capability_data in capability_iter {
+    ///     match capability_data.expect("Failed to get capability") {
+    ///         CapabilityData::Handles(handles) => persistent_handles.extend(handles),
+    ///         _ => panic!("Unexpected capability data"),
+    ///     }
+    /// }
+    /// ```
+    pub fn capability_iter(
+        &mut self,
+        capability: CapabilityType,
+        property: u32,
+    ) -> CapabilityIterator<'_> {
+        CapabilityIterator {
+            context: self,
+            capability,
+            property: Some(property),
+        }
+    }
+
+    /// Get the properties of all the algorithms implemented by the TPM.
+    ///
+    /// # Details
+    /// This method will request the [CapabilityType::Algorithms] capability
+    /// from the TPM as many times as needed for the TPM to stop indicating
+    /// that more data is available.
+    ///
+    /// # Returns
+    /// The properties of the implemented algorithms, ordered by
+    /// their algorithm identifiers.
+    pub fn get_algorithm_properties(&mut self) -> Result<Vec<AlgorithmProperty>> {
+        let mut algorithm_properties = Vec::<AlgorithmProperty>::new();
+        for capability_data in
+            self.capability_iter(CapabilityType::Algorithms, u32::from(TPM2_ALG_FIRST))
+        {
+            match capability_data? {
+                CapabilityData::Algorithms(algorithms) => {
+                    for (alg, alg_properties) in algorithms {
+                        algorithm_properties.push(AlgorithmProperty::try_from(
+                            TPMS_ALG_PROPERTY {
+                                alg,
+                                algProperties: alg_properties,
+                            },
+                        )?);
+                    }
+                }
+                _ => return Err(Error::local_error(ErrorKind::WrongValueFromTpm)),
+            }
+        }
+        algorithm_properties.sort_by_key(|algorithm_property| {
+            TPM2_ALG_ID::from(algorithm_property.algorithm_identifier())
+        });
+        Ok(algorithm_properties)
+    }
+
+    /// Get all the handles of an area.
+    ///
+    /// # Details
+    /// This method will request the [CapabilityType::Handles] capability
+    /// from the TPM as many times as needed for the TPM to stop indicating
+    /// that more data is available.
+    ///
+    /// # Arguments
+    /// * `handle_area` - The [HandleArea] whose handles are to be returned.
+    pub fn get_handles(&mut self, handle_area: HandleArea) -> Result<Vec<TpmHandle>> {
+        let mut handles = Vec::<TpmHandle>::new();
+        for capability_data in self.capability_iter(CapabilityType::Handles, handle_area.into()) {
+            match capability_data? {
+                CapabilityData::Handles(area_handles) => handles.extend(area_handles),
+                _ => return Err(Error::local_error(ErrorKind::WrongValueFromTpm)),
+            }
+        }
+        Ok(handles)
+    }
+
     /// Test if the given parameters are supported by the TPM.
     ///
+    /// # Details
+    /// This can be used in order to check that e.g. a key size or an
+    /// ECC curve is supported by the TPM before creating an object.
+    ///
     /// # Errors
     /// * if any of the public parameters is not compatible with the TPM,
     /// an `Err` containing the specific unmarshalling error will be returned.
@@ -79,3 +189,76 @@ impl Context {
         }
     }
 }
+
+/// Iterator over the capability data of the TPM.
+///
+/// # Details
+/// Created by [Context::capability_iter]. The iterator stops
+/// after the TPM indicates that no more data is available, or
+/// after an error has been yielded.
+#[derive(Debug)]
+pub struct CapabilityIterator<'a> {
+    context: &'a mut Context,
+    capability: CapabilityType,
+    property: Option<u32>,
+}
+
+impl Iterator for CapabilityIterator<'_> {
+    type Item = Result<CapabilityData>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let property = self.property.take()?;
+        match self.context.get_capability(
+            self.capability,
+            property,
+            max_property_count(self.capability),
+        ) {
+            Ok((capability_data, more_data)) => {
+                if more_data {
+                    self.property = next_property(&capability_data);
+                }
+                Some(Ok(capability_data))
+            }
+            Err(e) => Some(Err(e)),
+        }
+    }
+}
+
+/// Returns the maximum number of properties of the
+/// given capability type that fits in a response.
+fn max_property_count(capability: CapabilityType) -> u32 {
+    let property_size = match capability {
+        CapabilityType::Algorithms => size_of::<TPMS_ALG_PROPERTY>(),
+        CapabilityType::Handles => size_of::<TPM2_HANDLE>(),
+        CapabilityType::Command => size_of::<TPMA_CC>(),
+        CapabilityType::PPCommands | CapabilityType::AuditCommands => size_of::<TPM2_CC>(),
+        CapabilityType::AssignedPCR => size_of::<TPMS_PCR_SELECTION>(),
+        CapabilityType::TPMProperties => size_of::<TPMS_TAGGED_PROPERTY>(),
+        CapabilityType::PCRProperties => size_of::<TPMS_TAGGED_PCR_SELECT>(),
+        CapabilityType::ECCCurves => size_of::<TPM2_ECC_CURVE>(),
+    };
+    ((TPM2_MAX_CAP_BUFFER as usize - size_of::<TPM2_CAP>() - size_of::<u32>()) / property_size)
+        as u32
+}
+
+/// Returns the property following the last one in
+/// the capability data, if there is one.
+fn next_property(capability_data: &CapabilityData) -> Option<u32> {
+    let last = match capability_data {
+        CapabilityData::Algorithms(algorithms) => algorithms.keys().max().map(|&v| u32::from(v)),
+        CapabilityData::Handles(handles) => handles.iter().map(|&v| TPM2_HANDLE::from(v)).max(),
+        CapabilityData::Commands(commands) => commands
+            .iter()
+            .map(|&v| v & TPMA_CC_COMMANDINDEX_MASK)
+            .max(),
+        CapabilityData::PPCommands(commands) | CapabilityData::AuditCommands(commands) => {
+            commands.iter().copied().max()
+        }
+        // All the PCR banks are returned in one response.
+        CapabilityData::AssignedPCR(_) => None,
+        CapabilityData::TPMProperties(properties) => properties.keys().copied().max(),
+        CapabilityData::PCRProperties(properties) => properties.keys().copied().max(),
+        CapabilityData::ECCCurves(curves) => curves.iter().map(|&v| u32::from(v)).max(),
+    };
+    last.and_then(|v| v.checked_add(1))
+}
@@ -1,9 +1,114 @@
 // Copyright 2021 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
-use crate::Context;
+use crate::{
+    constants::response_code::Tss2ResponseCodeKind,
+    handles::AuthHandle,
+    interface_types::{clock::ClockAdjust, resource_handles::Provision},
+    structures::TimeInfo,
+    tss2_esys::*,
+    Context, Error, Result,
+};
+use log::error;
+use mbox::MBox;
+use std::convert::TryFrom;
+use std::ptr::null_mut;
 
 impl Context {
-    // Missing function: ReadClock
-    // Missing function: ClockSet
-    // Missing function: ClockRateAdjust
+    /// Reads the current time and clock values of the TPM.
+    ///
+    /// # Details
+    /// Unlike [Context::get_time], this does not require a signing
+    /// key and the returned values are not signed.
+    ///
+    /// # Returns
+    /// The [TimeInfo] containing the time, the clock information
+    /// and whether the clock value is safe.
+    pub fn read_clock(&mut self) -> Result<TimeInfo> {
+        let mut current_time_ptr = null_mut();
+        let ret = unsafe {
+            Esys_ReadClock(
+                self.mut_context(),
+                self.optional_session_1(),
+                self.optional_session_2(),
+                self.optional_session_3(),
+                &mut current_time_ptr,
+            )
+        };
+        let ret = Error::from_tss_rc(ret);
+        if ret.is_success() {
+            let current_time = unsafe { MBox::from_raw(current_time_ptr) };
+            TimeInfo::try_from(*current_time)
+        } else {
+            error!("Error when reading clock: {}", ret);
+            Err(ret)
+        }
+    }
+
+    /// Advances the clock of the TPM.
+    ///
+    /// # Details
+    /// The clock of the TPM can only be advanced, it can never be set
+    /// to a value lower than its current value.
+    ///
+    /// # Arguments
+    /// * `auth` - The [Provision] used for authorization.
+    /// * `new_time` - The new value of the clock, in milliseconds.
+    ///
+    /// # Errors
+    /// * if `new_time` is lower than the current clock value, a TSS error
+    ///   of kind [Tss2ResponseCodeKind::Value](crate::constants::response_code::Tss2ResponseCodeKind::Value)
+    ///   is returned.
+    pub fn clock_set(&mut self, auth: Provision, new_time: u64) -> Result<()> {
+        let ret = unsafe {
+            Esys_ClockSet(
+                self.mut_context(),
+                AuthHandle::from(auth).into(),
+                self.required_session_1()?,
+                self.optional_session_2(),
+                self.optional_session_3(),
+                new_time,
+            )
+        };
+        let ret = Error::from_tss_rc(ret);
+        if ret.is_success() {
+            Ok(())
+        } else {
+            if let Error::Tss2Error(rc) = ret {
+                if rc.kind() == Some(Tss2ResponseCodeKind::Value) {
+                    error!("The clock cannot be set to a value lower than its current value");
+                }
+            }
+            error!("Error when setting clock: {}", ret);
+            Err(ret)
+        }
+    }
+
+    /// Adjusts the rate of advance of the TPM clock.
+    ///
+    /// # Details
+    /// This method is used to adjust the rate at which the clock and
+    /// time of the TPM are updated, in order to compensate for drift.
+    ///
+    /// # Arguments
+    /// * `auth` - The [Provision] used for authorization.
+    /// * `rate_adjust` - The [ClockAdjust] that is to be applied.
+    pub fn clock_rate_adjust(&mut self, auth: Provision, rate_adjust: ClockAdjust) -> Result<()> {
+        let ret = unsafe {
+            Esys_ClockRateAdjust(
+                self.mut_context(),
+                AuthHandle::from(auth).into(),
+                self.required_session_1()?,
+                self.optional_session_2(),
+                self.optional_session_3(),
+                rate_adjust.into(),
+            )
+        };
+        let ret = Error::from_tss_rc(ret);
+        if ret.is_success() {
+            Ok(())
+        } else {
+            error!("Error when adjusting clock rate: {}", ret);
+            Err(ret)
+        }
+    }
 }
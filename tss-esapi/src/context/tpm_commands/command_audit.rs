@@ -1,7 +1,53 @@
 // Copyright 2021 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
-use crate::Context;
+use crate::{
+    handles::AuthHandle,
+    interface_types::{algorithm::HashingAlgorithm, resource_handles::Provision},
+    structures::CommandCodeList,
+    tss2_esys::*,
+    Context, Error, Result,
+};
+use log::error;
 
 impl Context {
-    // Missing function: SetCommandCodeAuditStatus
+    /// Sets the command codes that are audited by the TPM.
+    ///
+    /// # Details
+    /// This method is used to change the hash algorithm used for the
+    /// command audit digest, or to change which commands that are audited.
+    /// The hash algorithm can only be changed if both lists are empty.
+    ///
+    /// # Arguments
+    /// * `auth` - The [Provision] used for authorization.
+    /// * `audit_alg` - The [HashingAlgorithm] to use for the command audit, or
+    ///   [HashingAlgorithm::Null] if the algorithm is not to be changed.
+    /// * `set_list` - The command codes that are to be added to the list of audited commands.
+    /// * `clear_list` - The command codes that are to be removed from the list of audited commands.
+    pub fn set_command_code_audit_status(
+        &mut self,
+        auth: Provision,
+        audit_alg: HashingAlgorithm,
+        set_list: CommandCodeList,
+        clear_list: CommandCodeList,
+    ) -> Result<()> {
+        let ret = unsafe {
+            Esys_SetCommandCodeAuditStatus(
+                self.mut_context(),
+                AuthHandle::from(auth).into(),
+                self.required_session_1()?,
+                self.optional_session_2(),
+                self.optional_session_3(),
+                audit_alg.into(),
+                &set_list.into(),
+                &clear_list.into(),
+            )
+        };
+        let ret = Error::from_tss_rc(ret);
+        if ret.is_success() {
+            Ok(())
+        } else {
+            error!("Error when setting command code audit status: {}", ret);
+            Err(ret)
+        }
+    }
 }
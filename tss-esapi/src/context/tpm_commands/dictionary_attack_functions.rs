@@ -1,8 +1,54 @@
 // Copyright 2021 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
-use crate::Context;
+use crate::{
+    handles::ObjectHandle, interface_types::resource_handles::Lockout, tss2_esys::*, Context,
+    Error, Result,
+};
+use log::error;
 
 impl Context {
     // Missing function: DictionaryAttackLockReset
-    // Missing function: DictionaryAttackParameters
+
+    /// Changes the dictionary attack lockout parameters.
+    ///
+    /// # Arguments
+    /// * `auth` - The [Lockout] used for authorization.
+    /// * `new_max_tries` - The number of authorization failures before the lockout is imposed.
+    /// * `new_recovery_time` - The time in seconds before the authorization failure count
+    ///   is decremented, 0 disables the dictionary attack protection.
+    /// * `lockout_recovery` - The time in seconds after a failed authorization with the
+    ///   lockout authorization before it can be used again, 0 means that a TPM reset
+    ///   is needed instead.
+    ///
+    /// # Details
+    /// This method is used in order to tune the anti-hammering behaviour
+    /// of the TPM. It requires the lockout authorization.
+    pub fn dictionary_attack_parameters(
+        &mut self,
+        auth: Lockout,
+        new_max_tries: u32,
+        new_recovery_time: u32,
+        lockout_recovery: u32,
+    ) -> Result<()> {
+        let ret = unsafe {
+            Esys_DictionaryAttackParameters(
+                self.mut_context(),
+                ObjectHandle::from(auth).into(),
+                self.required_session_1()?,
+                self.optional_session_2(),
+                self.optional_session_3(),
+                new_max_tries,
+                new_recovery_time,
+                lockout_recovery,
+            )
+        };
+        let ret = Error::from_tss_rc(ret);
+
+        if ret.is_success() {
+            Ok(())
+        } else {
+            error!("Error when setting dictionary attack parameters: {}", ret);
+            Err(ret)
+        }
+    }
 }
@@ -2,14 +2,15 @@
 // SPDX-License-Identifier: Apache-2.0
 use crate::Context;
 use crate::{
-    handles::ObjectHandle,
-    structures::{Data, EncryptedSecret, Private, SymmetricDefinitionObject},
+    handles::{KeyHandle, ObjectHandle},
+    structures::{Data, EncryptedSecret, Name, Private, Public, SymmetricDefinitionObject},
     tss2_esys::*,
     Error, Result,
 };
 use log::error;
+use mbox::MBox;
 
-use std::convert::TryFrom;
+use std::convert::{TryFrom, TryInto};
 use std::ptr::null_mut;
 
 impl Context {
@@ -330,6 +331,124 @@ impl Context {
         }
     }
 
-    // Missing function: Rewrap
-    // Missing function: Import
+    /// Rewrap a duplicated object so that it is protected by a different parent.
+    ///
+    /// # Details
+    /// This command allows the TPM to serve in the role as a Duplication Authority.
+    /// The outer wrapper of `in_duplicate`, which is protected by `old_parent`, is
+    /// removed and a new outer wrapper, protected by `new_parent`, is applied. The
+    /// sensitive area of the object is never exposed in the process.
+    ///
+    /// # Arguments
+    /// * `old_parent` - An [ObjectHandle] of the parent of `in_duplicate`. This may be
+    ///   [ObjectHandle::Null] if `in_duplicate` has no outer wrapper.
+    /// * `new_parent` - An [ObjectHandle] of the new parent. This may be
+    ///   [ObjectHandle::Null] if no outer wrapper should be applied.
+    /// * `in_duplicate` - The object that is being rewrapped.
+    /// * `name` - The [Name] of the object being rewrapped.
+    /// * `in_sym_seed` - The seed for the symmetric key and HMAC key, protected by
+    ///   the asymmetric algorithms of `old_parent`.
+    ///
+    /// # Returns
+    /// The command returns a tuple consisting of:
+    /// * `out_duplicate` - The object encrypted using the symmetric key derived from
+    ///   `out_sym_seed`.
+    /// * `out_sym_seed` - Seed for a symmetric key protected by `new_parent`.
+    pub fn rewrap(
+        &mut self,
+        old_parent: ObjectHandle,
+        new_parent: ObjectHandle,
+        in_duplicate: Private,
+        name: Name,
+        in_sym_seed: EncryptedSecret,
+    ) -> Result<(Private, EncryptedSecret)> {
+        let mut out_duplicate_ptr = null_mut();
+        let mut out_sym_seed_ptr = null_mut();
+        let ret = unsafe {
+            Esys_Rewrap(
+                self.mut_context(),
+                old_parent.into(),
+                new_parent.into(),
+                self.required_session_1()?,
+                self.optional_session_2(),
+                self.optional_session_3(),
+                &in_duplicate.into(),
+                &name.try_into()?,
+                &in_sym_seed.into(),
+                &mut out_duplicate_ptr,
+                &mut out_sym_seed_ptr,
+            )
+        };
+        let ret = Error::from_tss_rc(ret);
+
+        if ret.is_success() {
+            let out_duplicate = unsafe { MBox::<TPM2B_PRIVATE>::from_raw(out_duplicate_ptr) };
+            let out_sym_seed =
+                unsafe { MBox::<TPM2B_ENCRYPTED_SECRET>::from_raw(out_sym_seed_ptr) };
+            Ok((
+                Private::try_from(*out_duplicate)?,
+                EncryptedSecret::try_from(*out_sym_seed)?,
+            ))
+        } else {
+            error!("Error when performing rewrap: {}", ret);
+            Err(ret)
+        }
+    }
+
+    /// Import attaches an object that has been duplicated to a new parent.
+    ///
+    /// # Details
+    /// This command allows an object created by [duplicate](Context::duplicate) to be
+    /// encrypted using the symmetric algorithm of a new parent so that it can be loaded.
+    ///
+    /// # Arguments
+    /// * `parent_handle` - A [KeyHandle] of the new parent for the object.
+    /// * `encryption_key` - An optional symmetric key used as the inner wrapper for
+    ///   `duplicate`. If `None` an empty buffer is passed to the TPM.
+    /// * `object_public` - The public area of the object to be imported.
+    /// * `duplicate` - The symmetrically encrypted duplicate object that may contain
+    ///   an inner symmetric wrapper.
+    /// * `in_sym_seed` - The seed for the symmetric key and HMAC key, protected by the
+    ///   asymmetric algorithms of the new parent.
+    /// * `symmetric_alg` - Definition of the symmetric algorithm used for the inner wrapper.
+    ///   [SymmetricDefinitionObject::Null] means that no inner wrapper is present.
+    ///
+    /// # Returns
+    /// The private area of the imported object, encrypted under `parent_handle`, which
+    /// can be passed to [load](Context::load).
+    pub fn import(
+        &mut self,
+        parent_handle: KeyHandle,
+        encryption_key: Option<Data>,
+        object_public: Public,
+        duplicate: Private,
+        in_sym_seed: EncryptedSecret,
+        symmetric_alg: SymmetricDefinitionObject,
+    ) -> Result<Private> {
+        let mut out_private_ptr = null_mut();
+        let ret = unsafe {
+            Esys_Import(
+                self.mut_context(),
+                parent_handle.into(),
+                self.required_session_1()?,
+                self.optional_session_2(),
+                self.optional_session_3(),
+                &encryption_key.unwrap_or_default().into(),
+                &object_public.into(),
+                &duplicate.into(),
+                &in_sym_seed.into(),
+                &symmetric_alg.into(),
+                &mut out_private_ptr,
+            )
+        };
+        let ret = Error::from_tss_rc(ret);
+
+        if ret.is_success() {
+            let out_private = unsafe { MBox::<TPM2B_PRIVATE>::from_raw(out_private_ptr) };
+            Ok(Private::try_from(*out_private)?)
+        } else {
+            error!("Error when performing import: {}", ret);
+            Err(ret)
+        }
+    }
 }
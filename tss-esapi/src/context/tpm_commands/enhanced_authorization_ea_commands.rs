@@ -1,11 +1,12 @@
 // Copyright 2021 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
 use crate::{
-    handles::{AuthHandle, ObjectHandle, SessionHandle},
+    constants::ArithmeticOperation,
+    handles::{AuthHandle, NvIndexHandle, ObjectHandle, SessionHandle},
     interface_types::session_handles::PolicySession,
     structures::{
-        AuthTicket, Digest, DigestList, Name, Nonce, PcrSelectionList, Signature, Timeout,
-        VerifiedTicket,
+        AuthTicket, Digest, DigestList, MaxBuffer, Name, Nonce, PcrSelectionList, Signature,
+        Timeout, VerifiedTicket,
     },
     tss2_esys::*,
     Context, Error, Result, WrapperErrorKind as ErrorKind,
@@ -18,6 +19,12 @@ use std::time::Duration;
 
 impl Context {
     /// Cause the policy to include a signed authorization
+    ///
+    /// # Details
+    /// The signature must be made by the key loaded at auth_object over
+    /// aHash = H(nonce_tpm || expiration || cp_hash_a || policy_ref), where
+    /// expiration is encoded as a big endian INT32 number of seconds and
+    /// H is the hash algorithm of the signature scheme.
     #[allow(clippy::too_many_arguments)]
     pub fn policy_signed(
         &mut self,
@@ -120,7 +127,53 @@ impl Context {
         }
     }
 
-    // Missing function: PolicyTicket
+    /// Cause the policy to include an authorization from a ticket
+    ///
+    /// The TPM will check that the ticket was produced by a previous
+    /// call to [policy_signed][Context::policy_signed] or
+    /// [policy_secret][Context::policy_secret] with the same
+    /// parameters and that it has not expired. If this is the case, the
+    /// policyDigest of the policy session is extended in the same way as
+    /// the command that produced the ticket.
+    ///
+    /// # Arguments
+    /// * `policy_session` - The [policy session][PolicySession] being extended.
+    /// * `timeout` - The [Timeout] returned along with the ticket.
+    /// * `cp_hash_a` - The command parameter hash used when the ticket was produced.
+    /// * `policy_ref` - The policy reference used when the ticket was produced.
+    /// * `auth_name` - The [Name] of the object that provided the authorization.
+    /// * `ticket` - The [AuthTicket] to replay.
+    pub fn policy_ticket(
+        &mut self,
+        policy_session: PolicySession,
+        timeout: Timeout,
+        cp_hash_a: Digest,
+        policy_ref: Nonce,
+        auth_name: Name,
+        ticket: AuthTicket,
+    ) -> Result<()> {
+        let ret = unsafe {
+            Esys_PolicyTicket(
+                self.mut_context(),
+                SessionHandle::from(policy_session).into(),
+                self.optional_session_1(),
+                self.optional_session_2(),
+                self.optional_session_3(),
+                &timeout.into(),
+                &cp_hash_a.into(),
+                &policy_ref.into(),
+                &TPM2B_NAME::try_from(auth_name)?,
+                &TPMT_TK_AUTH::try_from(ticket)?,
+            )
+        };
+        let ret = Error::from_tss_rc(ret);
+        if ret.is_success() {
+            Ok(())
+        } else {
+            error!("Error when sending policy ticket: {}", ret);
+            Err(ret)
+        }
+    }
 
     /// Cause conditional gating of a policy based on an OR'd condition.
     ///
@@ -226,7 +279,53 @@ impl Context {
     }
 
     // Missing function: PolicyNV
-    // Missing function: PolicyCounterTimer
+
+    /// Cause conditional gating of a policy based on the contents
+    /// of the TPMS_TIME_INFO structure.
+    ///
+    /// The TPM will compare the bytes of TPMS_TIME_INFO starting at offset
+    /// with operand_b using the provided operation. If the comparison
+    /// succeeds the policyDigest of the policy session is extended.
+    ///
+    /// # Arguments
+    /// * `policy_session` - The [policy session][PolicySession] being extended.
+    /// * `operand_b` - The value to compare with.
+    /// * `offset` - The octet offset in TPMS_TIME_INFO of the first octet of operand A.
+    /// * `operation` - The [comparison][ArithmeticOperation] to make.
+    ///
+    /// # Constraints
+    /// * `operand_b` must be at most 64 bytes long
+    ///
+    /// # Errors
+    /// * if `operand_b` is too long, a `WrongParamSize` wrapper error will be returned
+    pub fn policy_counter_timer(
+        &mut self,
+        policy_session: PolicySession,
+        operand_b: MaxBuffer,
+        offset: u16,
+        operation: ArithmeticOperation,
+    ) -> Result<()> {
+        let operand_b = TPM2B_OPERAND::from(Digest::try_from(operand_b.value())?);
+        let ret = unsafe {
+            Esys_PolicyCounterTimer(
+                self.mut_context(),
+                SessionHandle::from(policy_session).into(),
+                self.optional_session_1(),
+                self.optional_session_2(),
+                self.optional_session_3(),
+                &operand_b,
+                offset,
+                operation.into(),
+            )
+        };
+        let ret = Error::from_tss_rc(ret);
+        if ret.is_success() {
+            Ok(())
+        } else {
+            error!("Error when computing policy counter timer: {}", ret);
+            Err(ret)
+        }
+    }
 
     /// Cause conditional gating of a policy based on command code of authorized command.
     ///
@@ -283,11 +382,20 @@ impl Context {
     ///
     /// The TPM will ensure that the current policy can only be used to authorize
     /// a command where the parameters are hashed into cp_hash_a.
+    ///
+    /// # Constraints
+    /// * `cp_hash_a` must be the size of a digest produced by the hash algorithm
+    ///   of the policy session
+    ///
+    /// # Errors
+    /// * if `cp_hash_a` is not the size of a digest of the session hash algorithm, a
+    ///   `WrongParamSize` wrapper error will be returned
     pub fn policy_cp_hash(
         &mut self,
         policy_session: PolicySession,
         cp_hash_a: &Digest,
     ) -> Result<()> {
+        check_session_digest_size(policy_session, cp_hash_a)?;
         let ret = unsafe {
             Esys_PolicyCpHash(
                 self.mut_context(),
@@ -311,11 +419,20 @@ impl Context {
     ///
     /// The TPM will ensure that the current policy can only be used to authorize
     /// a command acting on an object whose name hashes to name_hash.
+    ///
+    /// # Constraints
+    /// * `name_hash` must be the size of a digest produced by the hash algorithm
+    ///   of the policy session
+    ///
+    /// # Errors
+    /// * if `name_hash` is not the size of a digest of the session hash algorithm, a
+    ///   `WrongParamSize` wrapper error will be returned
     pub fn policy_name_hash(
         &mut self,
         policy_session: PolicySession,
         name_hash: &Digest,
     ) -> Result<()> {
+        check_session_digest_size(policy_session, name_hash)?;
         let ret = unsafe {
             Esys_PolicyNameHash(
                 self.mut_context(),
@@ -335,7 +452,51 @@ impl Context {
         }
     }
 
-    // Missing function: PolicyDuplicationSelect
+    /// Cause conditional gating of a policy based on the names of the
+    /// object being duplicated and its new parent.
+    ///
+    /// The TPM will ensure that the current policy can only be used to
+    /// authorize TPM2_Duplicate for the object named object_name onto the
+    /// parent named new_parent_name.
+    ///
+    /// # Arguments
+    /// * `policy_session` - The [policy session][PolicySession] being extended.
+    /// * `object_name` - The [Name] of the object to be duplicated.
+    /// * `new_parent_name` - The [Name] of the new parent.
+    /// * `include_object` - Whether or not the object name is included in the policy digest.
+    ///
+    /// # Details
+    /// Even if `include_object` is false the TPM still checks the object
+    /// name when the policy is used, so the correct name must always be
+    /// provided. Excluding it from the policy digest makes it possible to
+    /// put the resulting digest into the auth policy of the object itself.
+    pub fn policy_duplication_select(
+        &mut self,
+        policy_session: PolicySession,
+        object_name: Name,
+        new_parent_name: Name,
+        include_object: bool,
+    ) -> Result<()> {
+        let ret = unsafe {
+            Esys_PolicyDuplicationSelect(
+                self.mut_context(),
+                SessionHandle::from(policy_session).into(),
+                self.optional_session_1(),
+                self.optional_session_2(),
+                self.optional_session_3(),
+                &TPM2B_NAME::try_from(object_name)?,
+                &TPM2B_NAME::try_from(new_parent_name)?,
+                if include_object { 1 } else { 0 },
+            )
+        };
+        let ret = Error::from_tss_rc(ret);
+        if ret.is_success() {
+            Ok(())
+        } else {
+            error!("Error when computing policy duplication select: {}", ret);
+            Err(ret)
+        }
+    }
 
     /// Cause conditional gating of a policy based on an authorized policy
     ///
@@ -507,5 +668,68 @@ impl Context {
             Err(ret)
         }
     }
-    // Missing function: PolicyAuthorizeNV
+
+    /// Cause conditional gating of a policy based on an authorized policy
+    /// stored in an NV index.
+    ///
+    /// The TPM will ensure that the current policy digest equals the
+    /// approved policy digest that is stored in the NV index, as a
+    /// TPMT_HA structure. If this is the case, the policyDigest of the
+    /// policy session is replaced by a value derived from the name of
+    /// the NV index.
+    ///
+    /// # Arguments
+    /// * `auth_handle` - Handle indicating the source of the authorization value
+    ///   for reading the NV index.
+    /// * `nv_index` - The [NvIndexHandle] of the NV index containing the approved policy.
+    /// * `policy_session` - The [policy session][PolicySession] being extended.
+    ///
+    /// # Errors
+    /// * if the slot `session_1` is not set, an error is returned, as the
+    ///   `auth_handle` requires an authorization session.
+    pub fn policy_authorize_nv(
+        &mut self,
+        auth_handle: AuthHandle,
+        nv_index: NvIndexHandle,
+        policy_session: PolicySession,
+    ) -> Result<()> {
+        let ret = unsafe {
+            Esys_PolicyAuthorizeNV(
+                self.mut_context(),
+                auth_handle.into(),
+                nv_index.into(),
+                SessionHandle::from(policy_session).into(),
+                self.required_session_1()?,
+                self.optional_session_2(),
+                self.optional_session_3(),
+            )
+        };
+        let ret = Error::from_tss_rc(ret);
+        if ret.is_success() {
+            Ok(())
+        } else {
+            error!("Error when computing policy authorize NV: {}", ret);
+            Err(ret)
+        }
+    }
+}
+
+/// Checks that the digest has the size of a digest produced by the
+/// hash algorithm of the policy session.
+fn check_session_digest_size(policy_session: PolicySession, digest: &Digest) -> Result<()> {
+    let hashing_algorithm = match policy_session {
+        PolicySession::PolicySession {
+            hashing_algorithm, ..
+        } => hashing_algorithm,
+    };
+    if hashing_algorithm.digest_size() == Some(digest.value().len()) {
+        Ok(())
+    } else {
+        error!(
+            "Error: Invalid digest size {} for the session hash algorithm {:?}",
+            digest.value().len(),
+            hashing_algorithm
+        );
+        Err(Error::local_error(ErrorKind::WrongParamSize))
+    }
 }
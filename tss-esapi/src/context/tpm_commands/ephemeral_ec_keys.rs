@@ -1,8 +1,120 @@
 // Copyright 2021 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
-use crate::Context;
+use crate::{
+    handles::KeyHandle,
+    interface_types::ecc::EccCurve,
+    structures::{EccParameter, EccPoint, SensitiveData},
+    tss2_esys::*,
+    Context, Error, Result,
+};
+use log::error;
+use mbox::MBox;
+use std::convert::TryInto;
+use std::ptr::null_mut;
 
 impl Context {
-    // Missing function: Commit
-    // Missing function: EC_Ephemeral
+    /// Perform the first part of an ECC anonymous signing operation.
+    ///
+    /// # Details
+    /// The TPM performs the point multiplications on the provided points and returns
+    /// intermediate signing values. The `sign_handle` parameter shall refer to an ECC
+    /// key and the signing scheme must be anonymous (e.g. ECDAA).
+    ///
+    /// # Arguments
+    /// * `sign_handle` - A [KeyHandle] of the key that will be used in the signing operation.
+    /// * `p1` - A point (M) on the curve of the key referenced by `sign_handle`.
+    /// * `s2` - Octet array used to derive x-coordinate of a base point.
+    /// * `y2` - Y coordinate of the point associated with `s2`.
+    ///
+    /// # Returns
+    /// The command returns a tuple consisting of:
+    /// * `k` - ECC point K ≔ \[ds\](x2, y2).
+    /// * `l` - ECC point L ≔ \[r\](x2, y2).
+    /// * `e` - ECC point E ≔ \[r\]P1.
+    /// * `counter` - Least-significant 16 bits of commitCount. This value has to be
+    ///   provided to the subsequent signing operation.
+    pub fn commit(
+        &mut self,
+        sign_handle: KeyHandle,
+        p1: EccPoint,
+        s2: SensitiveData,
+        y2: EccParameter,
+    ) -> Result<(EccPoint, EccPoint, EccPoint, u16)> {
+        let mut k_ptr = null_mut();
+        let mut l_ptr = null_mut();
+        let mut e_ptr = null_mut();
+        let mut counter: u16 = 0;
+        let ret = unsafe {
+            Esys_Commit(
+                self.mut_context(),
+                sign_handle.into(),
+                self.required_session_1()?,
+                self.optional_session_2(),
+                self.optional_session_3(),
+                &p1.into(),
+                &s2.into(),
+                &y2.into(),
+                &mut k_ptr,
+                &mut l_ptr,
+                &mut e_ptr,
+                &mut counter,
+            )
+        };
+        let ret = Error::from_tss_rc(ret);
+
+        if ret.is_success() {
+            let k = unsafe { MBox::<TPM2B_ECC_POINT>::from_raw(k_ptr) };
+            let l = unsafe { MBox::<TPM2B_ECC_POINT>::from_raw(l_ptr) };
+            let e = unsafe { MBox::<TPM2B_ECC_POINT>::from_raw(e_ptr) };
+            Ok((
+                k.point.try_into()?,
+                l.point.try_into()?,
+                e.point.try_into()?,
+                counter,
+            ))
+        } else {
+            error!("Error when performing commit: {}", ret);
+            Err(ret)
+        }
+    }
+
+    /// Create an ephemeral key for use in a two-phase key exchange protocol.
+    ///
+    /// # Details
+    /// The TPM generates an ephemeral key pair on the specified curve and
+    /// returns the public point. The private part is retained by the TPM
+    /// and can be referenced by the returned counter.
+    ///
+    /// # Arguments
+    /// * `curve_id` - The [EccCurve] on which the ephemeral key will be created.
+    ///
+    /// # Returns
+    /// The command returns a tuple consisting of:
+    /// * `q` - The ephemeral public point.
+    /// * `counter` - Least-significant 16 bits of commitCount. This value has to be
+    ///   provided to the subsequent operation that uses the ephemeral key.
+    pub fn ec_ephemeral(&mut self, curve_id: EccCurve) -> Result<(EccPoint, u16)> {
+        let mut q_ptr = null_mut();
+        let mut counter: u16 = 0;
+        let ret = unsafe {
+            Esys_EC_Ephemeral(
+                self.mut_context(),
+                self.optional_session_1(),
+                self.optional_session_2(),
+                self.optional_session_3(),
+                curve_id.into(),
+                &mut q_ptr,
+                &mut counter,
+            )
+        };
+        let ret = Error::from_tss_rc(ret);
+
+        if ret.is_success() {
+            let q = unsafe { MBox::<TPM2B_ECC_POINT>::from_raw(q_ptr) };
+            Ok((q.point.try_into()?, counter))
+        } else {
+            error!("Error when creating ephemeral key: {}", ret);
+            Err(ret)
+        }
+    }
 }
@@ -1,9 +1,151 @@
 // Copyright 2021 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
-use crate::Context;
+use crate::{
+    constants::tss::TPM2_ALG_NULL,
+    handles::{AuthHandle, KeyHandle},
+    interface_types::resource_handles::Provision,
+    structures::{Digest, HashAgile, MaxBuffer, Signature},
+    tss2_esys::*,
+    Context, Error, Result,
+};
+use log::error;
+use mbox::MBox;
+use std::convert::TryFrom;
+use std::ptr::null_mut;
 
 impl Context {
-    // Missing function: FieldUpgradeStart
-    // Missing function: FieldUpgradeData
-    // Missing function: FirmwareRead
+    /// Starts a field upgrade of the TPM firmware.
+    ///
+    /// # Arguments
+    /// * `authorization` - The [Provision] used for authorization, must be [Provision::Platform].
+    /// * `key_handle` - The [KeyHandle] of the public key used to verify the manifest signature.
+    /// * `fu_digest` - The digest of the first block in the field upgrade sequence.
+    /// * `manifest_signature` - The [Signature] over `fu_digest` made with the key in `key_handle`.
+    ///
+    /// # Details
+    /// The format of the upgrade manifest and of the data blocks are
+    /// vendor specific.
+    pub fn field_upgrade_start(
+        &mut self,
+        authorization: Provision,
+        key_handle: KeyHandle,
+        fu_digest: Digest,
+        manifest_signature: Signature,
+    ) -> Result<()> {
+        let ret = unsafe {
+            Esys_FieldUpgradeStart(
+                self.mut_context(),
+                AuthHandle::from(authorization).into(),
+                key_handle.into(),
+                self.required_session_1()?,
+                self.optional_session_2(),
+                self.optional_session_3(),
+                &fu_digest.into(),
+                &TPMT_SIGNATURE::try_from(manifest_signature)?,
+            )
+        };
+        let ret = Error::from_tss_rc(ret);
+        if ret.is_success() {
+            Ok(())
+        } else {
+            error!("Error when starting field upgrade: {}", ret);
+            Err(ret)
+        }
+    }
+
+    /// Sends a block of field upgrade data to the TPM.
+    ///
+    /// # Arguments
+    /// * `fu_data` - The field upgrade data block.
+    ///
+    /// # Returns
+    /// A tuple containing the digest of the next block, which is empty
+    /// when the field upgrade is complete, and the digest of the first block
+    /// in the sequence.
+    pub fn field_upgrade_data(&mut self, fu_data: MaxBuffer) -> Result<(Digest, Digest)> {
+        let mut next_digest_ptr = null_mut();
+        let mut first_digest_ptr = null_mut();
+        let ret = unsafe {
+            Esys_FieldUpgradeData(
+                self.mut_context(),
+                self.optional_session_1(),
+                self.optional_session_2(),
+                self.optional_session_3(),
+                &fu_data.into(),
+                &mut next_digest_ptr,
+                &mut first_digest_ptr,
+            )
+        };
+        let ret = Error::from_tss_rc(ret);
+        if ret.is_success() {
+            let next_digest = unsafe { MBox::<TPMT_HA>::from_raw(next_digest_ptr) };
+            let first_digest = unsafe { MBox::<TPMT_HA>::from_raw(first_digest_ptr) };
+            Ok((
+                field_upgrade_digest(*next_digest)?,
+                field_upgrade_digest(*first_digest)?,
+            ))
+        } else {
+            error!("Error when sending field upgrade data: {}", ret);
+            Err(ret)
+        }
+    }
+
+    /// Reads a block of the current TPM firmware.
+    ///
+    /// # Arguments
+    /// * `sequence_number` - The number of the block to read, starting at 0.
+    ///
+    /// # Details
+    /// The blocks are read by incrementing the sequence number until
+    /// an empty buffer is returned, see [firmware_read_all][Context::firmware_read_all].
+    pub fn firmware_read(&mut self, sequence_number: u32) -> Result<MaxBuffer> {
+        let mut fu_data_ptr = null_mut();
+        let ret = unsafe {
+            Esys_FirmwareRead(
+                self.mut_context(),
+                self.optional_session_1(),
+                self.optional_session_2(),
+                self.optional_session_3(),
+                sequence_number,
+                &mut fu_data_ptr,
+            )
+        };
+        let ret = Error::from_tss_rc(ret);
+        if ret.is_success() {
+            let fu_data = unsafe { MBox::<TPM2B_MAX_BUFFER>::from_raw(fu_data_ptr) };
+            Ok(MaxBuffer::try_from(*fu_data)?)
+        } else {
+            error!("Error when reading firmware: {}", ret);
+            Err(ret)
+        }
+    }
+
+    /// Reads the entire current TPM firmware.
+    ///
+    /// # Details
+    /// Calls [firmware_read][Context::firmware_read] with increasing
+    /// sequence numbers until an empty block is returned, and concatenates
+    /// the blocks.
+    pub fn firmware_read_all(&mut self) -> Result<Vec<u8>> {
+        let mut firmware = Vec::new();
+        let mut sequence_number = 0;
+        loop {
+            let block = self.firmware_read(sequence_number)?;
+            if block.value().is_empty() {
+                return Ok(firmware);
+            }
+            firmware.extend_from_slice(block.value());
+            sequence_number += 1;
+        }
+    }
+}
+
+/// Converts a digest returned by the field upgrade commands, where
+/// TPM2_ALG_NULL indicates that there is no digest.
+fn field_upgrade_digest(tpmt_ha: TPMT_HA) -> Result<Digest> {
+    if tpmt_ha.hashAlg == TPM2_ALG_NULL {
+        Ok(Digest::default())
+    } else {
+        Ok(HashAgile::try_from(tpmt_ha)?.digest().clone())
+    }
 }
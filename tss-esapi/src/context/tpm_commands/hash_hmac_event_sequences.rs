@@ -1,12 +1,279 @@
 // Copyright 2021 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
-use crate::Context;
+use crate::{
+    context::handle_manager::HandleDropAction,
+    handles::{AuthHandle, KeyHandle, ObjectHandle, PcrHandle, TpmHandle},
+    interface_types::{algorithm::HashingAlgorithm, resource_handles::Hierarchy},
+    structures::{Auth, Digest, DigestList, HashAgile, HashcheckTicket, MaxBuffer},
+    tss2_esys::*,
+    Context, Error, Result, WrapperErrorKind,
+};
+use log::error;
+use mbox::MBox;
+use std::convert::TryFrom;
+use std::ptr::null_mut;
 
 impl Context {
-    // Missing function: HMAC_Start
-    // Missing function: MAC_Start
-    // Missing function: HashSequenceStart
-    // Missing function: SequenceUpdate
-    // Missing function: SequenceComplete
-    // Missing function: EventSequenceComplete
+    /// Starts an HMAC sequence using the key associated with `handle`.
+    ///
+    /// # Arguments
+    /// * `handle` - A [KeyHandle] of the keyed hash key providing the HMAC key.
+    /// * `auth` - The authorization value for subsequent use of the sequence.
+    /// * `hashing_algorithm` - The hash algorithm of the HMAC, or `Null`
+    ///   to use the scheme of the key.
+    ///
+    /// # Returns
+    /// A handle to the sequence object that is used with
+    /// [sequence_update](Context::sequence_update) and
+    /// [sequence_complete](Context::sequence_complete).
+    pub fn hmac_start(
+        &mut self,
+        handle: KeyHandle,
+        auth: Auth,
+        hashing_algorithm: HashingAlgorithm,
+    ) -> Result<AuthHandle> {
+        let mut sequence_handle = ESYS_TR_NONE;
+        let ret = unsafe {
+            Esys_HMAC_Start(
+                self.mut_context(),
+                handle.into(),
+                self.required_session_1()?,
+                self.optional_session_2(),
+                self.optional_session_3(),
+                &auth.into(),
+                hashing_algorithm.into(),
+                &mut sequence_handle,
+            )
+        };
+        let ret = Error::from_tss_rc(ret);
+
+        if ret.is_success() {
+            let sequence_handle = AuthHandle::from(sequence_handle);
+            self.handle_manager
+                .add_handle(sequence_handle.into(), HandleDropAction::Flush)?;
+            Ok(sequence_handle)
+        } else {
+            error!("Error in hmac start: {}", ret);
+            Err(ret)
+        }
+    }
+
+    /// Starts a MAC sequence using the key associated with `handle`.
+    ///
+    /// # Details
+    /// TPM2_MAC_Start shares its command code and parameter layout with
+    /// TPM2_HMAC_Start, so the command is issued through
+    /// [hmac_start](Context::hmac_start), as `Esys_MAC_Start` is not
+    /// available in all supported versions of the TSS.
+    ///
+    /// # Arguments
+    /// * `handle` - A [KeyHandle] of the symmetric signing key providing the MAC key.
+    /// * `auth` - The authorization value for subsequent use of the sequence.
+    /// * `mac_scheme` - The algorithm that is used to calculate the MAC.
+    ///
+    /// # Returns
+    /// A handle to the sequence object that is used with
+    /// [sequence_update](Context::sequence_update) and
+    /// [sequence_complete](Context::sequence_complete).
+    pub fn mac_start(
+        &mut self,
+        handle: KeyHandle,
+        auth: Auth,
+        mac_scheme: HashingAlgorithm,
+    ) -> Result<AuthHandle> {
+        self.hmac_start(handle, auth, mac_scheme)
+    }
+
+    /// Starts a hash sequence.
+    ///
+    /// # Arguments
+    /// * `auth` - The authorization value for subsequent use of the sequence.
+    /// * `hashing_algorithm` - The hash algorithm of the sequence, or `Null`
+    ///   for an event sequence.
+    ///
+    /// # Returns
+    /// A handle to the sequence object that is used with
+    /// [sequence_update](Context::sequence_update) and
+    /// [sequence_complete](Context::sequence_complete).
+    pub fn hash_sequence_start(
+        &mut self,
+        auth: Auth,
+        hashing_algorithm: HashingAlgorithm,
+    ) -> Result<AuthHandle> {
+        let mut sequence_handle = ESYS_TR_NONE;
+        let ret = unsafe {
+            Esys_HashSequenceStart(
+                self.mut_context(),
+                self.optional_session_1(),
+                self.optional_session_2(),
+                self.optional_session_3(),
+                &auth.into(),
+                hashing_algorithm.into(),
+                &mut sequence_handle,
+            )
+        };
+        let ret = Error::from_tss_rc(ret);
+
+        if ret.is_success() {
+            let sequence_handle = AuthHandle::from(sequence_handle);
+            self.handle_manager
+                .add_handle(sequence_handle.into(), HandleDropAction::Flush)?;
+            Ok(sequence_handle)
+        } else {
+            error!("Error in hash sequence start: {}", ret);
+            Err(ret)
+        }
+    }
+
+    /// Adds data to a hash or MAC sequence.
+    ///
+    /// # Arguments
+    /// * `sequence_handle` - An [ObjectHandle] of the sequence object.
+    /// * `buffer` - The data that is added to the sequence.
+    pub fn sequence_update(
+        &mut self,
+        sequence_handle: ObjectHandle,
+        buffer: MaxBuffer,
+    ) -> Result<()> {
+        let ret = unsafe {
+            Esys_SequenceUpdate(
+                self.mut_context(),
+                sequence_handle.into(),
+                self.required_session_1()?,
+                self.optional_session_2(),
+                self.optional_session_3(),
+                &buffer.into(),
+            )
+        };
+        let ret = Error::from_tss_rc(ret);
+
+        if ret.is_success() {
+            Ok(())
+        } else {
+            error!("Error in sequence update: {}", ret);
+            Err(ret)
+        }
+    }
+
+    /// Adds the last part of data to a hash or MAC sequence and returns the result.
+    ///
+    /// # Details
+    /// The sequence object is flushed by the TPM when the command succeeds.
+    ///
+    /// # Arguments
+    /// * `sequence_handle` - An [ObjectHandle] of the sequence object.
+    /// * `buffer` - The data that is added to the sequence before it is completed.
+    /// * `hierarchy` - The hierarchy of the ticket for a hash sequence.
+    ///
+    /// # Returns
+    /// The command returns a tuple consisting of:
+    /// * `result` - The resulting digest or MAC.
+    /// * `validation` - A ticket indicating that the sequence of data did not
+    ///   start with TPM_GENERATED_VALUE. This is a NULL ticket for MAC sequences.
+    pub fn sequence_complete(
+        &mut self,
+        sequence_handle: ObjectHandle,
+        buffer: MaxBuffer,
+        hierarchy: Hierarchy,
+    ) -> Result<(Digest, HashcheckTicket)> {
+        let mut result_ptr = null_mut();
+        let mut validation_ptr = null_mut();
+        let ret = unsafe {
+            Esys_SequenceComplete(
+                self.mut_context(),
+                sequence_handle.into(),
+                self.required_session_1()?,
+                self.optional_session_2(),
+                self.optional_session_3(),
+                &buffer.into(),
+                if cfg!(tpm2_tss_version = "3") {
+                    ObjectHandle::from(hierarchy).into()
+                } else {
+                    TpmHandle::from(hierarchy).into()
+                },
+                &mut result_ptr,
+                &mut validation_ptr,
+            )
+        };
+        let ret = Error::from_tss_rc(ret);
+
+        if ret.is_success() {
+            self.handle_manager.set_as_flushed(sequence_handle)?;
+            let result = unsafe { MBox::<TPM2B_DIGEST>::from_raw(result_ptr) };
+            let validation = unsafe { MBox::<TPMT_TK_HASHCHECK>::from_raw(validation_ptr) };
+            Ok((
+                Digest::try_from(*result)?,
+                HashcheckTicket::try_from(*validation)?,
+            ))
+        } else {
+            error!("Error in sequence complete: {}", ret);
+            Err(ret)
+        }
+    }
+
+    /// Adds the last part of data to an event sequence, and extends the results into a PCR.
+    ///
+    /// # Details
+    /// The sequence object is flushed by the TPM when the command succeeds.
+    ///
+    /// # Arguments
+    /// * `pcr_handle` - The PCR that is extended with the digests, or `None` in
+    ///   order not to extend any PCR.
+    /// * `sequence_handle` - An [ObjectHandle] of the sequence object, started with
+    ///   [hash_sequence_start](Context::hash_sequence_start) and the `Null` hashing algorithm.
+    /// * `buffer` - The data that is added to the sequence before it is completed.
+    ///
+    /// # Returns
+    /// The digests of the data, one for each active PCR bank in the order
+    /// returned by the TPM, which are the values extended into the PCR.
+    ///
+    /// # Errors
+    /// * The command requires the authorization of both the PCR and the
+    ///   sequence, so if the first or second session is missing, a
+    ///   `MissingAuthSession` wrapper error is returned.
+    pub fn event_sequence_complete(
+        &mut self,
+        pcr_handle: Option<PcrHandle>,
+        sequence_handle: ObjectHandle,
+        buffer: MaxBuffer,
+    ) -> Result<DigestList> {
+        let mut results_ptr = null_mut();
+        let ret = unsafe {
+            Esys_EventSequenceComplete(
+                self.mut_context(),
+                pcr_handle
+                    .map(ObjectHandle::from)
+                    .unwrap_or(ObjectHandle::Null)
+                    .into(),
+                sequence_handle.into(),
+                self.required_session_1()?,
+                self.required_session_2()?,
+                self.optional_session_3(),
+                &buffer.into(),
+                &mut results_ptr,
+            )
+        };
+        let ret = Error::from_tss_rc(ret);
+
+        if ret.is_success() {
+            self.handle_manager.set_as_flushed(sequence_handle)?;
+            let results = unsafe { MBox::<TPML_DIGEST_VALUES>::from_raw(results_ptr) };
+            let count = results.count as usize;
+            if count > results.digests.len() {
+                error!(
+                    "Error: Invalid TPML_DIGEST_VALUES count(> {})",
+                    results.digests.len()
+                );
+                return Err(Error::local_error(WrapperErrorKind::WrongValueFromTpm));
+            }
+            let mut digest_list = DigestList::new();
+            for tpmt_ha in results.digests[..count].iter() {
+                digest_list.add(HashAgile::try_from(*tpmt_ha)?.digest().clone())?;
+            }
+            Ok(digest_list)
+        } else {
+            error!("Error in event sequence complete: {}", ret);
+            Err(ret)
+        }
+    }
 }
@@ -2,14 +2,17 @@
 // SPDX-License-Identifier: Apache-2.0
 use crate::{
     context::handle_manager::HandleDropAction,
-    handles::{AuthHandle, KeyHandle, ObjectHandle},
-    interface_types::resource_handles::Hierarchy,
+    handles::{AuthHandle, KeyHandle, ObjectHandle, TpmHandle},
+    interface_types::{
+        algorithm::HashingAlgorithm,
+        resource_handles::{Hierarchy, Provision},
+    },
     structures::{
         Auth, CreatePrimaryKeyResult, CreationData, CreationTicket, Data, Digest, PcrSelectionList,
         Public, SensitiveData,
     },
     tss2_esys::*,
-    Context, Error, Result,
+    Context, Error, Result, WrapperErrorKind,
 };
 use log::error;
 use mbox::MBox;
@@ -95,10 +98,151 @@ impl Context {
         }
     }
 
-    // Missing function: HierarchyControl
-    // Missing function: SetPrimaryPolicy
-    // Missing function: ChangePPS
-    // Missing function: ChangeEPS
+    /// Enables or disables a hierarchy.
+    ///
+    /// # Arguments
+    /// * `auth` - The [Provision] used for authorization.
+    /// * `hierarchy` - The [Hierarchy] that is to be enabled or disabled.
+    /// * `state` - `true` in order to enable the hierarchy, `false` in order to disable it.
+    ///
+    /// # Details
+    /// This is typically used by the platform firmware in order to disable
+    /// hierarchies before handing over control to the operating system.
+    /// A hierarchy can be disabled using its own authorization or the
+    /// platform authorization, but it can only be enabled again using the
+    /// platform authorization. The authorization is done using the first session.
+    ///
+    /// # Errors
+    /// * if the hierarchy is [Hierarchy::Null], an `InvalidParam` wrapper error is returned.
+    pub fn hierarchy_control(
+        &mut self,
+        auth: Provision,
+        hierarchy: Hierarchy,
+        state: bool,
+    ) -> Result<()> {
+        if hierarchy == Hierarchy::Null {
+            error!("The null hierarchy cannot be enabled or disabled");
+            return Err(Error::local_error(WrapperErrorKind::InvalidParam));
+        }
+        let ret = unsafe {
+            Esys_HierarchyControl(
+                self.mut_context(),
+                AuthHandle::from(auth).into(),
+                self.required_session_1()?,
+                self.optional_session_2(),
+                self.optional_session_3(),
+                TpmHandle::from(hierarchy).into(),
+                if state { 1 } else { 0 },
+            )
+        };
+        let ret = Error::from_tss_rc(ret);
+
+        if ret.is_success() {
+            Ok(())
+        } else {
+            error!("Error in controlling hierarchy: {}", ret);
+            Err(ret)
+        }
+    }
+
+    /// Sets the authorization policy of a hierarchy.
+    ///
+    /// # Arguments
+    /// * `auth_handle` - The [Provision] whose authorization policy is to be set.
+    /// * `auth_policy` - The [Digest] of the new authorization policy.
+    /// * `hash_alg` - The [HashingAlgorithm] used to compute the policy.
+    ///
+    /// # Details
+    /// Once the policy is set, a policy session satisfying it can be used
+    /// as an alternative to the authorization value of the hierarchy.
+    /// An empty `auth_policy` together with [HashingAlgorithm::Null]
+    /// clears the policy.
+    pub fn set_primary_policy(
+        &mut self,
+        auth_handle: Provision,
+        auth_policy: Digest,
+        hash_alg: HashingAlgorithm,
+    ) -> Result<()> {
+        let ret = unsafe {
+            Esys_SetPrimaryPolicy(
+                self.mut_context(),
+                AuthHandle::from(auth_handle).into(),
+                self.required_session_1()?,
+                self.optional_session_2(),
+                self.optional_session_3(),
+                &auth_policy.into(),
+                hash_alg.into(),
+            )
+        };
+        let ret = Error::from_tss_rc(ret);
+
+        if ret.is_success() {
+            Ok(())
+        } else {
+            error!("Error in setting primary policy: {}", ret);
+            Err(ret)
+        }
+    }
+
+    /// Replaces the platform primary seed.
+    ///
+    /// # Arguments
+    /// * `auth` - The [Provision] used for authorization, this needs to be the platform hierarchy.
+    ///
+    /// # Details
+    /// This is a destructive operation, all the keys derived from the
+    /// previous platform primary seed are invalidated and the objects
+    /// in the platform hierarchy are flushed.
+    pub fn change_pps(&mut self, auth: Provision) -> Result<()> {
+        let ret = unsafe {
+            Esys_ChangePPS(
+                self.mut_context(),
+                AuthHandle::from(auth).into(),
+                self.required_session_1()?,
+                self.optional_session_2(),
+                self.optional_session_3(),
+            )
+        };
+        let ret = Error::from_tss_rc(ret);
+
+        if ret.is_success() {
+            Ok(())
+        } else {
+            error!("Error in changing platform primary seed: {}", ret);
+            Err(ret)
+        }
+    }
+
+    /// Replaces the endorsement primary seed.
+    ///
+    /// # Arguments
+    /// * `auth` - The [Provision] used for authorization, this needs to be the platform hierarchy.
+    ///
+    /// # Details
+    /// This is a destructive operation, all the keys derived from the
+    /// previous endorsement primary seed, such as the endorsement key,
+    /// are invalidated and the objects in the endorsement hierarchy are
+    /// flushed. The authorization value and policy of the endorsement
+    /// hierarchy are also reset.
+    pub fn change_eps(&mut self, auth: Provision) -> Result<()> {
+        let ret = unsafe {
+            Esys_ChangeEPS(
+                self.mut_context(),
+                AuthHandle::from(auth).into(),
+                self.required_session_1()?,
+                self.optional_session_2(),
+                self.optional_session_3(),
+            )
+        };
+        let ret = Error::from_tss_rc(ret);
+
+        if ret.is_success() {
+            Ok(())
+        } else {
+            error!("Error in changing endorsement primary seed: {}", ret);
+            Err(ret)
+        }
+    }
 
     /// Clear all TPM context associated with a specific Owner
     pub fn clear(&mut self, auth_handle: AuthHandle) -> Result<()> {
@@ -144,6 +288,13 @@ impl Context {
     }
 
     /// Change authorization for a hierarchy root
+    ///
+    /// # Details
+    /// This is used to set the authorization value of the owner, endorsement,
+    /// platform or lockout hierarchy. An empty `new_auth` clears the
+    /// authorization value. The authorization value associated with
+    /// `auth_handle` in the context is updated to `new_auth`, so the
+    /// hierarchy can be used without calling [Context::tr_set_auth].
     pub fn hierarchy_change_auth(&mut self, auth_handle: AuthHandle, new_auth: Auth) -> Result<()> {
         let ret = unsafe {
             Esys_HierarchyChangeAuth(
@@ -1,8 +1,10 @@
 // Copyright 2021 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
 use crate::{
-    handles::PcrHandle,
-    structures::{DigestValues, PcrSelectionList},
+    constants::response_code::Tss2ResponseCodeKind,
+    handles::{AuthHandle, PcrHandle},
+    interface_types::{algorithm::HashingAlgorithm, resource_handles::Provision},
+    structures::{Auth, Digest, DigestValues, PcrSelectionList},
     tss2_esys::*,
     utils::PcrData,
     Context, Error, Result,
@@ -188,9 +190,141 @@ impl Context {
         }
     }
 
-    // Missing function: PCR_Allocate
-    // Missing function: PCR_SetAuthPolicy
-    // Missing function: PCR_SetAuthValue
+    /// Sets the desired PCR allocation of PCR banks.
+    ///
+    /// # Arguments
+    /// * `auth` - The [Provision] used for authorization, this needs to be the platform hierarchy.
+    /// * `pcr_allocation` - A [PcrSelectionList] with the pcr slots that shall be allocated in each bank.
+    ///
+    /// # Details
+    /// This method is used in order to enable or disable PCR banks, for example in
+    /// order to turn off the SHA-1 bank. Banks that are not present in the
+    /// `pcr_allocation` are left unchanged.
+    ///
+    /// The new allocation does not take effect until the next TPM reset
+    /// (`TPM2_Startup(TPM_SU_CLEAR)`) i.e. usually after a reboot.
+    ///
+    /// # Returns
+    /// A tuple containing:
+    /// * whether the allocation succeeded.
+    /// * the maximum number of PCRs supported in each bank.
+    /// * the number of octets needed for the requested allocation.
+    /// * the number of octets available for the allocation.
+    pub fn pcr_allocate(
+        &mut self,
+        auth: Provision,
+        pcr_allocation: PcrSelectionList,
+    ) -> Result<(bool, u32, u32, u32)> {
+        let mut allocation_success = 0;
+        let mut max_pcr = 0;
+        let mut size_needed = 0;
+        let mut size_available = 0;
+        let ret = unsafe {
+            Esys_PCR_Allocate(
+                self.mut_context(),
+                AuthHandle::from(auth).into(),
+                self.required_session_1()?,
+                self.optional_session_2(),
+                self.optional_session_3(),
+                &pcr_allocation.into(),
+                &mut allocation_success,
+                &mut max_pcr,
+                &mut size_needed,
+                &mut size_available,
+            )
+        };
+        let ret = Error::from_tss_rc(ret);
+
+        if ret.is_success() {
+            Ok((
+                allocation_success != 0,
+                max_pcr,
+                size_needed,
+                size_available,
+            ))
+        } else {
+            error!("Error when allocating PCR banks: {}", ret);
+            Err(ret)
+        }
+    }
+
+    /// Sets the authorization policy of a PCR group.
+    ///
+    /// # Arguments
+    /// * `auth` - The [Provision] used for authorization, this needs to be the platform hierarchy.
+    /// * `auth_policy` - The [Digest] of the new authorization policy.
+    /// * `hash_alg` - The [HashingAlgorithm] used to compute the policy.
+    /// * `pcr_num` - A [PcrHandle] to a PCR in the authorization policy group that is to be changed.
+    ///
+    /// # Details
+    /// The policy applies to all the PCRs in the same authorization policy
+    /// group as `pcr_num`. An empty `auth_policy` together with
+    /// [HashingAlgorithm::Null] removes the policy.
+    ///
+    /// Not all TPMs have PCRs that belong to authorization policy groups.
+    pub fn pcr_set_auth_policy(
+        &mut self,
+        auth: Provision,
+        auth_policy: Digest,
+        hash_alg: HashingAlgorithm,
+        pcr_num: PcrHandle,
+    ) -> Result<()> {
+        let ret = unsafe {
+            Esys_PCR_SetAuthPolicy(
+                self.mut_context(),
+                AuthHandle::from(auth).into(),
+                self.required_session_1()?,
+                self.optional_session_2(),
+                self.optional_session_3(),
+                &auth_policy.into(),
+                hash_alg.into(),
+                // The ESYS_TR values of the PCRs are the same as the PCR handles of the TPM.
+                ESYS_TR::from(pcr_num),
+            )
+        };
+        let ret = Error::from_tss_rc(ret);
+
+        if ret.is_success() {
+            Ok(())
+        } else {
+            error!("Error when setting PCR auth policy: {}", ret);
+            Err(ret)
+        }
+    }
+
+    /// Sets the authorization value of a PCR group.
+    ///
+    /// # Arguments
+    /// * `pcr_handle` - A [PcrHandle] to a PCR in the authorization value group that is to be changed.
+    /// * `auth` - The new authorization value.
+    ///
+    /// # Details
+    /// The authorization value applies to all the PCRs in the same
+    /// authorization value group as `pcr_handle`. The current authorization
+    /// value of the PCR is needed in order to change it, and the new value
+    /// has to be set with [Context::tr_set_auth] before using the PCRs again.
+    ///
+    /// Not all TPMs have PCRs that belong to authorization value groups.
+    pub fn pcr_set_auth_value(&mut self, pcr_handle: PcrHandle, auth: Auth) -> Result<()> {
+        let ret = unsafe {
+            Esys_PCR_SetAuthValue(
+                self.mut_context(),
+                pcr_handle.into(),
+                self.required_session_1()?,
+                self.optional_session_2(),
+                self.optional_session_3(),
+                &auth.into(),
+            )
+        };
+        let ret = Error::from_tss_rc(ret);
+
+        if ret.is_success() {
+            Ok(())
+        } else {
+            error!("Error when setting PCR auth value: {}", ret);
+            Err(ret)
+        }
+    }
 
     /// Resets the value in a PCR.
     ///
@@ -203,6 +337,13 @@ impl Context {
     /// this method can be used to set the the specified PCR in all
     /// banks to 0.
     ///
+    /// Which PCRs can be reset depends on the platform, in the PC client
+    /// platform only PCR 16 and PCR 23 can be reset from the default locality.
+    ///
+    /// # Errors
+    /// * if the PCR cannot be reset from the current locality, a
+    ///   `Locality` TSS error is returned.
+    ///
     /// # Example
     ///
     /// ```rust
@@ -260,6 +401,11 @@ impl Context {
         if ret.is_success() {
             Ok(())
         } else {
+            if let Error::Tss2Error(rc) = ret {
+                if rc.kind() == Some(Tss2ResponseCodeKind::Locality) {
+                    error!("The PCR cannot be reset from the current locality");
+                }
+            }
             error!("Error when resetting PCR: {}", ret);
             Err(ret)
         }
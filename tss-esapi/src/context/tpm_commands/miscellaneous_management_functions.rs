@@ -1,8 +1,80 @@
 // Copyright 2021 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
-use crate::Context;
+use crate::{
+    handles::AuthHandle, interface_types::resource_handles::Provision, structures::CommandCodeList,
+    tss2_esys::*, Context, Error, Result,
+};
+use log::error;
 
 impl Context {
-    // Missing function: PP_Commands
-    // Missing function: SetAlgorithmSet
+    /// Sets the command codes that require physical presence.
+    ///
+    /// # Details
+    /// This method is used to determine which commands require
+    /// that physical presence is asserted in order to be authorized
+    /// using platform authorization.
+    ///
+    /// # Arguments
+    /// * `auth` - The [Provision] used for authorization, must be [Provision::Platform].
+    /// * `set_list` - The command codes that are to be added to the list of commands requiring physical presence.
+    /// * `clear_list` - The command codes that are to be removed from the list of commands requiring physical presence.
+    pub fn pp_commands(
+        &mut self,
+        auth: Provision,
+        set_list: CommandCodeList,
+        clear_list: CommandCodeList,
+    ) -> Result<()> {
+        let ret = unsafe {
+            Esys_PP_Commands(
+                self.mut_context(),
+                AuthHandle::from(auth).into(),
+                self.required_session_1()?,
+                self.optional_session_2(),
+                self.optional_session_3(),
+                &set_list.into(),
+                &clear_list.into(),
+            )
+        };
+        let ret = Error::from_tss_rc(ret);
+        if ret.is_success() {
+            Ok(())
+        } else {
+            error!(
+                "Error when setting commands requiring physical presence: {}",
+                ret
+            );
+            Err(ret)
+        }
+    }
+
+    /// Sets the algorithm set that is enabled in the TPM.
+    ///
+    /// # Details
+    /// This method is used to select which one of the vendor defined
+    /// algorithm sets that is to be enabled. The change will take
+    /// effect after the next TPM2_Startup(TPM_SU_CLEAR).
+    ///
+    /// # Arguments
+    /// * `auth` - The [Provision] used for authorization, must be [Provision::Platform].
+    /// * `algorithm_set` - A TPM vendor dependent value indicating the algorithm set
+    ///   selection. The value 0 indicates the default set of the TPM.
+    pub fn set_algorithm_set(&mut self, auth: Provision, algorithm_set: u32) -> Result<()> {
+        let ret = unsafe {
+            Esys_SetAlgorithmSet(
+                self.mut_context(),
+                AuthHandle::from(auth).into(),
+                self.required_session_1()?,
+                self.optional_session_2(),
+                self.optional_session_3(),
+                algorithm_set,
+            )
+        };
+        let ret = Error::from_tss_rc(ret);
+        if ret.is_success() {
+            Ok(())
+        } else {
+            error!("Error when setting algorithm set: {}", ret);
+            Err(ret)
+        }
+    }
 }
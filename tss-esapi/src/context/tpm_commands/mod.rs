@@ -5,6 +5,7 @@ mod attached_components;
 mod attestation_commands;
 mod authenticated_countdown_timer;
 mod capability_commands;
+pub use capability_commands::CapabilityIterator;
 mod clocks_and_timers;
 mod command_audit;
 mod context_management;
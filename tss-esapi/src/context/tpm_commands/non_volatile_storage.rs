@@ -1,13 +1,14 @@
 // Copyright 2021 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
 use crate::{
+    constants::{response_code::TpmResponseCode, PropertyTag},
     context::handle_manager::HandleDropAction,
-    handles::{AuthHandle, NvIndexHandle},
+    handles::{AuthHandle, KeyHandle, NvIndexHandle},
     interface_types::resource_handles::{NvAuth, Provision},
     nv::storage::NvPublic,
-    structures::{Auth, MaxNvBuffer, Name},
+    structures::{Attest, Auth, Data, MaxNvBuffer, Name, Signature, SignatureScheme},
     tss2_esys::*,
-    Context, Error, Result,
+    Context, Error, Result, WrapperErrorKind,
 };
 use log::error;
 use mbox::MBox;
@@ -31,6 +32,15 @@ impl Context {
         auth: Option<&Auth>,
         public_info: &NvPublic,
     ) -> Result<NvIndexHandle> {
+        if let Some(nv_index_max) = self.get_tpm_property(PropertyTag::NvIndexMax)? {
+            if public_info.data_size() > nv_index_max as usize {
+                error!(
+                    "Error: data area size is larger than the maximum supported by the TPM ({})",
+                    nv_index_max
+                );
+                return Err(Error::local_error(WrapperErrorKind::InvalidParam));
+            }
+        }
         let mut object_identifier: ESYS_TR = ESYS_TR_NONE;
         let ret = unsafe {
             Esys_NV_DefineSpace(
@@ -91,13 +101,60 @@ impl Context {
         }
     }
 
-    // Missing function: UndefineSpaceSpecial
+    /// Deletes an index in the non volatile storage that
+    /// requires policy for deletion.
+    ///
+    /// # Details
+    /// The method will instruct the TPM to remove a nv index
+    /// that was defined with the
+    /// [policy_delete](crate::attributes::NvIndexAttributes::policy_delete)
+    /// attribute set. Such indexes cannot be removed using
+    /// [nv_undefine_space](Context::nv_undefine_space).
+    ///
+    /// # Arguments
+    /// * `nv_index_handle`- The [NvIndexHandle] associated with the nv area that is to be removed.
+    /// * `platform` - The [Provision] used for authorization, which needs to be [Provision::Platform].
+    ///
+    /// # Errors
+    /// * if either of the slots `session_1` or `session_2` is not set, an
+    ///   error is returned. The first session needs to be a policy session
+    ///   satisfying the auth policy of the nv index and the second session
+    ///   is used for the platform authorization.
+    pub fn nv_undefine_space_special(
+        &mut self,
+        nv_index_handle: NvIndexHandle,
+        platform: Provision,
+    ) -> Result<()> {
+        let ret = unsafe {
+            Esys_NV_UndefineSpaceSpecial(
+                self.mut_context(),
+                nv_index_handle.into(),
+                AuthHandle::from(platform).into(),
+                self.required_session_1()?,
+                self.required_session_2()?,
+                self.optional_session_3(),
+            )
+        };
+
+        let ret = Error::from_tss_rc(ret);
+        if ret.is_success() {
+            self.handle_manager.set_as_closed(nv_index_handle.into())?;
+            Ok(())
+        } else {
+            error!("Error when undefining NV space with policy: {}", ret);
+            Err(ret)
+        }
+    }
 
     /// Reads the public part of an nv index.
     ///
     /// # Details
     /// This method is used to read the public
     /// area and name of a nv index.
+    ///
+    /// The public area contains the declared size and the attributes
+    /// of the index, and the name is needed in order to bind HMAC
+    /// sessions to the index.
     pub fn nv_read_public(&mut self, nv_index_handle: NvIndexHandle) -> Result<(NvPublic, Name)> {
         let mut tss_nv_public_ptr = null_mut();
         let mut tss_nv_name_ptr = null_mut();
@@ -159,11 +216,254 @@ impl Context {
         }
     }
 
-    // Missing function: NV_Increment
-    // Missing function: NV_Extend
-    // Missing function: NV_SetBits
-    // Missing function: NV_WriteLock
-    // Missing function: NV_GlobalWriteLock
+    /// Writes all the data to the nv index.
+    ///
+    /// # Details
+    /// The data is written from the start of the index in chunks
+    /// of at most `TPM_PT_NV_BUFFER_MAX` bytes.
+    ///
+    /// # Errors
+    /// * if the data is larger than the size of the index, a
+    ///   `WrongParamSize` wrapper error is returned before
+    ///   anything is written.
+    pub fn nv_write_full(
+        &mut self,
+        auth_handle: NvAuth,
+        nv_index_handle: NvIndexHandle,
+        data: &[u8],
+    ) -> Result<()> {
+        let (nv_public, _) =
+            self.execute_without_session(|ctx| ctx.nv_read_public(nv_index_handle))?;
+        if data.len() > nv_public.data_size() {
+            error!(
+                "Error: data is larger than the size of the nv index ({})",
+                nv_public.data_size()
+            );
+            return Err(Error::local_error(WrapperErrorKind::WrongParamSize));
+        }
+        let chunk_size = self.nv_buffer_chunk_size()?;
+
+        for (index, chunk) in data.chunks(chunk_size).enumerate() {
+            self.nv_write(
+                auth_handle,
+                nv_index_handle,
+                &MaxNvBuffer::try_from(chunk.to_vec())?,
+                (index * chunk_size) as u16,
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Increments the counter nv index.
+    ///
+    /// # Arguments
+    /// * `auth_handle` - The [NvAuth] used for authorization.
+    /// * `nv_index_handle` - The handle of the nv index.
+    ///
+    /// # Details
+    /// The nv index has to be of the
+    /// [Counter](crate::constants::NvIndexType::Counter) type.
+    /// The value of the counter is read as an 8 byte big endian integer.
+    ///
+    /// # Errors
+    /// * if the nv index is not a counter, the TSS error returned by the TPM, of kind
+    ///   [AttributesMismatch](crate::constants::response_code::TpmResponseCode::AttributesMismatch),
+    ///   is returned.
+    pub fn nv_increment(
+        &mut self,
+        auth_handle: NvAuth,
+        nv_index_handle: NvIndexHandle,
+    ) -> Result<()> {
+        let ret = unsafe {
+            Esys_NV_Increment(
+                self.mut_context(),
+                AuthHandle::from(auth_handle).into(),
+                nv_index_handle.into(),
+                self.required_session_1()?,
+                self.optional_session_2(),
+                self.optional_session_3(),
+            )
+        };
+        let ret = Error::from_tss_rc(ret);
+        if ret.is_success() {
+            Ok(())
+        } else if ret.tpm_response_code() == Some(TpmResponseCode::AttributesMismatch) {
+            error!(
+                "Error when incrementing NV, the nv index is not a counter: {}",
+                ret
+            );
+            Err(ret)
+        } else {
+            error!("Error when incrementing NV: {}", ret);
+            Err(ret)
+        }
+    }
+
+    /// Extends the extend nv index with the data.
+    ///
+    /// # Arguments
+    /// * `auth_handle` - The [NvAuth] used for authorization.
+    /// * `nv_index_handle` - The handle of the nv index.
+    /// * `data` - The data that is extended into the nv index.
+    ///
+    /// # Details
+    /// The nv index has to be of the
+    /// [Extend](crate::constants::NvIndexType::Extend) type. Its new value
+    /// is the digest, with the name algorithm of the index, of the previous
+    /// value concatenated with the data.
+    ///
+    /// # Errors
+    /// * if the nv index is not an extend index, the TSS error returned by the TPM,
+    ///   of kind [AttributesMismatch](crate::constants::response_code::TpmResponseCode::AttributesMismatch),
+    ///   is returned.
+    pub fn nv_extend(
+        &mut self,
+        auth_handle: NvAuth,
+        nv_index_handle: NvIndexHandle,
+        data: MaxNvBuffer,
+    ) -> Result<()> {
+        let ret = unsafe {
+            Esys_NV_Extend(
+                self.mut_context(),
+                AuthHandle::from(auth_handle).into(),
+                nv_index_handle.into(),
+                self.required_session_1()?,
+                self.optional_session_2(),
+                self.optional_session_3(),
+                &data.into(),
+            )
+        };
+        let ret = Error::from_tss_rc(ret);
+        if ret.is_success() {
+            Ok(())
+        } else if ret.tpm_response_code() == Some(TpmResponseCode::AttributesMismatch) {
+            error!(
+                "Error when extending NV, the nv index is not an extend index: {}",
+                ret
+            );
+            Err(ret)
+        } else {
+            error!("Error when extending NV: {}", ret);
+            Err(ret)
+        }
+    }
+
+    /// Sets bits in the bit field nv index.
+    ///
+    /// # Arguments
+    /// * `auth_handle` - The [NvAuth] used for authorization.
+    /// * `nv_index_handle` - The handle of the nv index.
+    /// * `bits` - The bits that are set in the nv index.
+    ///
+    /// # Details
+    /// The nv index has to be of the
+    /// [Bits](crate::constants::NvIndexType::Bits) type. Its new value
+    /// is the previous value ORed with `bits`, so bits can only be set
+    /// and never cleared. The value is read as an 8 byte big endian integer.
+    ///
+    /// # Errors
+    /// * if the nv index is not a bit field, the TSS error returned by the TPM,
+    ///   of kind [AttributesMismatch](crate::constants::response_code::TpmResponseCode::AttributesMismatch),
+    ///   is returned.
+    pub fn nv_set_bits(
+        &mut self,
+        auth_handle: NvAuth,
+        nv_index_handle: NvIndexHandle,
+        bits: u64,
+    ) -> Result<()> {
+        let ret = unsafe {
+            Esys_NV_SetBits(
+                self.mut_context(),
+                AuthHandle::from(auth_handle).into(),
+                nv_index_handle.into(),
+                self.required_session_1()?,
+                self.optional_session_2(),
+                self.optional_session_3(),
+                bits,
+            )
+        };
+        let ret = Error::from_tss_rc(ret);
+        if ret.is_success() {
+            Ok(())
+        } else if ret.tpm_response_code() == Some(TpmResponseCode::AttributesMismatch) {
+            error!(
+                "Error when setting bits in NV, the nv index is not a bit field: {}",
+                ret
+            );
+            Err(ret)
+        } else {
+            error!("Error when setting bits in NV: {}", ret);
+            Err(ret)
+        }
+    }
+
+    /// Write locks the nv index.
+    ///
+    /// # Arguments
+    /// * `auth_handle` - The [NvAuth] used for authorization.
+    /// * `nv_index_handle` - The handle of the nv index.
+    ///
+    /// # Details
+    /// This method will instruct the TPM to set the write locked attribute
+    /// of the nv index. If the index was defined with the
+    /// [write_stclear](crate::attributes::NvIndexAttributes::write_stclear)
+    /// attribute set, it stays locked until the next TPM Reset or TPM Restart.
+    /// If it was defined with the
+    /// [write_define](crate::attributes::NvIndexAttributes::write_define)
+    /// attribute set and has been written, it stays locked until it is undefined.
+    pub fn nv_write_lock(
+        &mut self,
+        auth_handle: NvAuth,
+        nv_index_handle: NvIndexHandle,
+    ) -> Result<()> {
+        let ret = unsafe {
+            Esys_NV_WriteLock(
+                self.mut_context(),
+                AuthHandle::from(auth_handle).into(),
+                nv_index_handle.into(),
+                self.required_session_1()?,
+                self.optional_session_2(),
+                self.optional_session_3(),
+            )
+        };
+        let ret = Error::from_tss_rc(ret);
+        if ret.is_success() {
+            Ok(())
+        } else {
+            error!("Error when write locking NV: {}", ret);
+            Err(ret)
+        }
+    }
+
+    /// Write locks all nv indexes with the global lock attribute.
+    ///
+    /// # Details
+    /// This method will instruct the TPM to set the write locked
+    /// attribute on every nv index that was defined with the
+    /// [global_lock](crate::attributes::NvIndexAttributes::global_lock)
+    /// attribute set. The indexes stay locked until the next
+    /// TPM Reset or TPM Restart.
+    ///
+    /// # Arguments
+    /// * `auth_handle` - The [Provision] used for authorization.
+    pub fn nv_global_write_lock(&mut self, auth_handle: Provision) -> Result<()> {
+        let ret = unsafe {
+            Esys_NV_GlobalWriteLock(
+                self.mut_context(),
+                AuthHandle::from(auth_handle).into(),
+                self.required_session_1()?,
+                self.optional_session_2(),
+                self.optional_session_3(),
+            )
+        };
+        let ret = Error::from_tss_rc(ret);
+        if ret.is_success() {
+            Ok(())
+        } else {
+            error!("Error when global write locking NV: {}", ret);
+            Err(ret)
+        }
+    }
 
     /// Reads data from the nv index.
     ///
@@ -202,7 +502,186 @@ impl Context {
         }
     }
 
-    // Missing function: NV_ReadLock
-    // Missing function: NV_ChangeAuth
-    // Missing function: NV_Certify
+    /// Reads all the data of the nv index.
+    ///
+    /// # Details
+    /// The size of the index is read from its public area and
+    /// the data is read in chunks of at most `TPM_PT_NV_BUFFER_MAX`
+    /// bytes, which are concatenated.
+    ///
+    /// # Errors
+    /// * if the size of the index is larger than what a [MaxNvBuffer]
+    ///   can hold, a `WrongParamSize` wrapper error is returned.
+    pub fn nv_read_full(
+        &mut self,
+        auth_handle: NvAuth,
+        nv_index_handle: NvIndexHandle,
+    ) -> Result<MaxNvBuffer> {
+        let (nv_public, _) =
+            self.execute_without_session(|ctx| ctx.nv_read_public(nv_index_handle))?;
+        let data_size = nv_public.data_size();
+        if data_size > MaxNvBuffer::MAX_SIZE {
+            error!(
+                "Error: nv index size is larger than the maximum nv buffer size ({})",
+                MaxNvBuffer::MAX_SIZE
+            );
+            return Err(Error::local_error(WrapperErrorKind::WrongParamSize));
+        }
+        let chunk_size = self.nv_buffer_chunk_size()?;
+
+        let mut data = Vec::with_capacity(data_size);
+        for offset in (0..data_size).step_by(chunk_size) {
+            // The last chunk may be smaller than the buffer max.
+            let size = std::cmp::min(chunk_size, data_size - offset);
+            let chunk = self.nv_read(auth_handle, nv_index_handle, size as u16, offset as u16)?;
+            data.extend_from_slice(&chunk);
+        }
+        MaxNvBuffer::try_from(data)
+    }
+
+    /// Read locks the nv index.
+    ///
+    /// # Arguments
+    /// * `auth_handle` - The [NvAuth] used for authorization.
+    /// * `nv_index_handle` - The handle of the nv index.
+    ///
+    /// # Details
+    /// This method will instruct the TPM to set the read locked attribute
+    /// of the nv index, which has to be defined with the
+    /// [read_stclear](crate::attributes::NvIndexAttributes::read_stclear)
+    /// attribute set. The index stays locked until the next TPM Reset or
+    /// TPM Restart.
+    pub fn nv_read_lock(
+        &mut self,
+        auth_handle: NvAuth,
+        nv_index_handle: NvIndexHandle,
+    ) -> Result<()> {
+        let ret = unsafe {
+            Esys_NV_ReadLock(
+                self.mut_context(),
+                AuthHandle::from(auth_handle).into(),
+                nv_index_handle.into(),
+                self.required_session_1()?,
+                self.optional_session_2(),
+                self.optional_session_3(),
+            )
+        };
+        let ret = Error::from_tss_rc(ret);
+        if ret.is_success() {
+            Ok(())
+        } else {
+            error!("Error when read locking NV: {}", ret);
+            Err(ret)
+        }
+    }
+
+    /// Changes the authorization value of the nv index.
+    ///
+    /// # Arguments
+    /// * `nv_index_handle` - The handle of the nv index.
+    /// * `new_auth` - The new authorization value.
+    ///
+    /// # Details
+    /// This command requires the admin role of the nv index, so the first
+    /// session has to be a policy session satisfying the policy of the
+    /// index, which has to include the `TPM2_CC_NV_ChangeAuth` command code.
+    ///
+    /// The new authorization value has to be set with [Context::tr_set_auth]
+    /// before using it to authorize the use of the nv index.
+    pub fn nv_change_auth(&mut self, nv_index_handle: NvIndexHandle, new_auth: Auth) -> Result<()> {
+        let ret = unsafe {
+            Esys_NV_ChangeAuth(
+                self.mut_context(),
+                nv_index_handle.into(),
+                self.required_session_1()?,
+                self.optional_session_2(),
+                self.optional_session_3(),
+                &new_auth.into(),
+            )
+        };
+        let ret = Error::from_tss_rc(ret);
+        if ret.is_success() {
+            Ok(())
+        } else {
+            error!("Error when changing NV auth: {}", ret);
+            Err(ret)
+        }
+    }
+
+    /// Certifies the contents of an nv index.
+    ///
+    /// # Details
+    /// This method is used to get the TPM to sign the contents
+    /// of an area in NV memory, so that a verifier can be convinced
+    /// that the index holds a specific value.
+    ///
+    /// # Arguments
+    /// * `sign_handle` - Handle of the key used to sign the attestation structure.
+    /// * `auth_handle` - Handle indicating the source of the authorization value for the NV index.
+    /// * `nv_index` - Handle of the NV index that is being certified.
+    /// * `qualifying_data` - User provided qualifying data.
+    /// * `signing_scheme` - Signing scheme to use if the scheme for `sign_handle` is `Null`.
+    /// * `size` - The number of octets to certify.
+    /// * `offset` - The octet offset into the NV area.
+    ///
+    /// # Returns
+    /// The attestation structure, containing the certified NV contents,
+    /// together with the signature over it.
+    ///
+    /// # Errors
+    /// * if either of the slots `session_1` or `session_2` is not set, an
+    ///   error is returned, as both handles require an authorization session.
+    #[allow(clippy::too_many_arguments)]
+    pub fn nv_certify(
+        &mut self,
+        sign_handle: KeyHandle,
+        auth_handle: AuthHandle,
+        nv_index: NvIndexHandle,
+        qualifying_data: Data,
+        signing_scheme: SignatureScheme,
+        size: u16,
+        offset: u16,
+    ) -> Result<(Attest, Signature)> {
+        let mut certify_info_ptr = null_mut();
+        let mut signature_ptr = null_mut();
+        let ret = unsafe {
+            Esys_NV_Certify(
+                self.mut_context(),
+                sign_handle.into(),
+                auth_handle.into(),
+                nv_index.into(),
+                self.required_session_1()?,
+                self.required_session_2()?,
+                self.optional_session_3(),
+                &qualifying_data.into(),
+                &signing_scheme.into(),
+                size,
+                offset,
+                &mut certify_info_ptr,
+                &mut signature_ptr,
+            )
+        };
+        let ret = Error::from_tss_rc(ret);
+        if ret.is_success() {
+            let certify_info = unsafe { MBox::<TPM2B_ATTEST>::from_raw(certify_info_ptr) };
+            let signature = unsafe { MBox::from_raw(signature_ptr) };
+            Ok((
+                Attest::try_from(*certify_info)?,
+                Signature::try_from(*signature)?,
+            ))
+        } else {
+            error!("Error when certifying NV: {}", ret);
+            Err(ret)
+        }
+    }
+
+    /// Returns the size of the chunks used when reading or writing
+    /// nv indices, which is limited by `TPM_PT_NV_BUFFER_MAX`.
+    fn nv_buffer_chunk_size(&mut self) -> Result<usize> {
+        Ok(self
+            .get_tpm_property(PropertyTag::NvBufferMax)?
+            .map_or(MaxNvBuffer::MAX_SIZE, |nv_buffer_max| {
+                std::cmp::min(nv_buffer_max as usize, MaxNvBuffer::MAX_SIZE)
+            }))
+    }
 }
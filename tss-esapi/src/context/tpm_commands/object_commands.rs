@@ -6,10 +6,12 @@ use crate::{
     interface_types::resource_handles::Hierarchy,
     structures::{
         Auth, CreateKeyResult, CreationData, CreationTicket, Data, Digest, EncryptedSecret,
-        IDObject, Name, PcrSelectionList, Private, Public, SensitiveData,
+        IDObject, Name, PcrSelectionList, Private, Public, Sensitive, SensitiveCreate,
+        SensitiveData,
     },
+    traits::marshall_tss,
     tss2_esys::*,
-    Context, Error, Result,
+    Context, Error, Result, WrapperErrorKind,
 };
 use log::error;
 use mbox::MBox;
@@ -134,22 +136,34 @@ impl Context {
         }
     }
 
-    /// Load an external key into the TPM and return its new handle.
+    /// Load an external object into the TPM and return its new handle.
+    ///
+    /// # Arguments
+    /// * `private` - The sensitive area of the object, or `None` if only
+    ///   the public area is to be loaded.
+    /// * `public` - The public area of the object.
+    /// * `hierarchy` - The hierarchy the object is associated with. Objects
+    ///   with a sensitive area are usually loaded into the [Hierarchy::Null].
+    ///
+    /// # Details
+    /// When the sensitive area is provided, the TPM requires the `fixed_tpm`,
+    /// `fixed_parent` and `restricted` attributes of the public area to be clear.
     pub fn load_external(
         &mut self,
-        private: &TPM2B_SENSITIVE,
-        public: &Public,
+        private: Option<Sensitive>,
+        public: Public,
         hierarchy: Hierarchy,
     ) -> Result<KeyHandle> {
         let mut esys_key_handle = ESYS_TR_NONE;
+        let private = private.map(TPM2B_SENSITIVE::from);
         let ret = unsafe {
             Esys_LoadExternal(
                 self.mut_context(),
                 self.optional_session_1(),
                 self.optional_session_2(),
                 self.optional_session_3(),
-                private,
-                &public.clone().into(),
+                private.as_ref().map_or_else(null, |private| private),
+                &public.into(),
                 if cfg!(tpm2_tss_version = "3") {
                     ObjectHandle::from(hierarchy).into()
                 } else {
@@ -178,35 +192,7 @@ impl Context {
         public: &Public,
         hierarchy: Hierarchy,
     ) -> Result<KeyHandle> {
-        let mut esys_key_handle = ESYS_TR_NONE;
-        let ret = unsafe {
-            Esys_LoadExternal(
-                self.mut_context(),
-                self.optional_session_1(),
-                self.optional_session_2(),
-                self.optional_session_3(),
-                null(),
-                &public.clone().into(),
-                if cfg!(tpm2_tss_version = "3") {
-                    ObjectHandle::from(hierarchy).into()
-                } else {
-                    TpmHandle::from(hierarchy).into()
-                },
-                &mut esys_key_handle,
-            )
-        };
-
-        let ret = Error::from_tss_rc(ret);
-
-        if ret.is_success() {
-            let key_handle = KeyHandle::from(esys_key_handle);
-            self.handle_manager
-                .add_handle(key_handle.into(), HandleDropAction::Flush)?;
-            Ok(key_handle)
-        } else {
-            error!("Error in loading external public object: {}", ret);
-            Err(ret)
-        }
+        self.load_external(None, public.clone(), hierarchy)
     }
 
     /// Read the public part of a key currently in the TPM and return it.
@@ -322,6 +308,19 @@ impl Context {
     }
 
     /// Unseal and return data from a Sealed Data Object
+    ///
+    /// # Arguments
+    /// * `item_handle` - The handle of the loaded sealed data object.
+    ///
+    /// # Details
+    /// The use of the object has to be authorized by the first session, either
+    /// with its authorization value or with a policy session satisfying its policy.
+    /// Sealed data objects can be built with
+    /// [PublicBuilder::build_sealing_object](crate::structures::PublicBuilder::build_sealing_object).
+    ///
+    /// # Errors
+    /// * if the first session is missing, a `MissingAuthSession` wrapper error
+    ///   is returned.
     pub fn unseal(&mut self, item_handle: ObjectHandle) -> Result<SensitiveData> {
         let mut out_data = null_mut();
 
@@ -329,7 +328,7 @@ impl Context {
             Esys_Unseal(
                 self.mut_context(),
                 item_handle.into(),
-                self.optional_session_1(),
+                self.required_session_1()?,
                 self.optional_session_2(),
                 self.optional_session_3(),
                 &mut out_data,
@@ -347,6 +346,12 @@ impl Context {
     }
 
     /// Change authorization for a TPM-resident object.
+    ///
+    /// # Details
+    /// The loaded object is not modified, instead a new private area
+    /// protected by `new_auth` is returned. It must be loaded under the
+    /// same parent, using the existing public area, in order to use the
+    /// new authorization value.
     pub fn object_change_auth(
         &mut self,
         object_handle: ObjectHandle,
@@ -377,5 +382,74 @@ impl Context {
         }
     }
 
-    // Missing function: CreateLoaded
+    /// Create an object and load it into the TPM.
+    ///
+    /// # Arguments
+    /// * `parent_handle` - The [ObjectHandle] of the parent for the new object, this
+    ///                     can be a hierarchy, a storage key or a derivation parent.
+    /// * `in_sensitive` - The sensitive values of the object that is being created.
+    /// * `in_public` - The public template of the object that is being created.
+    ///
+    /// # Details
+    /// This combines [Context::create] and [Context::load] in a single
+    /// command. When the parent is a derivation parent, the `in_public`
+    /// is used as a template for deriving the new object.
+    ///
+    /// # Returns
+    /// A tuple containing the handle of the loaded object, and its
+    /// private and public parts.
+    pub fn create_loaded(
+        &mut self,
+        parent_handle: ObjectHandle,
+        in_sensitive: SensitiveCreate,
+        in_public: Public,
+    ) -> Result<(ObjectHandle, Private, Public)> {
+        // The template is the marshalled public area.
+        let public_area = marshall_tss(
+            &TPM2B_PUBLIC::from(in_public).publicArea,
+            Tss2_MU_TPMT_PUBLIC_Marshal,
+        )?;
+        let mut in_public_template = TPM2B_TEMPLATE {
+            size: public_area.len().try_into().map_err(|_| {
+                error!("Error: The public template is too large");
+                Error::local_error(WrapperErrorKind::WrongParamSize)
+            })?,
+            ..Default::default()
+        };
+        in_public_template.buffer[..public_area.len()].copy_from_slice(&public_area);
+
+        let mut esys_object_handle = ESYS_TR_NONE;
+        let mut out_private_ptr = null_mut();
+        let mut out_public_ptr = null_mut();
+        let ret = unsafe {
+            Esys_CreateLoaded(
+                self.mut_context(),
+                parent_handle.into(),
+                self.optional_session_1(),
+                self.optional_session_2(),
+                self.optional_session_3(),
+                &in_sensitive.into(),
+                &in_public_template,
+                &mut esys_object_handle,
+                &mut out_private_ptr,
+                &mut out_public_ptr,
+            )
+        };
+        let ret = Error::from_tss_rc(ret);
+        if ret.is_success() {
+            let out_private_owned = unsafe { MBox::from_raw(out_private_ptr) };
+            let out_public_owned = unsafe { MBox::from_raw(out_public_ptr) };
+            let object_handle = ObjectHandle::from(esys_object_handle);
+            self.handle_manager
+                .add_handle(object_handle, HandleDropAction::Flush)?;
+            Ok((
+                object_handle,
+                Private::try_from(*out_private_owned)?,
+                Public::try_from(*out_public_owned)?,
+            ))
+        } else {
+            error!("Error in creating loaded object: {}", ret);
+            Err(ret)
+        }
+    }
 }
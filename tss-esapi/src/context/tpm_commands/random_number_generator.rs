@@ -43,6 +43,12 @@ impl Context {
     }
 
     /// Add additional information into the TPM RNG state
+    ///
+    /// # Details
+    /// The size of `in_data` is bounded by the maximum size of
+    /// `TPM2B_SENSITIVE_DATA` when the [SensitiveData] is created,
+    /// so oversized data is rejected with a `WrongParamSize` wrapper
+    /// error before reaching this method.
     pub fn stir_random(&mut self, in_data: SensitiveData) -> Result<()> {
         let ret = unsafe {
             Esys_StirRandom(
@@ -1,6 +1,7 @@
 // Copyright 2021 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
 use crate::{
+    constants::response_code::Tss2ResponseCodeKind,
     handles::KeyHandle,
     structures::{Digest, HashcheckTicket, Signature, VerifiedTicket},
     tss2_esys::*,
@@ -13,6 +14,20 @@ use std::ptr::null_mut;
 
 impl Context {
     /// Verify if a signature was generated by signing a given digest with a key in the TPM.
+    ///
+    /// # Arguments
+    /// * `key_handle` - The [KeyHandle] of the public key used for verifying the signature.
+    /// * `digest` - The digest that was signed.
+    /// * `signature` - The signature to verify.
+    ///
+    /// # Returns
+    /// The ticket proving that the TPM verified the signature, which
+    /// can be used in a policy authorization.
+    ///
+    /// # Errors
+    /// * if the signature is not valid for the digest, a TSS error of kind
+    ///   [Tss2ResponseCodeKind::Signature](crate::constants::response_code::Tss2ResponseCodeKind::Signature)
+    ///   is returned.
     pub fn verify_signature(
         &mut self,
         key_handle: KeyHandle,
@@ -40,6 +55,11 @@ impl Context {
             let validation = VerifiedTicket::try_from(*validation)?;
             Ok(validation)
         } else {
+            if let Error::Tss2Error(rc) = ret {
+                if rc.kind() == Some(Tss2ResponseCodeKind::Signature) {
+                    error!("The signature is not valid for the digest");
+                }
+            }
             error!("Error when verifying signature: {}", ret);
             Err(ret)
         }
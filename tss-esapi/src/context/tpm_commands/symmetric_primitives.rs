@@ -29,6 +29,12 @@ impl Context {
     /// * `in_data` - The data that is going to be decrypted or encrypted.
     /// * `initial_value_in` - An initial value as required by the algorithm.
     ///
+    /// # Returns
+    /// The command returns a tuple consisting of:
+    /// * `out_data` - The encrypted or decrypted data.
+    /// * `initial_value_out` - The chaining value, which can be used as `initial_value_in`
+    ///   when processing the next block of data.
+    ///
     /// # Example
     /// ```rust
     /// # use tss_esapi::{
@@ -387,5 +393,47 @@ impl Context {
         }
     }
 
-    // Missing function: MAC
+    /// Computes a MAC over the provided data using the key associated with `handle`.
+    ///
+    /// # Details
+    /// TPM2_MAC shares its command code and parameter layout with TPM2_HMAC, so the
+    /// command is issued through `Esys_HMAC` which is available in all supported
+    /// versions of the TSS.
+    ///
+    /// # Arguments
+    /// * `handle` - A [KeyHandle] of the symmetric signing key providing the MAC key.
+    /// * `buffer` - The data that the MAC is calculated over.
+    /// * `mac_scheme` - The algorithm that is used to calculate the MAC.
+    ///
+    /// # Returns
+    /// The MAC as a [Digest].
+    pub fn mac(
+        &mut self,
+        handle: KeyHandle,
+        buffer: MaxBuffer,
+        mac_scheme: HashingAlgorithm,
+    ) -> Result<Digest> {
+        let mut out_mac_ptr = null_mut();
+        let ret = unsafe {
+            Esys_HMAC(
+                self.mut_context(),
+                handle.into(),
+                self.required_session_1()?,
+                self.optional_session_2(),
+                self.optional_session_3(),
+                &buffer.into(),
+                mac_scheme.into(),
+                &mut out_mac_ptr,
+            )
+        };
+        let ret = Error::from_tss_rc(ret);
+
+        if ret.is_success() {
+            let out_mac = unsafe { MBox::<TPM2B_DIGEST>::from_raw(out_mac_ptr) };
+            Ok(Digest::try_from(*out_mac)?)
+        } else {
+            error!("Error in mac: {}", ret);
+            Err(ret)
+        }
+    }
 }
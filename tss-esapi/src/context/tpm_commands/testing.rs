@@ -1,8 +1,8 @@
 // Copyright 2021 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
 use crate::{
-    structures::MaxBuffer,
-    tss2_esys::{Esys_GetTestResult, Esys_SelfTest},
+    structures::{AlgorithmList, MaxBuffer},
+    tss2_esys::{Esys_GetTestResult, Esys_IncrementalSelfTest, Esys_SelfTest},
     Context, Error, Result,
 };
 use log::error;
@@ -32,7 +32,42 @@ impl Context {
         }
     }
 
-    // Missing function: incremental_self_test
+    /// Execute the TPM self test of the algorithms
+    ///
+    /// # Arguments
+    /// * `to_test` - The algorithms that should be tested.
+    ///
+    /// # Details
+    /// The algorithms that have already been tested are not tested again.
+    /// The TPM may schedule the tests and return before they are complete,
+    /// in which case [Context::get_test_result] can be used to get the result.
+    ///
+    /// # Returns
+    /// The algorithms that are still to be tested, including the ones that
+    /// are being tested. An empty list means that all the algorithms have
+    /// been tested.
+    pub fn incremental_self_test(&mut self, to_test: AlgorithmList) -> Result<AlgorithmList> {
+        let mut to_do_list = null_mut();
+        let ret = unsafe {
+            Esys_IncrementalSelfTest(
+                self.mut_context(),
+                self.optional_session_1(),
+                self.optional_session_2(),
+                self.optional_session_3(),
+                &to_test.into(),
+                &mut to_do_list,
+            )
+        };
+        let ret = Error::from_tss_rc(ret);
+
+        if ret.is_success() {
+            let to_do_list = unsafe { MBox::from_raw(to_do_list) };
+            AlgorithmList::try_from(*to_do_list)
+        } else {
+            error!("Error in incremental self-test: {}", ret);
+            Err(ret)
+        }
+    }
 
     /// Get the TPM self test result
     ///
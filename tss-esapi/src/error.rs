@@ -1,6 +1,6 @@
 // Copyright 2020 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
-use crate::constants::response_code::Tss2ResponseCode;
+use crate::constants::response_code::{TpmResponseCode, Tss2ResponseCode};
 use crate::tss2_esys::TSS2_RC;
 /// Main error type used by the crate to return issues with a method call. The value can either be
 /// a TSS-generated response code or a wrapper error - marking an issue caught within the wrapping
@@ -30,6 +30,53 @@ impl Error {
             false
         }
     }
+
+    /// Verify whether the value contained is a warning response code.
+    ///
+    /// # Details
+    /// Warnings are the format-zero response codes with the severity
+    /// bit set. They indicate that the command was not executed, but
+    /// not necessarily that it was wrong.
+    pub fn is_warning(&self) -> bool {
+        if let Error::Tss2Error(tss2_rc) = self {
+            tss2_rc.is_warning()
+        } else {
+            false
+        }
+    }
+
+    /// Verify whether the command that failed with this error can
+    /// simply be sent again.
+    ///
+    /// # Details
+    /// This is the case for the `TPM_RC_RETRY`, `TPM_RC_TESTING` and
+    /// `TPM_RC_YIELDED` warnings.
+    pub fn is_retryable(&self) -> bool {
+        matches!(
+            self.tpm_response_code(),
+            Some(TpmResponseCode::Retry)
+                | Some(TpmResponseCode::Testing)
+                | Some(TpmResponseCode::Yielded)
+        )
+    }
+
+    /// Get the TPM response code contained in the error.
+    ///
+    /// # Details
+    /// This allows matching on the named TPM response codes, such as
+    /// [TpmResponseCode::AuthFail] or [TpmResponseCode::Retry],
+    /// without having to destructure the error first.
+    ///
+    /// # Returns
+    /// The TPM response code, or `None` if the error is a wrapper error
+    /// or the success code. A response code without a named variant is
+    /// returned as [TpmResponseCode::Unknown] with its raw value.
+    pub fn tpm_response_code(&self) -> Option<TpmResponseCode> {
+        match self {
+            Error::Tss2Error(rc) => TpmResponseCode::from_response_code(*rc),
+            Error::WrapperError(_) => None,
+        }
+    }
 }
 
 impl std::fmt::Display for Error {
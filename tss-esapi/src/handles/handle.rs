@@ -248,6 +248,55 @@ pub mod pcr {
     impl_multiple_constant_values_handle_conversion!(PcrHandle, ObjectHandle);
 }
 
+/// Authenticated countdown timer handle module
+///
+/// The ESAPI of version 3 of the tpm2-tss library
+/// specifies preallocated identifiers for the
+/// authenticated countdown timers (ACT), the ActHandle
+/// is a wrapper for those handles.
+pub mod act {
+    use super::{auth::AuthHandle, object::ObjectHandle};
+    use crate::{tss2_esys::ESYS_TR, Error, Result, WrapperErrorKind};
+
+    use log::error;
+    use num_derive::{FromPrimitive, ToPrimitive};
+    use num_traits::{FromPrimitive, ToPrimitive};
+    use std::convert::{From, TryFrom};
+
+    /// The first preallocated ESYS_TR for the authenticated countdown timers.
+    const ESYS_TR_RH_ACT_FIRST: ESYS_TR = 0x120;
+
+    /// ACT handle
+    ///
+    /// Handles to the pre-allocated authenticated countdown
+    /// timer objects. The corresponding TPM handles are in the
+    /// range TPM2_RH_ACT_0 (0x40000110) to TPM2_RH_ACT_F (0x4000011F).
+    #[derive(FromPrimitive, ToPrimitive, Debug, Copy, Clone, PartialEq, Eq)]
+    #[repr(u32)]
+    pub enum ActHandle {
+        Act0 = ESYS_TR_RH_ACT_FIRST,
+        Act1 = ESYS_TR_RH_ACT_FIRST + 0x1,
+        Act2 = ESYS_TR_RH_ACT_FIRST + 0x2,
+        Act3 = ESYS_TR_RH_ACT_FIRST + 0x3,
+        Act4 = ESYS_TR_RH_ACT_FIRST + 0x4,
+        Act5 = ESYS_TR_RH_ACT_FIRST + 0x5,
+        Act6 = ESYS_TR_RH_ACT_FIRST + 0x6,
+        Act7 = ESYS_TR_RH_ACT_FIRST + 0x7,
+        Act8 = ESYS_TR_RH_ACT_FIRST + 0x8,
+        Act9 = ESYS_TR_RH_ACT_FIRST + 0x9,
+        ActA = ESYS_TR_RH_ACT_FIRST + 0xA,
+        ActB = ESYS_TR_RH_ACT_FIRST + 0xB,
+        ActC = ESYS_TR_RH_ACT_FIRST + 0xC,
+        ActD = ESYS_TR_RH_ACT_FIRST + 0xD,
+        ActE = ESYS_TR_RH_ACT_FIRST + 0xE,
+        ActF = ESYS_TR_RH_ACT_FIRST + 0xF,
+    }
+
+    impl_basic_multiple_constant_values_handle!(ActHandle);
+    impl_multiple_constant_values_handle_conversion!(ActHandle, ObjectHandle);
+    impl_multiple_constant_values_handle_conversion!(ActHandle, AuthHandle);
+}
+
 /// Macro for implmeneting conversion between handles.
 macro_rules! impl_handle_conversion {
     ($handle_type_self:ident, $handle_type_other:ident) => {
@@ -288,7 +337,7 @@ pub mod auth {
     add_constant_handle!(AuthHandle, Endorsement, ESYS_TR_RH_ENDORSEMENT);
     add_constant_handle!(AuthHandle, Platform, ESYS_TR_RH_PLATFORM);
     // TODO: Figure out how to add AUTH_00 to AUTH_FF range
-    // TODO: Figure out how to add ACT_0 to ACT_F range
+    // The ACT_0 to ACT_F range is available through the ActHandle.
 }
 
 /// NV Index handle module
@@ -306,6 +355,22 @@ pub mod nv_index {
     impl_handle_conversion!(NvIndexHandle, AuthHandle);
 }
 
+/// Attached component handle module
+pub mod attached_component {
+    use super::object::ObjectHandle;
+    impl_basic_handle!(
+        /// Attached Component Handle
+        ///
+        /// Represents an esys resource handle for an
+        /// attached component. The corresponding TPM handles
+        /// are in the range TPM2_AC_FIRST (0x90000000) to
+        /// TPM2_AC_LAST (0x9000FFFF), see
+        /// [AttachedComponentTpmHandle](crate::handles::AttachedComponentTpmHandle).
+        AttachedComponentHandle
+    );
+    impl_handle_conversion!(AttachedComponentHandle, ObjectHandle);
+}
+
 /// Key handle module
 pub mod key {
     use super::object::ObjectHandle;
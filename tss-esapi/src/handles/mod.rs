@@ -8,6 +8,8 @@
 /////////////////////////////////////////////////////////
 /// ESYS Handles
 /////////////////////////////////////////////////////////
+pub use handle::act::ActHandle;
+pub use handle::attached_component::AttachedComponentHandle;
 pub use handle::auth::AuthHandle;
 pub use handle::key::KeyHandle;
 pub use handle::nv_index::NvIndexHandle;
@@ -15,6 +15,7 @@ use std::convert::TryFrom;
 /// # Details
 /// This corresponds to TPMI_ALG_HASH interface type.
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum HashingAlgorithm {
     Sha1,
     Sha256,
@@ -27,6 +28,24 @@ pub enum HashingAlgorithm {
     Null,
 }
 
+impl HashingAlgorithm {
+    /// Returns the size in bytes of the digests produced by the hashing algorithm.
+    ///
+    /// # Returns
+    /// The size of the digests, or `None` for the `Null` algorithm.
+    pub const fn digest_size(&self) -> Option<usize> {
+        match self {
+            HashingAlgorithm::Sha1 => Some(20),
+            HashingAlgorithm::Sha256 | HashingAlgorithm::Sm3_256 | HashingAlgorithm::Sha3_256 => {
+                Some(32)
+            }
+            HashingAlgorithm::Sha384 | HashingAlgorithm::Sha3_384 => Some(48),
+            HashingAlgorithm::Sha512 | HashingAlgorithm::Sha3_512 => Some(64),
+            HashingAlgorithm::Null => None,
+        }
+    }
+}
+
 impl From<HashingAlgorithm> for AlgorithmIdentifier {
     fn from(hashing_algorithm: HashingAlgorithm) -> Self {
         match hashing_algorithm {
@@ -128,6 +147,7 @@ impl TryFrom<TPMI_ALG_KEYEDHASH_SCHEME> for KeyedHashSchemeAlgorithm {
 ///
 /// # Details this corresponds to the TPMI_ALG_KDF
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum KeyDerivationFunction {
     Kdf1Sp800_56a,
     Kdf2,
@@ -238,6 +258,7 @@ impl TryFrom<TPMI_ALG_SYM> for SymmetricAlgorithm {
 /// # Details
 /// Corresponds to TPMI_ALG_SYM_MODE.
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SymmetricMode {
     Ctr,
     Ofb,
@@ -7,6 +7,7 @@ use std::convert::TryFrom;
 /// # Details
 /// This corresponds to TPMI_ECC_CURVE
 #[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum EccCurve {
     NistP192,
     NistP224,
@@ -10,6 +10,7 @@ use std::convert::TryFrom;
 /// # Details
 /// This corresponds to TPMI_AES_KEY_BITS
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum AesKeyBits {
     Aes128,
     Aes192,
@@ -43,6 +44,7 @@ impl TryFrom<TPMI_AES_KEY_BITS> for AesKeyBits {
 /// # Details
 /// This corresponds to TPMI_SM4_KEY_BITS
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Sm4KeyBits {
     Sm4_128,
 }
@@ -77,6 +79,7 @@ impl TryFrom<TPMI_SM4_KEY_BITS> for Sm4KeyBits {
 // the interface type defined as
 // pub type TPMI_CAMELLIA_KEY_BITS = TPM2_KEY_BITS
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum CamelliaKeyBits {
     Camellia128,
     Camellia192,
@@ -110,6 +113,7 @@ impl TryFrom<TPM2_KEY_BITS> for CamelliaKeyBits {
 /// # Details
 /// This corresponds to TPMI_RSA_KEY_BITS
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum RsaKeyBits {
     Rsa1024,
     Rsa2048,
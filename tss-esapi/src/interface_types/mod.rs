@@ -4,6 +4,7 @@
 //! This module contains the different interface types defined in
 //! the TPM 2.0 specification.
 pub mod algorithm;
+pub mod clock;
 pub mod dynamic_handles;
 pub mod ecc;
 pub mod key_bits;
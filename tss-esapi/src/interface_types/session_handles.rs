@@ -62,6 +62,17 @@ pub enum HmacSession {
     },
 }
 
+impl From<HmacSession> for SessionHandle {
+    fn from(hmac_session: HmacSession) -> SessionHandle {
+        match hmac_session {
+            HmacSession::HmacSession {
+                hashing_algorithm: _,
+                session_handle,
+            } => session_handle,
+        }
+    }
+}
+
 impl From<HmacSession> for AuthSession {
     fn from(hmac_session: HmacSession) -> AuthSession {
         AuthSession::HmacSession(hmac_session)
@@ -62,6 +62,8 @@
 //! kinds of user experience to the developers; at the moment the only implementation allows for a
 //! resource-handle-free coding experience by working soloely with object contexts.
 //! * tss2_esys - exposes raw FFI bindings to the Enhanced System API.
+//! * traits - exposes the `Marshall` and `UnMarshall` traits used to convert structures to and
+//! from the binary form used by the TPM.
 //! * constants - exposes constants that were ported to Rust manually as bindgen does not support
 //! converting them yet.
 //!
@@ -96,7 +98,7 @@
 //! [here](https://github.com/tpm2-software/tpm2-tss/blob/main/doc/logging.md#runtime-log-level).
 //!
 pub use abstraction::transient::TransientKeyContext;
-pub use context::Context;
+pub use context::{CapabilityIterator, Context};
 pub use error::{Error, Result, WrapperErrorKind};
 pub use tcti_ldr::TctiNameConf;
 // To replace painlessly the old Tcti structure, should maybe be deprecated at some point.
@@ -113,4 +115,5 @@ pub mod interface_types;
 pub mod nv;
 pub mod structures;
 pub mod tcti_ldr;
+pub mod traits;
 pub mod utils;
@@ -3,6 +3,7 @@
 
 use crate::{
     attributes::NvIndexAttributes,
+    constants::NvIndexType,
     handles::NvIndexTpmHandle,
     interface_types::algorithm::HashingAlgorithm,
     structures::Digest,
@@ -23,6 +24,8 @@ pub struct NvPublic {
 
 impl NvPublic {
     const MAX_SIZE: usize = std::mem::size_of::<TPMS_NV_PUBLIC>();
+    /// The size of the data area of counter and bit field indices.
+    const COUNTER_DATA_SIZE: usize = 8;
 
     pub fn nv_index(&self) -> NvIndexTpmHandle {
         self.nv_index
@@ -130,12 +133,48 @@ impl NvPublicBuilder {
         self
     }
 
+    /// Builds the NvPublic.
+    ///
+    /// # Details
+    /// The size of the data area of the index can not be checked against
+    /// the maximum size supported by the TPM here, this is instead done
+    /// by [Context::nv_define_space](crate::Context::nv_define_space).
+    ///
+    /// # Errors
+    /// * if the index, the name algorithm, the attributes or the size of
+    ///   the data area has not been specified, a `ParamsMissing` wrapper
+    ///   error is returned.
+    /// * if the index is a counter or bit field index and the size of the
+    ///   data area is not 8, an `InconsistentParams` wrapper error is returned.
+    /// * if the size of the data area is larger than `u16::MAX`, an
+    ///   `InvalidParam` wrapper error is returned.
     pub fn build(self) -> Result<NvPublic> {
         // TODO: Do some clever checking of the values in
         // order to determine some defaults values when
         // some params have not been specified.
         //
 
+        // Index attributes
+        let attributes = self.attributes.ok_or_else(|| {
+            error!("Error: No attributes were specified");
+            Error::local_error(WrapperErrorKind::ParamsMissing)
+        })?;
+
+        // Counter and bit field indices always hold a 64 bit value.
+        if let Some(data_size) = self.data_size {
+            if matches!(
+                attributes.index_type()?,
+                NvIndexType::Counter | NvIndexType::Bits
+            ) && data_size != NvPublic::COUNTER_DATA_SIZE
+            {
+                error!(
+                    "Error: data area size of a counter or bit field index must be {}",
+                    NvPublic::COUNTER_DATA_SIZE
+                );
+                return Err(Error::local_error(WrapperErrorKind::InconsistentParams));
+            }
+        }
+
         Ok(NvPublic {
             // Nv Index
             nv_index: self.nv_index.ok_or_else(|| {
@@ -147,11 +186,7 @@ impl NvPublicBuilder {
                 error!("Error: No name algorithm was specified");
                 Error::local_error(WrapperErrorKind::ParamsMissing)
             })?,
-            // Index attributes
-            attributes: self.attributes.ok_or_else(|| {
-                error!("Error: No attributes were specified");
-                Error::local_error(WrapperErrorKind::ParamsMissing)
-            })?,
+            attributes,
             // Index Auth policy
             authorization_policy: self.authorization_policy.unwrap_or_default(),
             // Size of the data area of the index
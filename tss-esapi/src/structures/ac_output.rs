@@ -0,0 +1,52 @@
+// Copyright 2021 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use crate::{constants::AcCapability, tss2_esys::TPMS_AC_OUTPUT, Error, Result};
+use std::convert::TryFrom;
+
+/// Structure holding the output from an attached
+/// component.
+///
+/// # Details
+/// This corresponds to TPMS_AC_OUTPUT.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct AcOutput {
+    capability: AcCapability,
+    data: u32,
+}
+
+impl AcOutput {
+    /// Creates a new AcOutput
+    pub const fn new(capability: AcCapability, data: u32) -> Self {
+        AcOutput { capability, data }
+    }
+
+    /// Returns the capability that the data is associated with.
+    pub const fn capability(&self) -> AcCapability {
+        self.capability
+    }
+
+    /// Returns the data.
+    pub const fn data(&self) -> u32 {
+        self.data
+    }
+}
+
+impl From<AcOutput> for TPMS_AC_OUTPUT {
+    fn from(ac_output: AcOutput) -> Self {
+        TPMS_AC_OUTPUT {
+            tag: ac_output.capability.into(),
+            data: ac_output.data,
+        }
+    }
+}
+
+impl TryFrom<TPMS_AC_OUTPUT> for AcOutput {
+    type Error = Error;
+
+    fn try_from(tpms_ac_output: TPMS_AC_OUTPUT) -> Result<Self> {
+        Ok(AcOutput {
+            capability: AcCapability::try_from(tpms_ac_output.tag)?,
+            data: tpms_ac_output.data,
+        })
+    }
+}
@@ -0,0 +1,133 @@
+// Copyright 2021 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use crate::{
+    constants::{tss::TPM2_GENERATED_VALUE, StructureTag},
+    structures::{AttestInfo, ClockInfo, Data, Name},
+    traits::{marshall_tss, unmarshall_tss, Marshall, UnMarshall},
+    tss2_esys::{
+        Tss2_MU_TPMS_ATTEST_Marshal, Tss2_MU_TPMS_ATTEST_Unmarshal, TPM2B_ATTEST, TPMS_ATTEST,
+    },
+    Error, Result, WrapperErrorKind,
+};
+use log::error;
+use std::convert::{TryFrom, TryInto};
+
+/// Type for holding attestation data
+///
+/// # Details
+/// Corresponds to `TPMS_ATTEST`.
+#[derive(Debug, Clone)]
+pub struct Attest {
+    qualified_signer: Name,
+    extra_data: Data,
+    clock_info: ClockInfo,
+    firmware_version: u64,
+    attested: AttestInfo,
+}
+
+impl Attest {
+    /// Returns the type of the attestation.
+    pub const fn attestation_type(&self) -> StructureTag {
+        self.attested.attestation_type()
+    }
+
+    /// Returns the qualified name of the signing object.
+    pub const fn qualified_signer(&self) -> &Name {
+        &self.qualified_signer
+    }
+
+    /// Returns the extra data specified by the caller.
+    pub const fn extra_data(&self) -> &Data {
+        &self.extra_data
+    }
+
+    /// Returns the internal TPM clock data.
+    pub const fn clock_info(&self) -> &ClockInfo {
+        &self.clock_info
+    }
+
+    /// Returns the TPM firmware version number.
+    pub const fn firmware_version(&self) -> u64 {
+        self.firmware_version
+    }
+
+    /// Returns the attested data.
+    pub const fn attested(&self) -> &AttestInfo {
+        &self.attested
+    }
+}
+
+impl TryFrom<Attest> for TPMS_ATTEST {
+    type Error = Error;
+
+    fn try_from(attest: Attest) -> Result<Self> {
+        let (attestation_type, attested) = attest.attested.into_tss_attested()?;
+        Ok(TPMS_ATTEST {
+            magic: TPM2_GENERATED_VALUE,
+            type_: attestation_type.into(),
+            qualifiedSigner: attest.qualified_signer.try_into()?,
+            extraData: attest.extra_data.into(),
+            clockInfo: attest.clock_info.into(),
+            firmwareVersion: attest.firmware_version,
+            attested,
+        })
+    }
+}
+
+impl TryFrom<TPMS_ATTEST> for Attest {
+    type Error = Error;
+
+    fn try_from(tpms_attest: TPMS_ATTEST) -> Result<Self> {
+        if tpms_attest.magic != TPM2_GENERATED_VALUE {
+            error!(
+                "Error: Attestation data was not generated by a TPM (magic: {:#x})",
+                tpms_attest.magic
+            );
+            return Err(Error::local_error(WrapperErrorKind::InvalidParam));
+        }
+
+        let attestation_type = StructureTag::try_from(tpms_attest.type_)?;
+        Ok(Attest {
+            qualified_signer: Name::try_from(tpms_attest.qualifiedSigner)?,
+            extra_data: Data::try_from(tpms_attest.extraData)?,
+            clock_info: ClockInfo::try_from(tpms_attest.clockInfo)?,
+            firmware_version: tpms_attest.firmwareVersion,
+            attested: AttestInfo::from_tss_attested(attestation_type, tpms_attest.attested)?,
+        })
+    }
+}
+
+impl TryFrom<TPM2B_ATTEST> for Attest {
+    type Error = Error;
+
+    fn try_from(tpm2b_attest: TPM2B_ATTEST) -> Result<Self> {
+        let size = tpm2b_attest.size as usize;
+        if size > tpm2b_attest.attestationData.len() {
+            error!(
+                "Error: Invalid TPM2B_ATTEST size(> {})",
+                tpm2b_attest.attestationData.len()
+            );
+            return Err(Error::local_error(WrapperErrorKind::WrongParamSize));
+        }
+
+        Attest::unmarshall(&tpm2b_attest.attestationData[..size])
+    }
+}
+
+impl Marshall for Attest {
+    fn marshall(&self) -> Result<Vec<u8>> {
+        marshall_tss(
+            &TPMS_ATTEST::try_from(self.clone())?,
+            Tss2_MU_TPMS_ATTEST_Marshal,
+        )
+    }
+}
+
+impl UnMarshall for Attest {
+    fn unmarshall(marshalled_data: &[u8]) -> Result<Self> {
+        Attest::try_from(unmarshall_tss(
+            marshalled_data,
+            Tss2_MU_TPMS_ATTEST_Unmarshal,
+        )?)
+    }
+}
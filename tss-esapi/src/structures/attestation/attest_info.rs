@@ -0,0 +1,101 @@
+// Copyright 2021 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use crate::{
+    constants::StructureTag,
+    structures::{
+        CertifyInfo, CommandAuditInfo, CreationInfo, NvCertifyInfo, QuoteInfo, SessionAuditInfo,
+        TimeAttestInfo,
+    },
+    tss2_esys::TPMU_ATTEST,
+    Error, Result,
+};
+use std::convert::TryInto;
+
+/// Enum representing the attested data
+///
+/// # Details
+/// This corresponds to TPMU_ATTEST, where the variant
+/// is selected by the type of the attestation.
+#[derive(Debug, Clone)]
+pub enum AttestInfo {
+    Certify { info: CertifyInfo },
+    Creation { info: CreationInfo },
+    CommandAudit { info: CommandAuditInfo },
+    SessionAudit { info: SessionAuditInfo },
+    Nv { info: NvCertifyInfo },
+    Quote { info: QuoteInfo },
+    Time { info: TimeAttestInfo },
+}
+
+impl AttestInfo {
+    /// Returns the attestation type of the attested data.
+    pub const fn attestation_type(&self) -> StructureTag {
+        match self {
+            AttestInfo::Certify { .. } => StructureTag::AttestCertify,
+            AttestInfo::Creation { .. } => StructureTag::AttestCreation,
+            AttestInfo::CommandAudit { .. } => StructureTag::AttestCommandAudit,
+            AttestInfo::SessionAudit { .. } => StructureTag::AttestSessionAudit,
+            AttestInfo::Nv { .. } => StructureTag::AttestNv,
+            AttestInfo::Quote { .. } => StructureTag::AttestQuote,
+            AttestInfo::Time { .. } => StructureTag::AttestTime,
+        }
+    }
+
+    /// Converts the attested data into the tss union together with its selector.
+    pub(crate) fn into_tss_attested(self) -> Result<(StructureTag, TPMU_ATTEST)> {
+        let attestation_type = self.attestation_type();
+        let attested = match self {
+            AttestInfo::Certify { info } => TPMU_ATTEST {
+                certify: info.try_into()?,
+            },
+            AttestInfo::Creation { info } => TPMU_ATTEST {
+                creation: info.try_into()?,
+            },
+            AttestInfo::CommandAudit { info } => TPMU_ATTEST {
+                commandAudit: info.into(),
+            },
+            AttestInfo::SessionAudit { info } => TPMU_ATTEST {
+                sessionAudit: info.into(),
+            },
+            AttestInfo::Nv { info } => TPMU_ATTEST {
+                nv: info.try_into()?,
+            },
+            AttestInfo::Quote { info } => TPMU_ATTEST { quote: info.into() },
+            AttestInfo::Time { info } => TPMU_ATTEST { time: info.into() },
+        };
+        Ok((attestation_type, attested))
+    }
+
+    /// Creates the attested data from the tss union and its selector.
+    pub(crate) fn from_tss_attested(
+        attestation_type: StructureTag,
+        tpmu_attest: TPMU_ATTEST,
+    ) -> Result<Self> {
+        match attestation_type {
+            StructureTag::AttestCertify => Ok(AttestInfo::Certify {
+                info: unsafe { tpmu_attest.certify }.try_into()?,
+            }),
+            StructureTag::AttestCreation => Ok(AttestInfo::Creation {
+                info: unsafe { tpmu_attest.creation }.try_into()?,
+            }),
+            StructureTag::AttestCommandAudit => Ok(AttestInfo::CommandAudit {
+                info: unsafe { tpmu_attest.commandAudit }.try_into()?,
+            }),
+            StructureTag::AttestSessionAudit => Ok(AttestInfo::SessionAudit {
+                info: unsafe { tpmu_attest.sessionAudit }.try_into()?,
+            }),
+            StructureTag::AttestNv => Ok(AttestInfo::Nv {
+                info: unsafe { tpmu_attest.nv }.try_into()?,
+            }),
+            StructureTag::AttestQuote => Ok(AttestInfo::Quote {
+                info: unsafe { tpmu_attest.quote }.try_into()?,
+            }),
+            StructureTag::AttestTime => Ok(AttestInfo::Time {
+                info: unsafe { tpmu_attest.time }.try_into()?,
+            }),
+            _ => Err(Error::local_error(
+                crate::WrapperErrorKind::UnsupportedParam,
+            )),
+        }
+    }
+}
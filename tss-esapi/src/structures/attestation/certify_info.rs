@@ -0,0 +1,49 @@
+// Copyright 2021 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use crate::{structures::Name, tss2_esys::TPMS_CERTIFY_INFO, Error, Result};
+use std::convert::{TryFrom, TryInto};
+
+/// Structure holding the attested data for
+/// TPM2_Certify().
+///
+/// # Details
+/// This corresponds to TPMS_CERTIFY_INFO
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CertifyInfo {
+    name: Name,
+    qualified_name: Name,
+}
+
+impl CertifyInfo {
+    /// Returns the name of the certified object.
+    pub const fn name(&self) -> &Name {
+        &self.name
+    }
+
+    /// Returns the qualified name of the certified object.
+    pub const fn qualified_name(&self) -> &Name {
+        &self.qualified_name
+    }
+}
+
+impl TryFrom<TPMS_CERTIFY_INFO> for CertifyInfo {
+    type Error = Error;
+
+    fn try_from(tpms_certify_info: TPMS_CERTIFY_INFO) -> Result<Self> {
+        Ok(CertifyInfo {
+            name: tpms_certify_info.name.try_into()?,
+            qualified_name: tpms_certify_info.qualifiedName.try_into()?,
+        })
+    }
+}
+
+impl TryFrom<CertifyInfo> for TPMS_CERTIFY_INFO {
+    type Error = Error;
+
+    fn try_from(certify_info: CertifyInfo) -> Result<Self> {
+        Ok(TPMS_CERTIFY_INFO {
+            name: certify_info.name.try_into()?,
+            qualifiedName: certify_info.qualified_name.try_into()?,
+        })
+    }
+}
@@ -0,0 +1,65 @@
+// Copyright 2021 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use crate::{
+    structures::Digest,
+    tss2_esys::{TPMI_YES_NO, TPMS_SESSION_AUDIT_INFO},
+    Error, Result, WrapperErrorKind,
+};
+use log::error;
+use std::convert::{TryFrom, TryInto};
+
+/// Structure holding the attested data for
+/// TPM2_GetSessionAuditDigest().
+///
+/// # Details
+/// This corresponds to TPMS_SESSION_AUDIT_INFO
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SessionAuditInfo {
+    exclusive_session: bool,
+    session_digest: Digest,
+}
+
+impl SessionAuditInfo {
+    /// Returns true if all the commands recorded in the session
+    /// digest were executed without any intervening commands that
+    /// did not use the audit session.
+    pub const fn exclusive_session(&self) -> bool {
+        self.exclusive_session
+    }
+
+    /// Returns the current value of the session audit digest.
+    pub const fn session_digest(&self) -> &Digest {
+        &self.session_digest
+    }
+}
+
+impl TryFrom<TPMS_SESSION_AUDIT_INFO> for SessionAuditInfo {
+    type Error = Error;
+
+    fn try_from(tpms_session_audit_info: TPMS_SESSION_AUDIT_INFO) -> Result<Self> {
+        let exclusive_session = match tpms_session_audit_info.exclusiveSession {
+            0 => false,
+            1 => true,
+            _ => {
+                error!(
+                    "Error: Invalid value for exclusive session in session audit info: {}",
+                    tpms_session_audit_info.exclusiveSession
+                );
+                return Err(Error::local_error(WrapperErrorKind::InvalidParam));
+            }
+        };
+        Ok(SessionAuditInfo {
+            exclusive_session,
+            session_digest: tpms_session_audit_info.sessionDigest.try_into()?,
+        })
+    }
+}
+
+impl From<SessionAuditInfo> for TPMS_SESSION_AUDIT_INFO {
+    fn from(session_audit_info: SessionAuditInfo) -> Self {
+        TPMS_SESSION_AUDIT_INFO {
+            exclusiveSession: TPMI_YES_NO::from(session_audit_info.exclusive_session),
+            sessionDigest: session_audit_info.session_digest.into(),
+        }
+    }
+}
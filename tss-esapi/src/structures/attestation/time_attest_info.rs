@@ -0,0 +1,69 @@
+// Copyright 2021 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use crate::{structures::TimeInfo, tss2_esys::TPMS_TIME_ATTEST_INFO, Error, Result};
+use std::convert::{TryFrom, TryInto};
+
+/// Structure holding the attested data for
+/// TPM2_GetTime().
+///
+/// # Details
+/// This corresponds to TPMS_TIME_ATTEST_INFO
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimeAttestInfo {
+    time_info: TimeInfo,
+    firmware_version: u64,
+}
+
+impl TimeAttestInfo {
+    /// Returns the time information.
+    pub const fn time_info(&self) -> &TimeInfo {
+        &self.time_info
+    }
+
+    /// Returns the time value in milliseconds since the last
+    /// TPM Reset or TPM Restart.
+    pub const fn time(&self) -> u64 {
+        self.time_info.time()
+    }
+
+    /// Returns the time value in milliseconds that advances while the TPM is powered.
+    pub const fn clock(&self) -> u64 {
+        self.time_info.clock_info().clock()
+    }
+
+    /// Returns the number of occurrences of TPM Reset since the last TPM2_Clear().
+    pub const fn reset_count(&self) -> u32 {
+        self.time_info.clock_info().reset_count()
+    }
+
+    /// Returns the number of times that TPM2_Shutdown() or _TPM_Hash_Start
+    /// have occurred since the last TPM Reset or TPM2_Clear().
+    pub const fn restart_count(&self) -> u32 {
+        self.time_info.clock_info().restart_count()
+    }
+
+    /// Returns the vendor specific firmware version.
+    pub const fn firmware_version(&self) -> u64 {
+        self.firmware_version
+    }
+}
+
+impl TryFrom<TPMS_TIME_ATTEST_INFO> for TimeAttestInfo {
+    type Error = Error;
+
+    fn try_from(tpms_time_attest_info: TPMS_TIME_ATTEST_INFO) -> Result<Self> {
+        Ok(TimeAttestInfo {
+            time_info: tpms_time_attest_info.time.try_into()?,
+            firmware_version: tpms_time_attest_info.firmwareVersion,
+        })
+    }
+}
+
+impl From<TimeAttestInfo> for TPMS_TIME_ATTEST_INFO {
+    fn from(time_attest_info: TimeAttestInfo) -> Self {
+        TPMS_TIME_ATTEST_INFO {
+            time: time_attest_info.time_info.into(),
+            firmwareVersion: time_attest_info.firmware_version,
+        }
+    }
+}
@@ -25,6 +25,30 @@ macro_rules! named_field_buffer_type {
             pub fn value(&self) -> &[u8] {
                 &self.0
             }
+
+            /// Creates the buffer from a byte slice.
+            ///
+            /// # Errors
+            /// * if the slice is larger than `MAX_SIZE`, a `WrongParamSize`
+            ///   wrapper error is returned.
+            pub fn try_from_bytes(bytes: &[u8]) -> Result<Self> {
+                $native_type::try_from(bytes)
+            }
+
+            /// Returns the content of the buffer.
+            pub fn as_bytes(&self) -> &[u8] {
+                &self.0
+            }
+
+            /// Returns the number of bytes in the buffer.
+            pub fn len(&self) -> usize {
+                self.0.len()
+            }
+
+            /// Returns true if the buffer is empty.
+            pub fn is_empty(&self) -> bool {
+                self.0.is_empty()
+            }
         }
 
         impl Deref for $native_type {
@@ -91,6 +115,37 @@ macro_rules! buffer_type {
     };
 }
 
+#[allow(unused_macros)]
+macro_rules! serde_buffer_type {
+    ($native_type:ident) => {
+        #[cfg(feature = "serde")]
+        impl serde::Serialize for $native_type {
+            /// Serializes the buffer as a sequence of bytes
+            fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+            where
+                S: serde::Serializer,
+            {
+                serde::Serialize::serialize(self.value(), serializer)
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<'de> serde::Deserialize<'de> for $native_type {
+            /// Deserializes the buffer from a sequence of bytes
+            ///
+            /// # Errors
+            /// * if the sequence is larger than `MAX_SIZE` an error is returned.
+            fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                let bytes: Vec<u8> = serde::Deserialize::deserialize(deserializer)?;
+                $native_type::try_from(bytes).map_err(serde::de::Error::custom)
+            }
+        }
+    };
+}
+
 pub mod public;
 
 pub mod auth {
@@ -106,8 +161,32 @@ pub mod sensitive_data {
 }
 
 pub mod private {
+    use crate::{
+        traits::{marshall_tss, unmarshall_tss, Marshall, UnMarshall},
+        tss2_esys::{Tss2_MU_TPM2B_PRIVATE_Marshal, Tss2_MU_TPM2B_PRIVATE_Unmarshal},
+    };
     use tss_esapi_sys::_PRIVATE;
     buffer_type!(Private, ::std::mem::size_of::<_PRIVATE>(), TPM2B_PRIVATE);
+
+    impl Marshall for Private {
+        /// Produces the marshalled `TPM2B_PRIVATE`
+        fn marshall(&self) -> Result<Vec<u8>> {
+            marshall_tss(
+                &TPM2B_PRIVATE::from(self.clone()),
+                Tss2_MU_TPM2B_PRIVATE_Marshal,
+            )
+        }
+    }
+
+    impl UnMarshall for Private {
+        /// Creates the type from a marshalled `TPM2B_PRIVATE`
+        fn unmarshall(marshalled_data: &[u8]) -> Result<Self> {
+            Private::try_from(unmarshall_tss::<TPM2B_PRIVATE>(
+                marshalled_data,
+                Tss2_MU_TPM2B_PRIVATE_Unmarshal,
+            )?)
+        }
+    }
 }
 
 pub mod encrypted_secret {
@@ -120,6 +199,7 @@ pub mod id_object {
 
 pub mod digest {
     buffer_type!(Digest, 64, TPM2B_DIGEST);
+    serde_buffer_type!(Digest);
 
     // Some implementations to get from Digest to [u8; N] for common values of N (sha* primarily)
     // This is used to work around the fact that Rust does not allow custom functions for general values of N in [T; N],
@@ -208,6 +288,7 @@ pub mod public_key_rsa {
         TPM2_MAX_RSA_KEY_BYTES as usize,
         TPM2B_PUBLIC_KEY_RSA
     );
+    serde_buffer_type!(PublicKeyRsa);
 
     impl PublicKeyRsa {
         pub fn new_empty_with_size(rsa_key_bits: RsaKeyBits) -> Self {
@@ -295,4 +376,21 @@ pub mod ecc_parameter {
         crate::tss2_esys::TPM2_MAX_ECC_KEY_BYTES as usize,
         TPM2B_ECC_PARAMETER
     );
+    serde_buffer_type!(EccParameter);
+}
+
+pub mod private_key_rsa {
+    buffer_type!(
+        PrivateKeyRsa,
+        (crate::tss2_esys::TPM2_MAX_RSA_KEY_BYTES / 2) as usize,
+        TPM2B_PRIVATE_KEY_RSA
+    );
+}
+
+pub mod symmetric_key {
+    buffer_type!(
+        SymmetricKey,
+        crate::tss2_esys::TPM2_MAX_SYM_KEY_BYTES as usize,
+        TPM2B_SYM_KEY
+    );
 }
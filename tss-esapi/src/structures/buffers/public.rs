@@ -3,12 +3,17 @@
 pub mod ecc;
 pub mod keyed_hash;
 pub mod rsa;
+#[cfg(feature = "spki")]
+mod spki;
 
 use crate::{
-    attributes::ObjectAttributes,
+    attributes::{ObjectAttributes, ObjectAttributesBuilder},
     interface_types::algorithm::{HashingAlgorithm, PublicAlgorithm},
-    structures::{Digest, EccPoint, PublicKeyRsa, SymmetricCipherParameters},
-    tss2_esys::{TPM2B_PUBLIC, TPMT_PUBLIC},
+    structures::{Digest, EccPoint, KeyedHashScheme, PublicKeyRsa, SymmetricCipherParameters},
+    traits::{marshall_tss, unmarshall_tss, Marshall, UnMarshall},
+    tss2_esys::{
+        Tss2_MU_TPM2B_PUBLIC_Marshal, Tss2_MU_TPM2B_PUBLIC_Unmarshal, TPM2B_PUBLIC, TPMT_PUBLIC,
+    },
     Error, Result, WrapperErrorKind,
 };
 
@@ -285,13 +290,55 @@ impl PublicBuilder {
             }
         }
     }
+
+    /// Builds the [Public] structure of a sealed data object.
+    ///
+    /// # Arguments
+    /// * `auth_policy` - The optional policy that has to be satisfied in order to use the object.
+    /// * `name_hashing_algorithm` - The name hashing algorithm of the object.
+    ///
+    /// # Details
+    /// The object is a `KeyedHash` object with a `Null` scheme, that can neither sign
+    /// nor decrypt. It cannot be duplicated, and is not subject to the dictionary
+    /// attack protections. If a policy is provided, unsealing the data requires a
+    /// policy session satisfying it, otherwise the authorization value of the object
+    /// is used.
+    ///
+    /// The sealed data is provided as the sensitive data when creating the object.
+    pub fn build_sealing_object(
+        auth_policy: Option<Digest>,
+        name_hashing_algorithm: HashingAlgorithm,
+    ) -> Result<Public> {
+        let object_attributes = ObjectAttributesBuilder::new()
+            .with_fixed_tpm(true)
+            .with_fixed_parent(true)
+            .with_no_da(true)
+            .with_user_with_auth(auth_policy.is_none())
+            .with_admin_with_policy(auth_policy.is_some())
+            .with_sign_encrypt(false)
+            .with_decrypt(false)
+            .build()?;
+
+        PublicBuilder::new()
+            .with_public_algorithm(PublicAlgorithm::KeyedHash)
+            .with_name_hashing_algorithm(name_hashing_algorithm)
+            .with_object_attributes(object_attributes)
+            .with_auth_policy(&auth_policy.unwrap_or_default())
+            .with_keyed_hash_parameters(PublicKeyedHashParameters::new(KeyedHashScheme::Null))
+            .with_keyed_hash_unique_identifier(&Digest::default())
+            .build()
+    }
 }
 
 /// Enum representing the Public structure.
 ///
 /// # Details
 /// This corresponds to TPM2B_PUBLIC
+///
+/// The structure is serialized with `serde` field by field,
+/// including the nested parameters and schemes.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Public {
     Rsa {
         object_attributes: ObjectAttributes,
@@ -499,3 +546,23 @@ impl TryFrom<TPM2B_PUBLIC> for Public {
         }
     }
 }
+
+impl Marshall for Public {
+    /// Produces the marshalled `TPM2B_PUBLIC`
+    fn marshall(&self) -> Result<Vec<u8>> {
+        marshall_tss(
+            &TPM2B_PUBLIC::from(self.clone()),
+            Tss2_MU_TPM2B_PUBLIC_Marshal,
+        )
+    }
+}
+
+impl UnMarshall for Public {
+    /// Creates the type from a marshalled `TPM2B_PUBLIC`
+    fn unmarshall(marshalled_data: &[u8]) -> Result<Self> {
+        Public::try_from(unmarshall_tss(
+            marshalled_data,
+            Tss2_MU_TPM2B_PUBLIC_Unmarshal,
+        )?)
+    }
+}
@@ -207,6 +207,7 @@ impl PublicEccParametersBuilder {
 /// # Details
 /// This corresponds to TPMS_ECC_PARMS.
 #[derive(Clone, Debug, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PublicEccParameters {
     symmetric_definition_object: SymmetricDefinitionObject,
     ecc_scheme: EccScheme,
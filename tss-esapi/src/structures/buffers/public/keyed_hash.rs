@@ -10,6 +10,7 @@ use std::convert::{TryFrom, TryInto};
 ///
 /// These keyed hash parameters are specific to the [`crate::structures::Public`] type.
 #[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PublicKeyedHashParameters {
     keyed_hash_scheme: KeyedHashScheme,
 }
@@ -215,7 +215,15 @@ impl PublicRsaParametersBuilder {
 }
 
 /// Strucure used to hold the value of a RSA exponent
+///
+/// The exponent is serialized with `serde` as its value, which
+/// is validated when deserializing.
 #[derive(Clone, Debug, Copy, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "u32", into = "u32")
+)]
 pub struct RsaExponent {
     value: u32,
 }
@@ -291,6 +299,7 @@ impl TryFrom<UINT32> for RsaExponent {
 ///
 /// These rsa parameters are specific to the [`crate::structures::Public`] type.
 #[derive(Clone, Debug, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PublicRsaParameters {
     symmetric_definition_object: SymmetricDefinitionObject,
     rsa_scheme: RsaScheme,
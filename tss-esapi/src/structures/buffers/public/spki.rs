@@ -0,0 +1,253 @@
+// Copyright 2021 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use super::{
+    ecc::PublicEccParametersBuilder,
+    rsa::{PublicRsaParametersBuilder, RsaExponent},
+    Public, PublicBuilder,
+};
+use crate::{
+    attributes::ObjectAttributesBuilder,
+    interface_types::{
+        algorithm::{HashingAlgorithm, PublicAlgorithm},
+        ecc::EccCurve,
+        key_bits::RsaKeyBits,
+    },
+    structures::{EccPoint, EccScheme, KeyDerivationFunctionScheme, PublicKeyRsa, RsaScheme},
+    Error, Result, WrapperErrorKind,
+};
+use log::error;
+use picky_asn1::{
+    bit_string::BitString,
+    wrapper::{IntegerAsn1, ObjectIdentifierAsn1},
+};
+use picky_asn1_x509::{
+    oids, AlgorithmIdentifier, AlgorithmIdentifierParameters, EcParameters, PublicKey,
+    RsaPublicKey, SubjectPublicKeyInfo,
+};
+use std::convert::{TryFrom, TryInto};
+
+/// The uncompressed point format identifier of SEC 1.
+const EC_POINT_UNCOMPRESSED: u8 = 0x04;
+
+impl Public {
+    /// Converts the public key into a DER-encoded `SubjectPublicKeyInfo`.
+    ///
+    /// # Details
+    /// RSA keys are encoded as `rsaEncryption` keys holding the modulus
+    /// and the public exponent. ECC keys are encoded as `id-ecPublicKey`
+    /// keys on a named curve holding the uncompressed public point.
+    ///
+    /// # Errors
+    /// * if the public key is not an RSA or ECC key, or if the ECC curve
+    ///   has no named curve identifier, an `UnsupportedParam` wrapper error
+    ///   is returned.
+    pub fn to_subject_public_key_info_der(&self) -> Result<Vec<u8>> {
+        let subject_public_key_info = match self {
+            Public::Rsa {
+                parameters, unique, ..
+            } => {
+                // An exponent of zero is the shorthand for the default exponent.
+                let exponent = match parameters.exponent().value() {
+                    0 => RsaExponent::default().value(),
+                    value => value,
+                };
+                SubjectPublicKeyInfo::new_rsa_key(
+                    IntegerAsn1::from_bytes_be_unsigned(unique.value().to_vec()),
+                    // DER integers must be minimally encoded.
+                    IntegerAsn1::from_bytes_be_unsigned(
+                        exponent
+                            .to_be_bytes()
+                            .iter()
+                            .skip_while(|byte| **byte == 0)
+                            .copied()
+                            .collect(),
+                    ),
+                )
+            }
+            Public::Ecc {
+                parameters, unique, ..
+            } => {
+                let curve = parameters.ecc_curve();
+                let coordinate_size = ecc_coordinate_size(curve);
+                let mut point = vec![EC_POINT_UNCOMPRESSED];
+                point.extend(pad_coordinate(unique.x().value(), coordinate_size)?);
+                point.extend(pad_coordinate(unique.y().value(), coordinate_size)?);
+                SubjectPublicKeyInfo {
+                    algorithm: AlgorithmIdentifier::new_elliptic_curve(EcParameters::NamedCurve(
+                        ecc_curve_oid(curve)?,
+                    )),
+                    subject_public_key: PublicKey::Ec(BitString::with_bytes(point).into()),
+                }
+            }
+            _ => {
+                error!("Only RSA and ECC public keys can be converted into a SubjectPublicKeyInfo");
+                return Err(Error::local_error(WrapperErrorKind::UnsupportedParam));
+            }
+        };
+        picky_asn1_der::to_vec(&subject_public_key_info).map_err(|e| {
+            error!("Error when encoding the SubjectPublicKeyInfo: {}", e);
+            Error::local_error(WrapperErrorKind::InvalidParam)
+        })
+    }
+
+    /// Creates a public key from a DER-encoded `SubjectPublicKeyInfo`.
+    ///
+    /// # Details
+    /// The resulting structure describes an unrestricted signing key
+    /// without a scheme, using SHA-256 as name hashing algorithm. It is
+    /// suitable for loading the public key into the TPM in order to
+    /// verify signatures.
+    ///
+    /// # Errors
+    /// * if the data is not a valid DER-encoded `SubjectPublicKeyInfo`, an
+    ///   `InvalidParam` wrapper error is returned.
+    /// * if the key is not an RSA key or an ECC key on a curve supported
+    ///   by the TPM, an `UnsupportedParam` wrapper error is returned.
+    pub fn from_subject_public_key_info_der(der: &[u8]) -> Result<Self> {
+        let subject_public_key_info: SubjectPublicKeyInfo = picky_asn1_der::from_bytes(der)
+            .map_err(|e| {
+                error!("Error when decoding the SubjectPublicKeyInfo: {}", e);
+                Error::local_error(WrapperErrorKind::InvalidParam)
+            })?;
+        let object_attributes = ObjectAttributesBuilder::new()
+            .with_user_with_auth(true)
+            .with_decrypt(false)
+            .with_sign_encrypt(true)
+            .with_restricted(false)
+            .build()?;
+
+        match subject_public_key_info.subject_public_key {
+            PublicKey::Rsa(encapsulated_rsa_public_key) => {
+                let RsaPublicKey {
+                    modulus,
+                    public_exponent,
+                } = encapsulated_rsa_public_key.0;
+                let modulus = modulus.as_unsigned_bytes_be();
+                let key_bits = match modulus.len() {
+                    128 => RsaKeyBits::Rsa1024,
+                    256 => RsaKeyBits::Rsa2048,
+                    384 => RsaKeyBits::Rsa3072,
+                    512 => RsaKeyBits::Rsa4096,
+                    _ => {
+                        error!("Unsupported RSA modulus size: {} bytes", modulus.len());
+                        return Err(Error::local_error(WrapperErrorKind::UnsupportedParam));
+                    }
+                };
+                let public_exponent = public_exponent.as_unsigned_bytes_be();
+                if public_exponent.len() > 4 {
+                    error!("Unsupported RSA public exponent size");
+                    return Err(Error::local_error(WrapperErrorKind::UnsupportedParam));
+                }
+                let exponent = RsaExponent::create(
+                    public_exponent
+                        .iter()
+                        .fold(0u32, |value, byte| (value << 8) | u32::from(*byte)),
+                )?;
+                PublicBuilder::new()
+                    .with_public_algorithm(PublicAlgorithm::Rsa)
+                    .with_name_hashing_algorithm(HashingAlgorithm::Sha256)
+                    .with_object_attributes(object_attributes)
+                    .with_rsa_parameters(
+                        PublicRsaParametersBuilder::new()
+                            .with_scheme(RsaScheme::Null)
+                            .with_key_bits(key_bits)
+                            .with_exponent(exponent)
+                            .build()?,
+                    )
+                    .with_rsa_unique_identifier(&PublicKeyRsa::try_from(modulus)?)
+                    .build()
+            }
+            PublicKey::Ec(encapsulated_ec_point) => {
+                let curve = match subject_public_key_info.algorithm.parameters() {
+                    AlgorithmIdentifierParameters::Ec(EcParameters::NamedCurve(oid)) => {
+                        ecc_curve_from_oid(oid)?
+                    }
+                    _ => {
+                        error!("The ECC public key is not on a named curve");
+                        return Err(Error::local_error(WrapperErrorKind::UnsupportedParam));
+                    }
+                };
+                let coordinate_size = ecc_coordinate_size(curve);
+                let point = encapsulated_ec_point.0.payload_view();
+                if point.len() != 1 + 2 * coordinate_size || point[0] != EC_POINT_UNCOMPRESSED {
+                    error!("The ECC public key is not an uncompressed point on the curve");
+                    return Err(Error::local_error(WrapperErrorKind::UnsupportedParam));
+                }
+                let (x, y) = point[1..].split_at(coordinate_size);
+                PublicBuilder::new()
+                    .with_public_algorithm(PublicAlgorithm::Ecc)
+                    .with_name_hashing_algorithm(HashingAlgorithm::Sha256)
+                    .with_object_attributes(object_attributes)
+                    .with_ecc_parameters(
+                        PublicEccParametersBuilder::new()
+                            .with_ecc_scheme(EccScheme::Null)
+                            .with_curve(curve)
+                            .with_key_derivation_function_scheme(KeyDerivationFunctionScheme::Null)
+                            .build()?,
+                    )
+                    .with_ecc_unique_identifier(&EccPoint::new(x.try_into()?, y.try_into()?))
+                    .build()
+            }
+            PublicKey::Ed(_) => {
+                error!("Edwards curve public keys are not supported");
+                Err(Error::local_error(WrapperErrorKind::UnsupportedParam))
+            }
+        }
+    }
+}
+
+/// Returns the size in bytes of the coordinates of the points on the curve.
+fn ecc_coordinate_size(curve: EccCurve) -> usize {
+    match curve {
+        EccCurve::NistP192 => 24,
+        EccCurve::NistP224 => 28,
+        EccCurve::NistP256 | EccCurve::BnP256 | EccCurve::Sm2P256 => 32,
+        EccCurve::NistP384 => 48,
+        EccCurve::NistP521 => 66,
+        EccCurve::BnP638 => 80,
+    }
+}
+
+/// Left pads the coordinate with zeros up to the coordinate size of the curve.
+fn pad_coordinate(coordinate: &[u8], coordinate_size: usize) -> Result<Vec<u8>> {
+    if coordinate.len() > coordinate_size {
+        error!("The ECC point coordinate is too large for the curve");
+        return Err(Error::local_error(WrapperErrorKind::InvalidParam));
+    }
+    let mut padded_coordinate = vec![0; coordinate_size - coordinate.len()];
+    padded_coordinate.extend_from_slice(coordinate);
+    Ok(padded_coordinate)
+}
+
+/// Returns the named curve identifier of the curve.
+fn ecc_curve_oid(curve: EccCurve) -> Result<ObjectIdentifierAsn1> {
+    match curve {
+        EccCurve::NistP192 => Ok(oids::secp192r1().into()),
+        EccCurve::NistP224 => Ok(oids::secp224r1().into()),
+        EccCurve::NistP256 => Ok(oids::secp256r1().into()),
+        EccCurve::NistP384 => Ok(oids::secp384r1().into()),
+        EccCurve::NistP521 => Ok(oids::secp521r1().into()),
+        _ => {
+            error!("The ECC curve has no supported named curve identifier");
+            Err(Error::local_error(WrapperErrorKind::UnsupportedParam))
+        }
+    }
+}
+
+/// Returns the curve identified by the named curve identifier.
+fn ecc_curve_from_oid(oid: &ObjectIdentifierAsn1) -> Result<EccCurve> {
+    [
+        EccCurve::NistP192,
+        EccCurve::NistP224,
+        EccCurve::NistP256,
+        EccCurve::NistP384,
+        EccCurve::NistP521,
+    ]
+    .iter()
+    .copied()
+    .find(|curve| ecc_curve_oid(*curve).ok().as_ref() == Some(oid))
+    .ok_or_else(|| {
+        error!("The named curve of the ECC public key is not supported");
+        Error::local_error(WrapperErrorKind::UnsupportedParam)
+    })
+}
@@ -10,6 +10,7 @@ use std::convert::{TryFrom, TryInto};
 /// # Details
 /// This corresponds to TPMS_ECC_POINT
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct EccPoint {
     x: EccParameter,
     y: EccParameter,
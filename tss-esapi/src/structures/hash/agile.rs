@@ -16,6 +16,16 @@ impl HashAgile {
     pub fn new(algorithm: HashingAlgorithm, digest: Digest) -> Self {
         HashAgile { algorithm, digest }
     }
+
+    /// Returns the hashing algorithm used to produce the digest.
+    pub fn hashing_algorithm(&self) -> HashingAlgorithm {
+        self.algorithm
+    }
+
+    /// Returns the digest.
+    pub fn digest(&self) -> &Digest {
+        &self.digest
+    }
 }
 
 impl TryFrom<HashAgile> for TPMT_HA {
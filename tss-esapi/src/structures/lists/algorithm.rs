@@ -0,0 +1,124 @@
+// Copyright 2021 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use crate::{
+    constants::AlgorithmIdentifier,
+    tss2_esys::{TPM2_MAX_ALG_LIST_SIZE, TPML_ALG},
+    Error, Result, WrapperErrorKind,
+};
+use log::error;
+use std::convert::TryFrom;
+
+/// A list of algorithm identifiers.
+///
+/// # Details
+/// This corresponds to TPML_ALG
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AlgorithmList {
+    algorithms: Vec<AlgorithmIdentifier>,
+}
+
+impl AlgorithmList {
+    pub const MAX_SIZE: usize = TPM2_MAX_ALG_LIST_SIZE as usize;
+
+    /// Creates a new empty AlgorithmList
+    pub fn new() -> Self {
+        AlgorithmList {
+            algorithms: Vec::new(),
+        }
+    }
+
+    /// Returns the algorithms in the list.
+    pub fn value(&self) -> &[AlgorithmIdentifier] {
+        &self.algorithms
+    }
+
+    /// Adds an algorithm to the list.
+    ///
+    /// # Errors
+    /// * if the list is already full, a `WrongParamSize` wrapper error will be returned.
+    pub fn add(&mut self, algorithm: AlgorithmIdentifier) -> Result<()> {
+        if self.algorithms.len() >= AlgorithmList::MAX_SIZE {
+            error!(
+                "Error: Exceeded maximum count(> {})",
+                AlgorithmList::MAX_SIZE
+            );
+            return Err(Error::local_error(WrapperErrorKind::WrongParamSize));
+        }
+        self.algorithms.push(algorithm);
+        Ok(())
+    }
+
+    /// Returns the number of algorithms in the list.
+    pub fn len(&self) -> usize {
+        self.algorithms.len()
+    }
+
+    /// Returns true if the list contains no algorithms.
+    pub fn is_empty(&self) -> bool {
+        self.algorithms.is_empty()
+    }
+}
+
+impl TryFrom<TPML_ALG> for AlgorithmList {
+    type Error = Error;
+    fn try_from(tpml_alg: TPML_ALG) -> Result<Self> {
+        let algorithms_count = tpml_alg.count as usize;
+        if algorithms_count > AlgorithmList::MAX_SIZE {
+            error!(
+                "Error: Invalid TPML_ALG count(> {})",
+                AlgorithmList::MAX_SIZE
+            );
+            return Err(Error::local_error(WrapperErrorKind::InvalidParam));
+        }
+        let algorithms = tpml_alg.algorithms[..algorithms_count]
+            .iter()
+            .map(|&alg| AlgorithmIdentifier::try_from(alg))
+            .collect::<Result<Vec<AlgorithmIdentifier>>>()?;
+        Ok(AlgorithmList { algorithms })
+    }
+}
+
+impl From<AlgorithmList> for TPML_ALG {
+    fn from(algorithm_list: AlgorithmList) -> Self {
+        let mut tpml_alg: TPML_ALG = Default::default();
+        for algorithm in algorithm_list.algorithms {
+            tpml_alg.algorithms[tpml_alg.count as usize] = algorithm.into();
+            tpml_alg.count += 1;
+        }
+        tpml_alg
+    }
+}
+
+/// A builder for [AlgorithmList].
+#[derive(Debug, Clone, Default)]
+pub struct AlgorithmListBuilder {
+    algorithms: Vec<AlgorithmIdentifier>,
+}
+
+impl AlgorithmListBuilder {
+    /// Creates a new builder with no algorithms.
+    pub fn new() -> Self {
+        AlgorithmListBuilder {
+            algorithms: Vec::new(),
+        }
+    }
+
+    /// Adds an algorithm to the list that is being built.
+    pub fn with_algorithm(mut self, algorithm: AlgorithmIdentifier) -> Self {
+        self.algorithms.push(algorithm);
+        self
+    }
+
+    /// Builds the algorithm list.
+    ///
+    /// # Errors
+    /// * if more than [AlgorithmList::MAX_SIZE] algorithms have been
+    ///   added, a `WrongParamSize` wrapper error will be returned.
+    pub fn build(self) -> Result<AlgorithmList> {
+        let mut algorithm_list = AlgorithmList::new();
+        for algorithm in self.algorithms {
+            algorithm_list.add(algorithm)?;
+        }
+        Ok(algorithm_list)
+    }
+}
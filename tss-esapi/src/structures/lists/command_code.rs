@@ -0,0 +1,124 @@
+// Copyright 2021 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use crate::{
+    constants::CommandCode,
+    tss2_esys::{TPM2_MAX_CAP_CC, TPML_CC},
+    Error, Result, WrapperErrorKind,
+};
+use log::error;
+use std::convert::TryFrom;
+
+/// A list of command codes.
+///
+/// # Details
+/// This corresponds to TPML_CC
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CommandCodeList {
+    command_codes: Vec<CommandCode>,
+}
+
+impl CommandCodeList {
+    pub const MAX_SIZE: usize = TPM2_MAX_CAP_CC as usize;
+
+    /// Creates a new empty CommandCodeList
+    pub fn new() -> Self {
+        CommandCodeList {
+            command_codes: Vec::new(),
+        }
+    }
+
+    /// Returns the command codes in the list.
+    pub fn value(&self) -> &[CommandCode] {
+        &self.command_codes
+    }
+
+    /// Adds a command code to the list.
+    ///
+    /// # Errors
+    /// * if the list is already full, a `WrongParamSize` wrapper error will be returned.
+    pub fn add(&mut self, command_code: CommandCode) -> Result<()> {
+        if self.command_codes.len() >= CommandCodeList::MAX_SIZE {
+            error!(
+                "Error: Exceeded maximum count(> {})",
+                CommandCodeList::MAX_SIZE
+            );
+            return Err(Error::local_error(WrapperErrorKind::WrongParamSize));
+        }
+        self.command_codes.push(command_code);
+        Ok(())
+    }
+
+    /// Returns the number of command codes in the list.
+    pub fn len(&self) -> usize {
+        self.command_codes.len()
+    }
+
+    /// Returns true if the list contains no command codes.
+    pub fn is_empty(&self) -> bool {
+        self.command_codes.is_empty()
+    }
+}
+
+impl TryFrom<TPML_CC> for CommandCodeList {
+    type Error = Error;
+    fn try_from(tpml_cc: TPML_CC) -> Result<Self> {
+        let command_codes_count = tpml_cc.count as usize;
+        if command_codes_count > CommandCodeList::MAX_SIZE {
+            error!(
+                "Error: Invalid TPML_CC count(> {})",
+                CommandCodeList::MAX_SIZE
+            );
+            return Err(Error::local_error(WrapperErrorKind::InvalidParam));
+        }
+        let command_codes = tpml_cc.commandCodes[..command_codes_count]
+            .iter()
+            .map(|&cc| CommandCode::try_from(cc))
+            .collect::<Result<Vec<CommandCode>>>()?;
+        Ok(CommandCodeList { command_codes })
+    }
+}
+
+impl From<CommandCodeList> for TPML_CC {
+    fn from(command_code_list: CommandCodeList) -> Self {
+        let mut tpml_cc: TPML_CC = Default::default();
+        for command_code in command_code_list.command_codes {
+            tpml_cc.commandCodes[tpml_cc.count as usize] = command_code.into();
+            tpml_cc.count += 1;
+        }
+        tpml_cc
+    }
+}
+
+/// A builder for [CommandCodeList].
+#[derive(Debug, Clone, Default)]
+pub struct CommandCodeListBuilder {
+    command_codes: Vec<CommandCode>,
+}
+
+impl CommandCodeListBuilder {
+    /// Creates a new builder with no command codes.
+    pub fn new() -> Self {
+        CommandCodeListBuilder {
+            command_codes: Vec::new(),
+        }
+    }
+
+    /// Adds a command code to the list that is being built.
+    pub fn with_command_code(mut self, command_code: CommandCode) -> Self {
+        self.command_codes.push(command_code);
+        self
+    }
+
+    /// Builds the command code list.
+    ///
+    /// # Errors
+    /// * if more than [CommandCodeList::MAX_SIZE] command codes have been
+    ///   added, a `WrongParamSize` wrapper error will be returned.
+    pub fn build(self) -> Result<CommandCodeList> {
+        let mut command_code_list = CommandCodeList::new();
+        for command_code in self.command_codes {
+            command_code_list.add(command_code)?;
+        }
+        Ok(command_code_list)
+    }
+}
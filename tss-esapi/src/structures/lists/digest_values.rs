@@ -4,7 +4,8 @@ use crate::interface_types::algorithm::HashingAlgorithm;
 use crate::structures::Digest;
 use crate::structures::HashAgile;
 use crate::tss2_esys::TPML_DIGEST_VALUES;
-use crate::{Error, Result};
+use crate::{Error, Result, WrapperErrorKind};
+use log::error;
 use std::collections::HashMap;
 use std::convert::{TryFrom, TryInto};
 
@@ -23,6 +24,21 @@ impl DigestValues {
     pub fn set(&mut self, alg: HashingAlgorithm, dig: Digest) {
         let _ = self.digests.insert(alg, dig);
     }
+
+    /// Returns the digest for the hashing algorithm, if there is one.
+    pub fn get(&self, alg: HashingAlgorithm) -> Option<&Digest> {
+        self.digests.get(&alg)
+    }
+
+    /// Returns the number of digests.
+    pub fn len(&self) -> usize {
+        self.digests.len()
+    }
+
+    /// Returns true if there are no digests.
+    pub fn is_empty(&self) -> bool {
+        self.digests.is_empty()
+    }
 }
 
 impl TryFrom<DigestValues> for TPML_DIGEST_VALUES {
@@ -38,3 +54,23 @@ impl TryFrom<DigestValues> for TPML_DIGEST_VALUES {
         Ok(tss_digest_values)
     }
 }
+
+impl TryFrom<TPML_DIGEST_VALUES> for DigestValues {
+    type Error = Error;
+    fn try_from(tpml_digest_values: TPML_DIGEST_VALUES) -> Result<Self> {
+        let count = tpml_digest_values.count as usize;
+        if count > tpml_digest_values.digests.len() {
+            error!(
+                "Error: Invalid TPML_DIGEST_VALUES count(> {})",
+                tpml_digest_values.digests.len()
+            );
+            return Err(Error::local_error(WrapperErrorKind::InvalidParam));
+        }
+        let mut digest_values = DigestValues::new();
+        for tpmt_ha in tpml_digest_values.digests[..count].iter() {
+            let ha = HashAgile::try_from(*tpmt_ha)?;
+            digest_values.set(ha.hashing_algorithm(), ha.digest().clone());
+        }
+        Ok(digest_values)
+    }
+}
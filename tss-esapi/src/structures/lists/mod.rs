@@ -1,5 +1,7 @@
 // Copyright 2020 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
+pub mod algorithm;
+pub mod command_code;
 pub mod digest;
 pub mod digest_values;
 pub mod pcr_selection;
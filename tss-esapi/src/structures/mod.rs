@@ -20,6 +20,16 @@
 mod capabilitydata;
 pub use self::capabilitydata::CapabilityData;
 /////////////////////////////////////////////////////////
+/// The attached component section
+/////////////////////////////////////////////////////////
+mod ac_output;
+pub use self::ac_output::AcOutput;
+/////////////////////////////////////////////////////////
+/// The property section
+/////////////////////////////////////////////////////////
+mod property;
+pub use self::property::algorithm_property::AlgorithmProperty;
+/////////////////////////////////////////////////////////
 /// The names section
 /////////////////////////////////////////////////////////
 mod names;
@@ -46,6 +56,7 @@ pub use self::buffers::{
     max_nv_buffer::MaxNvBuffer,
     nonce::Nonce,
     private::Private,
+    private_key_rsa::PrivateKeyRsa,
     public::{
         ecc::{PublicEccParameters, PublicEccParametersBuilder},
         keyed_hash::PublicKeyedHashParameters,
@@ -54,6 +65,7 @@ pub use self::buffers::{
     },
     public_key_rsa::PublicKeyRsa,
     sensitive_data::SensitiveData,
+    symmetric_key::SymmetricKey,
     timeout::Timeout,
 };
 /////////////////////////////////////////////////////////
@@ -62,6 +74,16 @@ pub use self::buffers::{
 mod creation;
 pub use self::creation::CreationData;
 /////////////////////////////////////////////////////////
+/// The sensitive section
+/////////////////////////////////////////////////////////
+mod sensitive;
+pub use self::sensitive::Sensitive;
+/////////////////////////////////////////////////////////
+/// The sensitive create section
+/////////////////////////////////////////////////////////
+mod sensitive_create;
+pub use self::sensitive_create::SensitiveCreate;
+/////////////////////////////////////////////////////////
 /// The hash section
 /////////////////////////////////////////////////////////
 mod hash;
@@ -85,6 +107,16 @@ pub mod pcr_selection {
 /// The lists section
 /////////////////////////////////////////////////////////
 mod lists;
+pub use self::algorithm_list::{AlgorithmList, AlgorithmListBuilder};
+pub mod algorithm_list {
+    pub use super::lists::algorithm::*;
+}
+
+pub use self::command_code_list::{CommandCodeList, CommandCodeListBuilder};
+pub mod command_code_list {
+    pub use super::lists::command_code::*;
+}
+
 pub use self::digest_list::DigestList;
 pub mod digest_list {
     pub use super::lists::digest::*;
@@ -123,6 +155,7 @@ pub use tagged::{
     parameters::PublicParameters,
     schemes::{
         EccScheme, KeyDerivationFunctionScheme, KeyedHashScheme, RsaDecryptionScheme, RsaScheme,
+        SignatureScheme,
     },
     signature::Signature,
     symmetric::{SymmetricDefinition, SymmetricDefinitionObject},
@@ -131,8 +164,19 @@ pub use tagged::{
 /// ECC structures
 /////////////////////////////////////////////////////////
 mod ecc;
+pub use ecc::algorithm_detail::AlgorithmDetailEcc;
 pub use ecc::point::EccPoint;
 /////////////////////////////////////////////////////////
+/// Attestation Structures
+/////////////////////////////////////////////////////////
+mod attestation;
+pub use attestation::{
+    attest::Attest, attest_info::AttestInfo, certify_info::CertifyInfo, clock_info::ClockInfo,
+    command_audit_info::CommandAuditInfo, creation_info::CreationInfo,
+    nv_certify_info::NvCertifyInfo, quote_info::QuoteInfo, session_audit_info::SessionAuditInfo,
+    time_attest_info::TimeAttestInfo, time_info::TimeInfo,
+};
+/////////////////////////////////////////////////////////
 /// Signatures structures
 /////////////////////////////////////////////////////////
 mod signatures;
@@ -12,6 +12,7 @@ use std::convert::{TryFrom, TryInto};
 /// # Details
 /// Corresponds to TPMS_SYMCIPHER_PARMS
 #[derive(Clone, Debug, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SymmetricCipherParameters {
     symmetric_definition_object: SymmetricDefinitionObject,
 }
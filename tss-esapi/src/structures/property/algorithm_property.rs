@@ -0,0 +1,61 @@
+// Copyright 2021 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use crate::{
+    attributes::AlgorithmAttributes, constants::AlgorithmIdentifier, tss2_esys::TPMS_ALG_PROPERTY,
+    Error, Result,
+};
+use std::convert::{TryFrom, TryInto};
+
+/// Structure holding the properties of an algorithm
+/// implemented by the TPM.
+///
+/// # Details
+/// This corresponds to TPMS_ALG_PROPERTY
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct AlgorithmProperty {
+    algorithm_identifier: AlgorithmIdentifier,
+    algorithm_attributes: AlgorithmAttributes,
+}
+
+impl AlgorithmProperty {
+    /// Creates a new AlgorithmProperty
+    pub const fn new(
+        algorithm_identifier: AlgorithmIdentifier,
+        algorithm_attributes: AlgorithmAttributes,
+    ) -> Self {
+        AlgorithmProperty {
+            algorithm_identifier,
+            algorithm_attributes,
+        }
+    }
+
+    /// Returns the algorithm identifier.
+    pub const fn algorithm_identifier(&self) -> AlgorithmIdentifier {
+        self.algorithm_identifier
+    }
+
+    /// Returns the algorithm attributes.
+    pub const fn algorithm_attributes(&self) -> AlgorithmAttributes {
+        self.algorithm_attributes
+    }
+}
+
+impl TryFrom<TPMS_ALG_PROPERTY> for AlgorithmProperty {
+    type Error = Error;
+
+    fn try_from(tpms_algorithm_description: TPMS_ALG_PROPERTY) -> Result<Self> {
+        Ok(AlgorithmProperty {
+            algorithm_identifier: tpms_algorithm_description.alg.try_into()?,
+            algorithm_attributes: tpms_algorithm_description.algProperties.into(),
+        })
+    }
+}
+
+impl From<AlgorithmProperty> for TPMS_ALG_PROPERTY {
+    fn from(algorithm_property: AlgorithmProperty) -> Self {
+        TPMS_ALG_PROPERTY {
+            alg: algorithm_property.algorithm_identifier.into(),
+            algProperties: algorithm_property.algorithm_attributes.into(),
+        }
+    }
+}
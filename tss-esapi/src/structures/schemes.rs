@@ -8,6 +8,7 @@ use crate::{
 use std::convert::{TryFrom, TryInto};
 /// Struct for holding the hash scheme
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct HashScheme {
     hashing_algorithm: HashingAlgorithm,
 }
@@ -17,6 +18,11 @@ impl HashScheme {
     pub const fn new(hashing_algorithm: HashingAlgorithm) -> HashScheme {
         HashScheme { hashing_algorithm }
     }
+
+    /// Returns the hashing algorithm
+    pub const fn hashing_algorithm(&self) -> HashingAlgorithm {
+        self.hashing_algorithm
+    }
 }
 
 impl TryFrom<TPMS_SCHEME_HASH> for HashScheme {
@@ -38,6 +44,7 @@ impl From<HashScheme> for TPMS_SCHEME_HASH {
 
 /// Struct for holding HMAC scheme.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct HmacScheme {
     hashing_algorithm: HashingAlgorithm,
 }
@@ -84,6 +91,7 @@ impl From<HmacScheme> for TPMS_SCHEME_HMAC {
 
 /// Struct for holding the xor scheme
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct XorScheme {
     hashing_algorithm: HashingAlgorithm,
     key_derivation_function: KeyDerivationFunction,
@@ -126,6 +134,7 @@ impl From<XorScheme> for TPMS_SCHEME_XOR {
 /// # Details
 /// This corresponds to the TPMS_SCHEME_ECDAA
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct EcDaaScheme {
     hashing_algorithm: HashingAlgorithm,
     count: u16,
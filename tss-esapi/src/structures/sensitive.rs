@@ -0,0 +1,169 @@
+// Copyright 2021 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use crate::{
+    interface_types::algorithm::PublicAlgorithm,
+    structures::{Auth, Digest, EccParameter, PrivateKeyRsa, SensitiveData, SymmetricKey},
+    tss2_esys::{TPM2B_SENSITIVE, TPMT_SENSITIVE, TPMU_SENSITIVE_COMPOSITE},
+    Error, Result,
+};
+use std::convert::{TryFrom, TryInto};
+
+/// Enum representing the sensitive area of an object.
+///
+/// # Details
+/// This corresponds to TPMT_SENSITIVE. The variant
+/// has to match the type of the public area of the object.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Sensitive {
+    Rsa {
+        auth_value: Auth,
+        seed_value: Digest,
+        /// One of the prime factors of the modulus.
+        sensitive: PrivateKeyRsa,
+    },
+    Ecc {
+        auth_value: Auth,
+        seed_value: Digest,
+        /// The private scalar.
+        sensitive: EccParameter,
+    },
+    Bits {
+        auth_value: Auth,
+        seed_value: Digest,
+        /// The private data of a keyed hash object.
+        sensitive: SensitiveData,
+    },
+    Symmetric {
+        auth_value: Auth,
+        seed_value: Digest,
+        /// The symmetric key.
+        sensitive: SymmetricKey,
+    },
+}
+
+impl Sensitive {
+    /// Returns the authorization value
+    pub fn auth_value(&self) -> &Auth {
+        match self {
+            Sensitive::Rsa { auth_value, .. }
+            | Sensitive::Ecc { auth_value, .. }
+            | Sensitive::Bits { auth_value, .. }
+            | Sensitive::Symmetric { auth_value, .. } => auth_value,
+        }
+    }
+
+    /// Returns the seed value
+    pub fn seed_value(&self) -> &Digest {
+        match self {
+            Sensitive::Rsa { seed_value, .. }
+            | Sensitive::Ecc { seed_value, .. }
+            | Sensitive::Bits { seed_value, .. }
+            | Sensitive::Symmetric { seed_value, .. } => seed_value,
+        }
+    }
+}
+
+impl From<Sensitive> for TPMT_SENSITIVE {
+    fn from(sensitive: Sensitive) -> Self {
+        match sensitive {
+            Sensitive::Rsa {
+                auth_value,
+                seed_value,
+                sensitive,
+            } => TPMT_SENSITIVE {
+                sensitiveType: PublicAlgorithm::Rsa.into(),
+                authValue: auth_value.into(),
+                seedValue: seed_value.into(),
+                sensitive: TPMU_SENSITIVE_COMPOSITE {
+                    rsa: sensitive.into(),
+                },
+            },
+            Sensitive::Ecc {
+                auth_value,
+                seed_value,
+                sensitive,
+            } => TPMT_SENSITIVE {
+                sensitiveType: PublicAlgorithm::Ecc.into(),
+                authValue: auth_value.into(),
+                seedValue: seed_value.into(),
+                sensitive: TPMU_SENSITIVE_COMPOSITE {
+                    ecc: sensitive.into(),
+                },
+            },
+            Sensitive::Bits {
+                auth_value,
+                seed_value,
+                sensitive,
+            } => TPMT_SENSITIVE {
+                sensitiveType: PublicAlgorithm::KeyedHash.into(),
+                authValue: auth_value.into(),
+                seedValue: seed_value.into(),
+                sensitive: TPMU_SENSITIVE_COMPOSITE {
+                    bits: sensitive.into(),
+                },
+            },
+            Sensitive::Symmetric {
+                auth_value,
+                seed_value,
+                sensitive,
+            } => TPMT_SENSITIVE {
+                sensitiveType: PublicAlgorithm::SymCipher.into(),
+                authValue: auth_value.into(),
+                seedValue: seed_value.into(),
+                sensitive: TPMU_SENSITIVE_COMPOSITE {
+                    sym: sensitive.into(),
+                },
+            },
+        }
+    }
+}
+
+impl TryFrom<TPMT_SENSITIVE> for Sensitive {
+    type Error = Error;
+
+    fn try_from(tpmt_sensitive: TPMT_SENSITIVE) -> Result<Self> {
+        let auth_value = tpmt_sensitive.authValue.try_into()?;
+        let seed_value = tpmt_sensitive.seedValue.try_into()?;
+        match PublicAlgorithm::try_from(tpmt_sensitive.sensitiveType)? {
+            PublicAlgorithm::Rsa => Ok(Sensitive::Rsa {
+                auth_value,
+                seed_value,
+                sensitive: unsafe { tpmt_sensitive.sensitive.rsa }.try_into()?,
+            }),
+            PublicAlgorithm::Ecc => Ok(Sensitive::Ecc {
+                auth_value,
+                seed_value,
+                sensitive: unsafe { tpmt_sensitive.sensitive.ecc }.try_into()?,
+            }),
+            PublicAlgorithm::KeyedHash => Ok(Sensitive::Bits {
+                auth_value,
+                seed_value,
+                sensitive: unsafe { tpmt_sensitive.sensitive.bits }.try_into()?,
+            }),
+            PublicAlgorithm::SymCipher => Ok(Sensitive::Symmetric {
+                auth_value,
+                seed_value,
+                sensitive: unsafe { tpmt_sensitive.sensitive.sym }.try_into()?,
+            }),
+        }
+    }
+}
+
+impl From<Sensitive> for TPM2B_SENSITIVE {
+    fn from(sensitive: Sensitive) -> Self {
+        TPM2B_SENSITIVE {
+            size: std::mem::size_of::<TPMT_SENSITIVE>()
+                .try_into()
+                .expect("Failed to convert usize to u16"), // should not fail on valid targets
+            sensitiveArea: sensitive.into(),
+        }
+    }
+}
+
+impl TryFrom<TPM2B_SENSITIVE> for Sensitive {
+    type Error = Error;
+
+    fn try_from(tpm2b_sensitive: TPM2B_SENSITIVE) -> Result<Self> {
+        Sensitive::try_from(tpm2b_sensitive.sensitiveArea)
+    }
+}
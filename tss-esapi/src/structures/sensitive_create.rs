@@ -0,0 +1,78 @@
+// Copyright 2021 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use crate::{
+    structures::{Auth, SensitiveData},
+    tss2_esys::{TPM2B_SENSITIVE_CREATE, TPMS_SENSITIVE_CREATE},
+    Error, Result,
+};
+use std::convert::{TryFrom, TryInto};
+
+/// Structure holding the sensitive values used when creating an object.
+///
+/// # Details
+/// This corresponds to the TPMS_SENSITIVE_CREATE structure.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SensitiveCreate {
+    user_auth: Auth,
+    data: SensitiveData,
+}
+
+impl SensitiveCreate {
+    /// Creates a new SensitiveCreate.
+    ///
+    /// # Arguments
+    /// * `user_auth` - The authorization value of the object.
+    /// * `data` - The data that is to be sealed, a key or derivation values.
+    pub fn new(user_auth: Auth, data: SensitiveData) -> Self {
+        SensitiveCreate { user_auth, data }
+    }
+
+    /// Returns the authorization value.
+    pub const fn user_auth(&self) -> &Auth {
+        &self.user_auth
+    }
+
+    /// Returns the sensitive data.
+    pub const fn data(&self) -> &SensitiveData {
+        &self.data
+    }
+}
+
+impl From<SensitiveCreate> for TPMS_SENSITIVE_CREATE {
+    fn from(sensitive_create: SensitiveCreate) -> Self {
+        TPMS_SENSITIVE_CREATE {
+            userAuth: sensitive_create.user_auth.into(),
+            data: sensitive_create.data.into(),
+        }
+    }
+}
+
+impl TryFrom<TPMS_SENSITIVE_CREATE> for SensitiveCreate {
+    type Error = Error;
+
+    fn try_from(tss_sensitive_create: TPMS_SENSITIVE_CREATE) -> Result<Self> {
+        Ok(SensitiveCreate {
+            user_auth: tss_sensitive_create.userAuth.try_into()?,
+            data: tss_sensitive_create.data.try_into()?,
+        })
+    }
+}
+
+impl From<SensitiveCreate> for TPM2B_SENSITIVE_CREATE {
+    fn from(sensitive_create: SensitiveCreate) -> Self {
+        TPM2B_SENSITIVE_CREATE {
+            size: std::mem::size_of::<TPMS_SENSITIVE_CREATE>()
+                .try_into()
+                .expect("Failed to convert usize to u16"), // should not fail on valid targets
+            sensitive: sensitive_create.into(),
+        }
+    }
+}
+
+impl TryFrom<TPM2B_SENSITIVE_CREATE> for SensitiveCreate {
+    type Error = Error;
+
+    fn try_from(tss_sensitive_create: TPM2B_SENSITIVE_CREATE) -> Result<Self> {
+        SensitiveCreate::try_from(tss_sensitive_create.sensitive)
+    }
+}
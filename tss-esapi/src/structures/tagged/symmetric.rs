@@ -137,6 +137,7 @@ impl TryFrom<TPMT_SYM_DEF> for SymmetricDefinition {
 /// # Details
 /// This corresponds to TPMT_SYM_DEF_OBJECT
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SymmetricDefinitionObject {
     // TDOD: Investigate why TDES is missing.
     Aes {
@@ -17,6 +17,9 @@ use std::path::PathBuf;
 use std::ptr::null_mut;
 use std::str::FromStr;
 
+mod mock_tcti;
+pub use mock_tcti::MockTcti;
+
 const DEVICE: &str = "device";
 const MSSIM: &str = "mssim";
 const SWTPM: &str = "swtpm";
@@ -28,11 +31,20 @@ const TABRMD: &str = "tabrmd";
 #[allow(missing_copy_implementations)]
 pub struct TctiContext {
     tcti_context: *mut tss_esapi_sys::TSS2_TCTI_CONTEXT,
+    is_mock: bool,
 }
 
 impl TctiContext {
     /// Allocate and initialize a new TctiContext structure.
     pub fn initialize(name_conf: TctiNameConf) -> Result<Self> {
+        // The mock TCTI is not loaded through the TCTI Loader Library.
+        if let TctiNameConf::Mock(mock_tcti) = name_conf {
+            return Ok(TctiContext {
+                tcti_context: mock_tcti.into_tcti_context(),
+                is_mock: true,
+            });
+        }
+
         let mut tcti_context = null_mut();
 
         let tcti_name_conf = CString::try_from(name_conf)?;
@@ -46,7 +58,10 @@ impl TctiContext {
                 return Err(ret);
             }
         }
-        Ok(TctiContext { tcti_context })
+        Ok(TctiContext {
+            tcti_context,
+            is_mock: false,
+        })
     }
 
     /// Get access to the inner C pointer
@@ -58,7 +73,11 @@ impl TctiContext {
 impl Drop for TctiContext {
     fn drop(&mut self) {
         unsafe {
-            tss_esapi_sys::Tss2_TctiLdr_Finalize(&mut self.tcti_context);
+            if self.is_mock {
+                MockTcti::free_tcti_context(self.tcti_context);
+            } else {
+                tss_esapi_sys::Tss2_TctiLdr_Finalize(&mut self.tcti_context);
+            }
         }
     }
 }
@@ -144,6 +163,11 @@ pub enum TctiNameConf {
     ///
     /// For more information about configuration, see [this page](https://www.mankier.com/3/Tss2_Tcti_Tabrmd_Init)
     Tabrmd(TabrmdConfig),
+    /// Send the commands to a mock TCTI that records them and returns pre-canned responses
+    ///
+    /// The mock TCTI cannot be loaded through the TCTI Loader Library, so it
+    /// cannot be converted into a TCTI name and configuration string.
+    Mock(MockTcti),
 }
 
 impl TctiNameConf {
@@ -175,6 +199,10 @@ impl TryFrom<TctiNameConf> for CString {
             TctiNameConf::Mssim(..) => MSSIM,
             TctiNameConf::Swtpm(..) => SWTPM,
             TctiNameConf::Tabrmd(..) => TABRMD,
+            TctiNameConf::Mock(..) => {
+                error!("The mock TCTI has no TCTI name");
+                return Err(Error::WrapperError(WrapperErrorKind::InvalidParam));
+            }
         };
 
         let tcti_conf = match tcti {
@@ -201,6 +229,8 @@ impl TryFrom<TctiNameConf> for CString {
             TctiNameConf::Tabrmd(config) => {
                 format!("bus_name={},bus_type={}", config.bus_name, config.bus_type)
             }
+            // Rejected when getting the TCTI name.
+            TctiNameConf::Mock(..) => unreachable!(),
         };
 
         if tcti_conf.is_empty() {
@@ -212,6 +242,14 @@ impl TryFrom<TctiNameConf> for CString {
     }
 }
 
+/// Parses a TCTI name and configuration string, in the `name:conf`
+/// format used by the tpm2-tools (e.g. `swtpm:host=localhost,port=2321`
+/// or `device:/dev/tpmrm0`).
+///
+/// # Errors
+/// * if the TCTI name is not one of `device`, `mssim`, `swtpm` and `tabrmd`,
+///   or if the configuration is invalid, an `InvalidParam` wrapper error is
+///   returned.
 impl FromStr for TctiNameConf {
     type Err = Error;
 
@@ -244,6 +282,7 @@ impl FromStr for TctiNameConf {
             )?));
         }
 
+        error!("Unknown TCTI name in the configuration: {}", config_str);
         Err(Error::WrapperError(WrapperErrorKind::InvalidParam))
     }
 }
@@ -308,6 +347,30 @@ fn validate_from_str_tcti() {
 
     let tcti = TctiNameConf::from_str("tabrmd").unwrap();
     assert_eq!(tcti, TctiNameConf::Tabrmd(Default::default()));
+
+    let tcti = TctiNameConf::from_str("swtpm:host=localhost,port=2321").unwrap();
+    assert_eq!(
+        tcti,
+        TctiNameConf::Swtpm(NetworkTPMConfig {
+            port: 2321,
+            host: ServerAddress::Hostname(String::from("localhost"))
+        })
+    );
+
+    let tcti = TctiNameConf::from_str("device:/dev/tpmrm0").unwrap();
+    assert_eq!(
+        tcti,
+        TctiNameConf::Device(DeviceConfig {
+            path: PathBuf::from("/dev/tpmrm0"),
+        })
+    );
+
+    for config_str in ["", "libtpms", "mssimx", "unknown:host=localhost", ":device"] {
+        assert_eq!(
+            TctiNameConf::from_str(config_str),
+            Err(Error::WrapperError(WrapperErrorKind::InvalidParam))
+        );
+    }
 }
 
 /// Configuration for a Device TCTI context
@@ -0,0 +1,193 @@
+// Copyright 2021 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Mock TCTI, allowing the commands sent by the ESAPI to be inspected
+//! without a TPM.
+use std::collections::VecDeque;
+use std::ptr;
+use std::sync::{Arc, Mutex, MutexGuard};
+use tss_esapi_sys::{
+    size_t, TSS2_BASE_RC_BAD_REFERENCE, TSS2_BASE_RC_BAD_SEQUENCE,
+    TSS2_BASE_RC_INSUFFICIENT_BUFFER, TSS2_BASE_RC_NOT_IMPLEMENTED, TSS2_RC, TSS2_RC_LAYER_SHIFT,
+    TSS2_TCTI_CONTEXT, TSS2_TCTI_CONTEXT_COMMON_V1, TSS2_TCTI_CONTEXT_COMMON_V2,
+    TSS2_TCTI_POLL_HANDLE,
+};
+
+/// Layer of the response codes returned by the TCTI.
+const TCTI_RC_LAYER: TSS2_RC = 10 << TSS2_RC_LAYER_SHIFT;
+/// Magic value identifying the mock TCTI contexts ("MOCKTCTI").
+const MOCK_TCTI_MAGIC: u64 = 0x4d4f_434b_5443_5449;
+
+/// TCTI recording the commands it receives and answering
+/// them with pre-canned responses.
+///
+/// # Details
+/// The responses are returned in the order in which they were added,
+/// one for each command. The mock TCTI is selected by creating a
+/// context with [TctiNameConf::Mock](super::TctiNameConf::Mock). Clones
+/// of the mock TCTI share the same commands and responses, so a clone
+/// can be kept in order to inspect the commands sent by the context.
+#[derive(Clone, Debug, Default)]
+pub struct MockTcti {
+    state: Arc<Mutex<MockTctiState>>,
+}
+
+#[derive(Debug, Default)]
+struct MockTctiState {
+    commands: Vec<Vec<u8>>,
+    responses: VecDeque<Vec<u8>>,
+}
+
+impl MockTcti {
+    /// Creates a mock TCTI without any responses.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Adds a marshalled response, to be returned for the next command
+    /// that has not been answered yet.
+    pub fn push_response(&self, response: Vec<u8>) {
+        self.state().responses.push_back(response);
+    }
+
+    /// Returns the marshalled commands that have been sent through the
+    /// mock TCTI, in the order in which they were sent.
+    pub fn commands(&self) -> Vec<Vec<u8>> {
+        self.state().commands.clone()
+    }
+
+    fn state(&self) -> MutexGuard<'_, MockTctiState> {
+        // The state is never left inconsistent, so a poisoned lock can be reused.
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Allocates a TCTI context using the mock TCTI.
+    ///
+    /// The context has to be released with [MockTcti::free_tcti_context].
+    pub(crate) fn into_tcti_context(self) -> *mut TSS2_TCTI_CONTEXT {
+        let context = Box::new(MockTctiContext {
+            common: TSS2_TCTI_CONTEXT_COMMON_V2 {
+                v1: TSS2_TCTI_CONTEXT_COMMON_V1 {
+                    magic: MOCK_TCTI_MAGIC,
+                    version: 2,
+                    transmit: Some(transmit),
+                    receive: Some(receive),
+                    finalize: Some(finalize),
+                    cancel: Some(cancel),
+                    getPollHandles: Some(get_poll_handles),
+                    setLocality: Some(set_locality),
+                },
+                makeSticky: Some(make_sticky),
+            },
+            mock_tcti: self,
+        });
+        Box::into_raw(context) as *mut TSS2_TCTI_CONTEXT
+    }
+
+    /// Releases a TCTI context allocated by [MockTcti::into_tcti_context].
+    ///
+    /// # Safety
+    /// The context must have been allocated by [MockTcti::into_tcti_context]
+    /// and must not be used after this call.
+    pub(crate) unsafe fn free_tcti_context(tcti_context: *mut TSS2_TCTI_CONTEXT) {
+        drop(Box::from_raw(tcti_context as *mut MockTctiContext));
+    }
+}
+
+impl PartialEq for MockTcti {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.state, &other.state)
+    }
+}
+
+/// TCTI context of the mock TCTI, starting with the common
+/// structure expected by the TSS.
+#[repr(C)]
+struct MockTctiContext {
+    common: TSS2_TCTI_CONTEXT_COMMON_V2,
+    mock_tcti: MockTcti,
+}
+
+/// Gets the mock TCTI of the context, if the context is a mock TCTI context.
+unsafe fn mock_tcti<'a>(tcti_context: *mut TSS2_TCTI_CONTEXT) -> Option<&'a MockTcti> {
+    let context = (tcti_context as *const MockTctiContext).as_ref()?;
+    if context.common.v1.magic == MOCK_TCTI_MAGIC {
+        Some(&context.mock_tcti)
+    } else {
+        None
+    }
+}
+
+unsafe extern "C" fn transmit(
+    tcti_context: *mut TSS2_TCTI_CONTEXT,
+    size: size_t,
+    command: *const u8,
+) -> TSS2_RC {
+    let mock_tcti = match mock_tcti(tcti_context) {
+        Some(mock_tcti) if !command.is_null() => mock_tcti,
+        _ => return TCTI_RC_LAYER | TSS2_BASE_RC_BAD_REFERENCE,
+    };
+    let command = std::slice::from_raw_parts(command, size as usize).to_vec();
+    mock_tcti.state().commands.push(command);
+    0
+}
+
+unsafe extern "C" fn receive(
+    tcti_context: *mut TSS2_TCTI_CONTEXT,
+    size: *mut size_t,
+    response: *mut u8,
+    _timeout: i32,
+) -> TSS2_RC {
+    let mock_tcti = match mock_tcti(tcti_context) {
+        Some(mock_tcti) if !size.is_null() => mock_tcti,
+        _ => return TCTI_RC_LAYER | TSS2_BASE_RC_BAD_REFERENCE,
+    };
+    let mut state = mock_tcti.state();
+    let response_size = match state.responses.front() {
+        Some(next_response) => next_response.len() as size_t,
+        // There is no response left for the command.
+        None => return TCTI_RC_LAYER | TSS2_BASE_RC_BAD_SEQUENCE,
+    };
+    // A null buffer is used for querying the size of the response.
+    if response.is_null() {
+        *size = response_size;
+        return 0;
+    }
+    if *size < response_size {
+        *size = response_size;
+        return TCTI_RC_LAYER | TSS2_BASE_RC_INSUFFICIENT_BUFFER;
+    }
+    if let Some(next_response) = state.responses.pop_front() {
+        ptr::copy_nonoverlapping(next_response.as_ptr(), response, next_response.len());
+    }
+    *size = response_size;
+    0
+}
+
+unsafe extern "C" fn finalize(_tcti_context: *mut TSS2_TCTI_CONTEXT) {
+    // The memory is owned by the TctiContext, which frees it when dropped.
+}
+
+unsafe extern "C" fn cancel(_tcti_context: *mut TSS2_TCTI_CONTEXT) -> TSS2_RC {
+    TCTI_RC_LAYER | TSS2_BASE_RC_NOT_IMPLEMENTED
+}
+
+unsafe extern "C" fn get_poll_handles(
+    _tcti_context: *mut TSS2_TCTI_CONTEXT,
+    _handles: *mut TSS2_TCTI_POLL_HANDLE,
+    _num_handles: *mut size_t,
+) -> TSS2_RC {
+    TCTI_RC_LAYER | TSS2_BASE_RC_NOT_IMPLEMENTED
+}
+
+unsafe extern "C" fn set_locality(_tcti_context: *mut TSS2_TCTI_CONTEXT, _locality: u8) -> TSS2_RC {
+    TCTI_RC_LAYER | TSS2_BASE_RC_NOT_IMPLEMENTED
+}
+
+unsafe extern "C" fn make_sticky(
+    _tcti_context: *mut TSS2_TCTI_CONTEXT,
+    _handle: *mut u32,
+    _sticky: u8,
+) -> TSS2_RC {
+    TCTI_RC_LAYER | TSS2_BASE_RC_NOT_IMPLEMENTED
+}
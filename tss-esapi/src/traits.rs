@@ -0,0 +1,83 @@
+// Copyright 2021 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Traits for converting the structures to and from
+//! the marshalled form used by the TPM.
+use crate::{
+    tss2_esys::{size_t, TSS2_RC},
+    Error, Result, WrapperErrorKind,
+};
+use log::error;
+
+/// Trait for types that can be marshalled
+/// into the binary form used by the TPM.
+pub trait Marshall: Sized {
+    /// Returns the type in the form of marshalled data
+    fn marshall(&self) -> Result<Vec<u8>>;
+}
+
+/// Trait for types that can be unmarshalled
+/// from the binary form used by the TPM.
+pub trait UnMarshall: Sized {
+    /// Creates the type from marshalled data.
+    ///
+    /// # Errors
+    /// * if the data could not be unmarshalled, or if it contains
+    ///   trailing bytes, an error will be returned.
+    fn unmarshall(marshalled_data: &[u8]) -> Result<Self>;
+}
+
+/// Marshalls a TSS value using the provided `Tss2_MU_*_Marshal` function.
+pub(crate) fn marshall_tss<T>(
+    tss_value: &T,
+    marshal: unsafe extern "C" fn(*const T, *mut u8, size_t, *mut size_t) -> TSS2_RC,
+) -> Result<Vec<u8>> {
+    let mut buffer = vec![0; std::mem::size_of::<T>()];
+    let mut offset = 0;
+    let ret = Error::from_tss_rc(unsafe {
+        marshal(
+            tss_value,
+            buffer.as_mut_ptr(),
+            buffer.len() as size_t,
+            &mut offset,
+        )
+    });
+    if !ret.is_success() {
+        error!("Error when marshalling data: {}", ret);
+        return Err(ret);
+    }
+    buffer.truncate(offset as usize);
+    Ok(buffer)
+}
+
+/// Unmarshalls a TSS value using the provided `Tss2_MU_*_Unmarshal` function.
+///
+/// All of the marshalled data must be consumed.
+pub(crate) fn unmarshall_tss<T: Default>(
+    marshalled_data: &[u8],
+    unmarshal: unsafe extern "C" fn(*const u8, size_t, *mut size_t, *mut T) -> TSS2_RC,
+) -> Result<T> {
+    let mut tss_value = T::default();
+    let mut offset = 0;
+    let ret = Error::from_tss_rc(unsafe {
+        unmarshal(
+            marshalled_data.as_ptr(),
+            marshalled_data.len() as size_t,
+            &mut offset,
+            &mut tss_value,
+        )
+    });
+    if !ret.is_success() {
+        error!("Error when unmarshalling data: {}", ret);
+        return Err(ret);
+    }
+    if offset as usize != marshalled_data.len() {
+        error!(
+            "Error: Only {} of the {} bytes of marshalled data were consumed",
+            offset,
+            marshalled_data.len()
+        );
+        return Err(Error::local_error(WrapperErrorKind::WrongParamSize));
+    }
+    Ok(tss_value)
+}
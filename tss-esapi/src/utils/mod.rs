@@ -19,14 +19,13 @@ use crate::structures::{
     Digest, EccPoint, EccScheme, PcrSlot, Public, PublicBuilder, PublicEccParametersBuilder,
     PublicKeyRsa, PublicRsaParametersBuilder, RsaExponent, RsaScheme, SymmetricDefinitionObject,
 };
+use crate::traits::{marshall_tss, unmarshall_tss, Marshall, UnMarshall};
 use crate::tss2_esys::*;
 use crate::{Context, Error, Result, WrapperErrorKind};
 use enumflags2::BitFlags;
 use log::error;
 use zeroize::Zeroize;
 
-use serde::{Deserialize, Serialize};
-use std::collections::BTreeMap;
 use std::convert::{TryFrom, TryInto};
 
 /// Rust native wrapper for `TPMS_CONTEXT` objects.
@@ -34,8 +33,9 @@ use std::convert::{TryFrom, TryInto};
 /// This structure is intended to help with persisting object contexts. As the main reason for
 /// saving the context of an object is to be able to re-use it later, on demand, a serializable
 /// structure is most commonly needed. `TpmsContext` implements the `Serialize` and `Deserialize`
-/// defined by `serde`.
-#[derive(Debug, Serialize, Deserialize, Clone, Zeroize)]
+/// defined by `serde`, when the `serde` feature is enabled.
+#[derive(Debug, Clone, Zeroize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[zeroize(drop)]
 pub struct TpmsContext {
     sequence: u64,
@@ -49,6 +49,43 @@ impl TpmsContext {
     pub fn context_blob(&self) -> &Vec<u8> {
         &self.context_blob
     }
+
+    /// Returns the context in the marshalled form of a `TPMS_CONTEXT`.
+    ///
+    /// # Details
+    /// The sequence number and the hierarchy, which the TPM validates
+    /// when loading the context, are preserved exactly. This allows
+    /// persisting saved contexts, e.g. to disk, across process restarts.
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        self.marshall()
+    }
+
+    /// Creates a context from the marshalled form of a `TPMS_CONTEXT`.
+    ///
+    /// # Errors
+    /// * if the data is not a valid marshalled `TPMS_CONTEXT`, or if it
+    ///   contains trailing bytes, an error is returned.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        TpmsContext::unmarshall(bytes)
+    }
+}
+
+impl Marshall for TpmsContext {
+    fn marshall(&self) -> Result<Vec<u8>> {
+        marshall_tss(
+            &TPMS_CONTEXT::try_from(self.clone())?,
+            Tss2_MU_TPMS_CONTEXT_Marshal,
+        )
+    }
+}
+
+impl UnMarshall for TpmsContext {
+    fn unmarshall(marshalled_data: &[u8]) -> Result<Self> {
+        TpmsContext::try_from(unmarshall_tss::<TPMS_CONTEXT>(
+            marshalled_data,
+            Tss2_MU_TPMS_CONTEXT_Unmarshal,
+        )?)
+    }
 }
 
 // TODO: Replace with `From`
@@ -275,7 +312,8 @@ pub fn create_unrestricted_signing_ecc_public(
         .build()
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, Zeroize, PartialEq, Eq)]
+#[derive(Debug, Clone, Zeroize, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum PublicKey {
     Rsa(Vec<u8>),
     Ecc { x: Vec<u8>, y: Vec<u8> },
@@ -300,34 +338,48 @@ type PcrValue = Digest;
 
 /// Struct for holding PcrSlots and their
 /// corresponding values.
+///
+/// # Details
+/// The pcr slots are kept in ascending order, with the
+/// values stored in the same order.
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub struct PcrBank {
-    bank: BTreeMap<PcrSlot, PcrValue>,
+    pcr_slots: Vec<PcrSlot>,
+    pcr_values: Vec<PcrValue>,
 }
 
 impl PcrBank {
     /// Function for retrieving a pcr value corresponding to a pcr slot.
     pub fn pcr_value(&self, pcr_slot: PcrSlot) -> Option<&PcrValue> {
-        self.bank.get(&pcr_slot)
+        self.pcr_slots
+            .iter()
+            .position(|slot| *slot == pcr_slot)
+            .map(|index| &self.pcr_values[index])
+    }
+
+    /// Function for retrieving the pcr values, in the order of their pcr slots.
+    pub fn pcr_values(&self) -> &[PcrValue] {
+        &self.pcr_values
     }
 
     /// Function for retrieiving the number of pcr slot values in the bank.
     pub fn len(&self) -> usize {
-        self.bank.len()
+        self.pcr_values.len()
     }
 
     /// Returns true if there are no pcr slot values in the bank.
     pub fn is_empty(&self) -> bool {
-        self.bank.is_empty()
+        self.pcr_values.is_empty()
     }
 }
 
 impl<'a> IntoIterator for &'a PcrBank {
     type Item = (&'a PcrSlot, &'a PcrValue);
-    type IntoIter = ::std::collections::btree_map::Iter<'a, PcrSlot, PcrValue>;
+    type IntoIter =
+        ::std::iter::Zip<::std::slice::Iter<'a, PcrSlot>, ::std::slice::Iter<'a, PcrValue>>;
 
     fn into_iter(self) -> Self::IntoIter {
-        self.bank.iter()
+        self.pcr_slots.iter().zip(self.pcr_values.iter())
     }
 }
 
@@ -378,7 +430,8 @@ impl PcrData {
                     })?;
             // Create PCR bank by mapping the pcr slots to the pcr values
             let mut parsed_pcr_bank = PcrBank {
-                bank: Default::default(),
+                pcr_slots: Vec::new(),
+                pcr_values: Vec::new(),
             };
             for pcr_slot in parsed_pcr_slots.iter() {
                 // Make sure there are still data
@@ -390,14 +443,14 @@ impl PcrData {
                     }
                 };
                 // Add the value corresponding to the pcr slot.
-                if parsed_pcr_bank
-                    .bank
-                    .insert(pcr_slot, PcrValue::try_from(*digest)?)
-                    .is_some()
-                {
+                if parsed_pcr_bank.pcr_slots.contains(&pcr_slot) {
                     error!("Error trying to insert data into PcrSlot where data have already been inserted");
                     return Err(Error::local_error(WrapperErrorKind::InconsistentParams));
                 }
+                parsed_pcr_bank.pcr_slots.push(pcr_slot);
+                parsed_pcr_bank
+                    .pcr_values
+                    .push(PcrValue::try_from(*digest)?);
             }
             data.push((parsed_hash_algorithm, parsed_pcr_bank));
         }
@@ -418,6 +471,20 @@ impl PcrData {
             .map(|(_, bank)| bank)
     }
 
+    /// Function for iterating over the banks in the data.
+    ///
+    /// # Details
+    /// The pcr values of each bank, in the order of their pcr slots,
+    /// are yielded together with the hashing algorithm of the bank.
+    /// The banks are yielded in the order they were read, and banks
+    /// without any pcr values are skipped.
+    pub fn iter_banks(&self) -> impl Iterator<Item = (HashingAlgorithm, &[PcrValue])> {
+        self.data
+            .iter()
+            .filter(|(_, bank)| !bank.is_empty())
+            .map(|(alg, bank)| (*alg, bank.pcr_values()))
+    }
+
     /// Function for retrieving the number of banks in the data.
     pub fn len(&self) -> usize {
         self.data.len()
@@ -6,13 +6,19 @@ use std::convert::{TryFrom, TryInto};
 use tss_esapi::{
     abstraction::{ak, ek, KeyCustomization},
     attributes::{ObjectAttributesBuilder, SessionAttributesBuilder},
-    constants::SessionType,
+    constants::{tss::TPM2_ALG_NULL, SessionType, StructureTag},
     handles::AuthHandle,
     interface_types::{
         algorithm::{AsymmetricAlgorithm, HashingAlgorithm, SignatureSchemeAlgorithm},
+        ecc::EccCurve,
+        resource_handles::Hierarchy,
         session_handles::PolicySession,
     },
-    structures::{Auth, Digest, SymmetricDefinition},
+    structures::{
+        Attest, Auth, Data, Digest, EccScheme, MaxBuffer, PcrSelectionListBuilder, PcrSlot, Public,
+        Signature, SymmetricDefinition,
+    },
+    tss2_esys::TPMT_SIG_SCHEME,
 };
 
 mod common;
@@ -54,6 +60,151 @@ fn test_create_ak_rsa_ecc() {
     }
 }
 
+#[test]
+fn test_create_ak_ecc() {
+    let mut context = create_ctx_without_session();
+
+    let ek_rsa = ek::create_ek_object(&mut context, AsymmetricAlgorithm::Rsa, None).unwrap();
+    let att_key = ak::create_ak(
+        &mut context,
+        ek_rsa,
+        HashingAlgorithm::Sha256,
+        SignatureSchemeAlgorithm::EcDsa,
+        None,
+        None,
+    )
+    .unwrap();
+
+    if let Public::Ecc {
+        object_attributes,
+        parameters,
+        ..
+    } = att_key.out_public
+    {
+        assert!(object_attributes.restricted());
+        assert!(object_attributes.sign_encrypt());
+        assert!(!object_attributes.decrypt());
+        assert_eq!(parameters.ecc_curve(), EccCurve::NistP256);
+        if let EccScheme::EcDsa(hash_scheme) = parameters.ecc_scheme() {
+            assert_eq!(hash_scheme.hashing_algorithm(), HashingAlgorithm::Sha256);
+        } else {
+            panic!("The AK does not have an ECDSA scheme");
+        }
+    } else {
+        panic!("The AK is not an ECC key");
+    }
+
+    context.flush_context(ek_rsa.into()).unwrap();
+}
+
+#[test]
+fn test_create_ak_and_quote() {
+    let mut context = create_ctx_without_session();
+
+    let ek_rsa = ek::create_ek_object(&mut context, AsymmetricAlgorithm::Rsa, None).unwrap();
+    let ak_auth = Auth::try_from(vec![0x1, 0x2, 0x42]).unwrap();
+    let att_key = ak::create_ak(
+        &mut context,
+        ek_rsa,
+        HashingAlgorithm::Sha256,
+        SignatureSchemeAlgorithm::RsaSsa,
+        Some(&ak_auth),
+        None,
+    )
+    .unwrap();
+    let loaded_ak = ak::load_ak(
+        &mut context,
+        ek_rsa,
+        Some(&ak_auth),
+        att_key.out_private,
+        att_key.out_public,
+    )
+    .unwrap();
+
+    let pcr_selection_list = PcrSelectionListBuilder::new()
+        .with_selection(HashingAlgorithm::Sha256, &[PcrSlot::Slot0])
+        .build();
+    let qualifying_data = Data::try_from(vec![0xab; 16]).unwrap();
+    let (attest, signature) = context
+        .execute_with_nullauth_session(|ctx| {
+            ctx.quote(
+                loaded_ak,
+                &qualifying_data,
+                TPMT_SIG_SCHEME {
+                    scheme: TPM2_ALG_NULL,
+                    details: Default::default(),
+                },
+                pcr_selection_list,
+            )
+        })
+        .expect("Failed to quote with the AK");
+    let attest = Attest::try_from(attest).expect("Failed to convert the attest");
+
+    assert_eq!(attest.attestation_type(), StructureTag::AttestQuote);
+    assert_eq!(attest.extra_data(), &qualifying_data);
+    assert!(matches!(signature, Signature::RsaSsa(_)));
+
+    context.flush_context(loaded_ak.into()).unwrap();
+    context.flush_context(ek_rsa.into()).unwrap();
+}
+
+#[test]
+fn test_load_ak_and_sign() {
+    let mut context = create_ctx_without_session();
+
+    let ek_rsa = ek::create_ek_object(&mut context, AsymmetricAlgorithm::Rsa, None).unwrap();
+    let ak_auth = Auth::try_from(vec![0x1, 0x2, 0x42]).unwrap();
+    let att_key = ak::create_ak(
+        &mut context,
+        ek_rsa,
+        HashingAlgorithm::Sha256,
+        SignatureSchemeAlgorithm::RsaPss,
+        Some(&ak_auth),
+        None,
+    )
+    .unwrap();
+    let loaded_ak = ak::load_ak(
+        &mut context,
+        ek_rsa,
+        Some(&ak_auth),
+        att_key.out_private,
+        att_key.out_public,
+    )
+    .unwrap();
+
+    // The AK is restricted, so the digest has to be computed by the TPM.
+    let (digest, ticket) = context
+        .execute_without_session(|ctx| {
+            ctx.hash(
+                &MaxBuffer::try_from(vec![0xab; 32]).unwrap(),
+                HashingAlgorithm::Sha256,
+                Hierarchy::Endorsement,
+            )
+        })
+        .unwrap();
+    let signature = context
+        .execute_with_nullauth_session(|ctx| {
+            ctx.sign(
+                loaded_ak,
+                &digest,
+                TPMT_SIG_SCHEME {
+                    scheme: TPM2_ALG_NULL,
+                    details: Default::default(),
+                },
+                ticket,
+            )
+        })
+        .expect("Failed to sign with the AK");
+    assert!(matches!(signature, Signature::RsaPss(_)));
+
+    let _ = context
+        .execute_without_session(|ctx| ctx.verify_signature(loaded_ak, &digest, signature))
+        .expect("Failed to verify the signature of the AK");
+
+    context.flush_context(loaded_ak.into()).unwrap();
+    context.flush_context(ek_rsa.into()).unwrap();
+}
+
 #[test]
 fn test_create_and_use_ak() {
     let mut context = create_ctx_without_session();
@@ -0,0 +1,184 @@
+// Copyright 2021 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+mod common;
+
+use std::convert::TryFrom;
+use tss_esapi::{
+    abstraction::attestation::parse_quote,
+    constants::tss::TPM2_ALG_NULL,
+    interface_types::{algorithm::HashingAlgorithm, resource_handles::Hierarchy},
+    structures::{Attest, Data, MaxBuffer, PcrSelectionListBuilder, PcrSlot},
+    tss2_esys::TPMT_SIG_SCHEME,
+};
+
+const QUOTED_PCRS: [PcrSlot; 8] = [
+    PcrSlot::Slot0,
+    PcrSlot::Slot1,
+    PcrSlot::Slot2,
+    PcrSlot::Slot3,
+    PcrSlot::Slot4,
+    PcrSlot::Slot5,
+    PcrSlot::Slot6,
+    PcrSlot::Slot7,
+];
+
+#[test]
+fn test_parse_quote() {
+    let mut context = common::create_ctx_with_session();
+    let key_handle = context
+        .create_primary(
+            Hierarchy::Owner,
+            &common::signing_key_pub(),
+            None,
+            None,
+            None,
+            None,
+        )
+        .expect("Failed to create signing key")
+        .key_handle;
+    let pcr_selection_list = PcrSelectionListBuilder::new()
+        .with_selection(HashingAlgorithm::Sha256, &QUOTED_PCRS)
+        .build();
+    let qualifying_data = Data::try_from(vec![0xab; 16]).expect("Failed to create nonce");
+
+    let (attest, _signature) = context
+        .quote(
+            key_handle,
+            &qualifying_data,
+            TPMT_SIG_SCHEME {
+                scheme: TPM2_ALG_NULL,
+                details: Default::default(),
+            },
+            pcr_selection_list.clone(),
+        )
+        .expect("Failed to get a quote");
+    let attest = Attest::try_from(attest).expect("Failed to convert the attest");
+    let quote_info = parse_quote(&attest).expect("Failed to parse the quote");
+    assert_eq!(&pcr_selection_list, quote_info.pcr_select());
+    assert_eq!(&qualifying_data, quote_info.extra_data());
+
+    // The PCR digest is the hash of the concatenated PCR values.
+    let (_, _, pcr_data) = context
+        .execute_without_session(|ctx| ctx.pcr_read(&pcr_selection_list))
+        .expect("Failed to read PCRs");
+    let pcr_bank = pcr_data
+        .pcr_bank(HashingAlgorithm::Sha256)
+        .expect("Failed to get the SHA-256 bank");
+    let concatenated_pcr_values: Vec<u8> = QUOTED_PCRS
+        .iter()
+        .flat_map(|pcr_slot| {
+            pcr_bank
+                .pcr_value(*pcr_slot)
+                .expect("Failed to get PCR value")
+                .value()
+                .to_vec()
+        })
+        .collect();
+    let (expected_pcr_digest, _) = context
+        .execute_without_session(|ctx| {
+            ctx.hash(
+                &MaxBuffer::try_from(concatenated_pcr_values).expect("Failed to create buffer"),
+                HashingAlgorithm::Sha256,
+                Hierarchy::Null,
+            )
+        })
+        .expect("Failed to hash PCR values");
+    assert_eq!(&expected_pcr_digest, quote_info.pcr_digest());
+}
+
+#[cfg(feature = "verify-quote")]
+mod test_verify_quote {
+    use crate::common;
+    use std::convert::TryFrom;
+    use tss_esapi::{
+        abstraction::attestation::verify_quote,
+        constants::tss::TPM2_ALG_NULL,
+        interface_types::{
+            algorithm::{EccSchemeAlgorithm, HashingAlgorithm},
+            ecc::EccCurve,
+            resource_handles::Hierarchy,
+        },
+        structures::{
+            Attest, Data, EccScheme, PcrSelectionListBuilder, PcrSlot, Public, Signature,
+        },
+        traits::{Marshall, UnMarshall},
+        tss2_esys::TPMT_SIG_SCHEME,
+        utils, Error, WrapperErrorKind,
+    };
+
+    fn quote(key_template: &Public, nonce: &Data) -> (Public, Attest, Signature) {
+        let mut context = common::create_ctx_with_session();
+        let key = context
+            .create_primary(Hierarchy::Owner, key_template, None, None, None, None)
+            .expect("Failed to create signing key");
+        let (attest, signature) = context
+            .quote(
+                key.key_handle,
+                nonce,
+                TPMT_SIG_SCHEME {
+                    scheme: TPM2_ALG_NULL,
+                    details: Default::default(),
+                },
+                PcrSelectionListBuilder::new()
+                    .with_selection(HashingAlgorithm::Sha256, &[PcrSlot::Slot0, PcrSlot::Slot1])
+                    .build(),
+            )
+            .expect("Failed to get a quote");
+        let attest = Attest::try_from(attest).expect("Failed to convert the attest");
+        (key.out_public, attest, signature)
+    }
+
+    fn tampered(attest: &Attest) -> Attest {
+        let mut marshalled_attest = attest.marshall().expect("Failed to marshall attest");
+        // The last byte belongs to the PCR digest.
+        let last = marshalled_attest.len() - 1;
+        marshalled_attest[last] ^= 0xff;
+        Attest::unmarshall(&marshalled_attest).expect("Failed to unmarshall attest")
+    }
+
+    #[test]
+    fn test_verify_rsa_quote() {
+        let nonce = Data::try_from(vec![0x11; 16]).expect("Failed to create nonce");
+        let (public, attest, signature) = quote(&common::signing_key_pub(), &nonce);
+        assert!(verify_quote(&public, &attest, &signature, &nonce).expect("Failed to verify"));
+        assert!(
+            !verify_quote(&public, &tampered(&attest), &signature, &nonce)
+                .expect("Failed to verify")
+        );
+    }
+
+    #[test]
+    fn test_verify_ecc_quote() {
+        let nonce = Data::try_from(vec![0x22; 16]).expect("Failed to create nonce");
+        let key_template = utils::create_unrestricted_signing_ecc_public(
+            EccScheme::create(
+                EccSchemeAlgorithm::EcDsa,
+                Some(HashingAlgorithm::Sha256),
+                None,
+            )
+            .expect("Failed to create ECC scheme"),
+            EccCurve::NistP256,
+        )
+        .expect("Failed to create ECC key template");
+        let (public, attest, signature) = quote(&key_template, &nonce);
+        assert!(verify_quote(&public, &attest, &signature, &nonce).expect("Failed to verify"));
+        assert!(
+            !verify_quote(&public, &tampered(&attest), &signature, &nonce)
+                .expect("Failed to verify")
+        );
+    }
+
+    #[test]
+    fn test_verify_quote_wrong_nonce() {
+        let nonce = Data::try_from(vec![0x33; 16]).expect("Failed to create nonce");
+        let (public, attest, signature) = quote(&common::signing_key_pub(), &nonce);
+        let other_nonce = Data::try_from(vec![0x44; 16]).expect("Failed to create nonce");
+        if let Err(Error::WrapperError(kind)) =
+            verify_quote(&public, &attest, &signature, &other_nonce)
+        {
+            assert_eq!(kind, WrapperErrorKind::InconsistentParams);
+        } else {
+            panic!("Verifying the quote with the wrong nonce did not fail as expected");
+        }
+    }
+}
@@ -0,0 +1,140 @@
+// Copyright 2021 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+#![cfg(feature = "make-credential")]
+mod common;
+
+use std::convert::TryFrom;
+use tss_esapi::{
+    abstraction::{ak, credential::make_credential_local, ek},
+    attributes::SessionAttributesBuilder,
+    constants::SessionType,
+    handles::{AuthHandle, SessionHandle},
+    interface_types::{
+        algorithm::{AsymmetricAlgorithm, HashingAlgorithm, SignatureSchemeAlgorithm},
+        session_handles::PolicySession,
+    },
+    structures::{Auth, Digest, Name, SymmetricDefinition},
+    Context, Error, WrapperErrorKind,
+};
+
+/// Makes a credential for an AK without the TPM, and activates it with the TPM.
+fn make_and_activate_credential(context: &mut Context, ek_alg: AsymmetricAlgorithm) {
+    let ek = ek::create_ek_object(context, ek_alg, None).unwrap();
+    let (ek_public, _, _) = context.read_public(ek).unwrap();
+    let ak_auth = Auth::try_from(vec![0x1, 0x2, 0x42]).unwrap();
+    let att_key = ak::create_ak(
+        context,
+        ek,
+        HashingAlgorithm::Sha256,
+        SignatureSchemeAlgorithm::RsaPss,
+        Some(&ak_auth),
+        None,
+    )
+    .unwrap();
+    let loaded_ak = ak::load_ak(
+        context,
+        ek,
+        Some(&ak_auth),
+        att_key.out_private,
+        att_key.out_public,
+    )
+    .unwrap();
+    let (_, ak_name, _) = context.read_public(loaded_ak).unwrap();
+
+    let credential = [0xab; 32];
+    let (credential_blob, secret) = make_credential_local(&ek_public, &credential, &ak_name)
+        .expect("Failed to make the credential");
+
+    let (session_attributes, session_attributes_mask) = SessionAttributesBuilder::new().build();
+    let hmac_session = context
+        .start_auth_session(
+            None,
+            None,
+            None,
+            SessionType::Hmac,
+            SymmetricDefinition::AES_256_CFB,
+            HashingAlgorithm::Sha256,
+        )
+        .expect("Failed to call start_auth_session")
+        .expect("Failed invalid session value");
+    context
+        .tr_sess_set_attributes(hmac_session, session_attributes, session_attributes_mask)
+        .unwrap();
+    let policy_session = context
+        .start_auth_session(
+            None,
+            None,
+            None,
+            SessionType::Policy,
+            SymmetricDefinition::AES_256_CFB,
+            HashingAlgorithm::Sha256,
+        )
+        .expect("Failed to call start_auth_session")
+        .expect("Failed invalid session value");
+    context
+        .tr_sess_set_attributes(policy_session, session_attributes, session_attributes_mask)
+        .unwrap();
+    let _ = context
+        .execute_with_session(Some(hmac_session), |ctx| {
+            ctx.policy_secret(
+                PolicySession::try_from(policy_session)
+                    .expect("Failed to convert auth session to policy session"),
+                AuthHandle::Endorsement,
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                None,
+            )
+        })
+        .unwrap();
+
+    context.set_sessions((Some(hmac_session), Some(policy_session), None));
+    let activated = context
+        .activate_credential(loaded_ak, ek, credential_blob, secret)
+        .expect("Failed to activate the credential");
+    context.clear_sessions();
+
+    assert_eq!(activated, Digest::try_from(credential.to_vec()).unwrap());
+
+    context
+        .flush_context(SessionHandle::from(hmac_session).into())
+        .unwrap();
+    context
+        .flush_context(SessionHandle::from(policy_session).into())
+        .unwrap();
+    context.flush_context(loaded_ak.into()).unwrap();
+    context.flush_context(ek.into()).unwrap();
+}
+
+#[test]
+fn test_make_credential_local_rsa_ek() {
+    let mut context = common::create_ctx_without_session();
+    make_and_activate_credential(&mut context, AsymmetricAlgorithm::Rsa);
+}
+
+#[test]
+fn test_make_credential_local_ecc_ek() {
+    let mut context = common::create_ctx_without_session();
+    make_and_activate_credential(&mut context, AsymmetricAlgorithm::Ecc);
+}
+
+#[test]
+fn test_make_credential_local_invalid_params() {
+    let ek_public = ek::create_ek_public_from_default_template(AsymmetricAlgorithm::Rsa, None)
+        .expect("Failed to create the EK template");
+    let name = Name::try_from(vec![0x00, 0x0b, 0xab, 0xcd]).unwrap();
+
+    if let Err(Error::WrapperError(kind)) = make_credential_local(&ek_public, &[0xab; 65], &name) {
+        assert_eq!(kind, WrapperErrorKind::WrongParamSize);
+    } else {
+        panic!("A credential larger than a digest should fail");
+    }
+
+    if let Err(Error::WrapperError(kind)) =
+        make_credential_local(&common::signing_key_pub(), &[0xab; 32], &name)
+    {
+        assert_eq!(kind, WrapperErrorKind::InvalidParam);
+    } else {
+        panic!("A signing key should not protect credentials");
+    }
+}
@@ -1,15 +1,26 @@
 // Copyright 2020 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
 
+use std::convert::TryFrom;
 use tss_esapi::{
     abstraction::ek,
+    attributes::NvIndexAttributesBuilder,
     constants::response_code::{FormatOneResponseCode, Tss2ResponseCode},
-    interface_types::algorithm::AsymmetricAlgorithm,
-    Error,
+    handles::NvIndexTpmHandle,
+    interface_types::{
+        algorithm::{AsymmetricAlgorithm, HashingAlgorithm, SymmetricMode},
+        ecc::EccCurve,
+        key_bits::{AesKeyBits, RsaKeyBits},
+        resource_handles::{NvAuth, Provision},
+    },
+    nv::storage::NvPublicBuilder,
+    structures::{MaxNvBuffer, Public, RsaExponent, SymmetricDefinitionObject},
+    tss2_esys::TPMA_OBJECT,
+    Error, WrapperErrorKind,
 };
 
 mod common;
-use common::create_ctx_without_session;
+use common::{create_ctx_with_session, create_ctx_without_session};
 
 #[test]
 fn test_retrieve_ek_pubcert() {
@@ -29,6 +40,73 @@ fn test_retrieve_ek_pubcert() {
     };
 }
 
+#[test]
+fn test_retrieve_ek_public_cert() {
+    let mut context = create_ctx_with_session();
+    let nv_index = NvIndexTpmHandle::new(0x01c00002).unwrap();
+
+    // Use the certificate provisioned in the TPM if there is one.
+    if let Ok(cert) = ek::retrieve_ek_public_cert(&mut context, AsymmetricAlgorithm::Rsa) {
+        assert_eq!(cert[0], 0x30);
+        return;
+    }
+
+    // Otherwise provision a DER blob larger than the NV buffer of the TPM.
+    let mut expected_cert = vec![0x30, 0x82, 0x05, 0x10];
+    expected_cert.extend((0..1296).map(|i| i as u8));
+
+    let nv_index_attributes = NvIndexAttributesBuilder::new()
+        .with_owner_write(true)
+        .with_auth_read(true)
+        .build()
+        .expect("Failed to create nv index attributes");
+    let nv_public = NvPublicBuilder::new()
+        .with_nv_index(nv_index)
+        .with_index_name_algorithm(HashingAlgorithm::Sha256)
+        .with_index_attributes(nv_index_attributes)
+        .with_data_area_size(expected_cert.len())
+        .build()
+        .expect("Failed to build nv public");
+    let nv_index_handle = context
+        .nv_define_space(Provision::Owner, None, &nv_public)
+        .expect("Call to nv_define_space failed");
+
+    for (i, chunk) in expected_cert.chunks(512).enumerate() {
+        context
+            .nv_write(
+                NvAuth::Owner,
+                nv_index_handle,
+                &MaxNvBuffer::try_from(chunk.to_vec()).unwrap(),
+                (i * 512) as u16,
+            )
+            .expect("Call to nv_write failed");
+    }
+
+    let cert = ek::retrieve_ek_public_cert(&mut context, AsymmetricAlgorithm::Rsa);
+
+    context
+        .nv_undefine_space(Provision::Owner, nv_index_handle)
+        .expect("Call to nv_undefine_space failed");
+
+    assert_eq!(
+        cert.expect("Failed to retrieve the EK certificate"),
+        expected_cert
+    );
+}
+
+#[test]
+fn test_retrieve_ek_public_cert_invalid_params() {
+    let mut context = create_ctx_without_session();
+
+    if let Err(Error::WrapperError(kind)) =
+        ek::retrieve_ek_public_cert(&mut context, AsymmetricAlgorithm::Null)
+    {
+        assert_eq!(kind, WrapperErrorKind::UnsupportedParam);
+    } else {
+        panic!("The Null algorithm should not be supported");
+    }
+}
+
 #[test]
 fn test_create_ek() {
     let mut context = create_ctx_without_session();
@@ -36,3 +114,116 @@ fn test_create_ek() {
     let _ = ek::create_ek_object(&mut context, AsymmetricAlgorithm::Rsa, None).unwrap();
     let _ = ek::create_ek_object(&mut context, AsymmetricAlgorithm::Ecc, None).unwrap();
 }
+
+// Source: TCG EK Credential Profile for TPM Family 2.0; Level 0 Version 2.3 Revision 2
+// Appendix B.3.3 and B.3.4
+const EK_OBJECT_ATTRIBUTES: TPMA_OBJECT = 0x000300b2;
+const EK_AUTH_POLICY: [u8; 32] = [
+    0x83, 0x71, 0x97, 0x67, 0x44, 0x84, 0xb3, 0xf8, 0x1a, 0x90, 0xcc, 0x8d, 0x46, 0xa5, 0xd7, 0x24,
+    0xfd, 0x52, 0xd7, 0x6e, 0x06, 0x52, 0x0b, 0x64, 0xf2, 0xa1, 0xda, 0x1b, 0x33, 0x14, 0x69, 0xaa,
+];
+
+#[test]
+fn test_rsa_ek_default_template() {
+    let public = ek::create_ek_public_from_default_template(AsymmetricAlgorithm::Rsa, None)
+        .expect("Failed to create the RSA EK template");
+    if let Public::Rsa {
+        object_attributes,
+        name_hashing_algorithm,
+        auth_policy,
+        parameters,
+        unique,
+    } = public
+    {
+        assert_eq!(TPMA_OBJECT::from(object_attributes), EK_OBJECT_ATTRIBUTES);
+        assert_eq!(name_hashing_algorithm, HashingAlgorithm::Sha256);
+        assert_eq!(auth_policy.value(), EK_AUTH_POLICY);
+        assert!(matches!(
+            parameters.symmetric_definition_object(),
+            SymmetricDefinitionObject::Aes {
+                key_bits: AesKeyBits::Aes128,
+                mode: SymmetricMode::Cfb,
+            }
+        ));
+        assert_eq!(parameters.key_bits(), RsaKeyBits::Rsa2048);
+        assert_eq!(parameters.exponent(), RsaExponent::default());
+        assert_eq!(unique.value(), [0u8; 256]);
+    } else {
+        panic!("The RSA EK template is not an RSA public area");
+    }
+}
+
+#[test]
+fn test_ecc_ek_default_template() {
+    let public = ek::create_ek_public_from_default_template(AsymmetricAlgorithm::Ecc, None)
+        .expect("Failed to create the ECC EK template");
+    if let Public::Ecc {
+        object_attributes,
+        name_hashing_algorithm,
+        auth_policy,
+        parameters,
+        unique,
+    } = public
+    {
+        assert_eq!(TPMA_OBJECT::from(object_attributes), EK_OBJECT_ATTRIBUTES);
+        assert_eq!(name_hashing_algorithm, HashingAlgorithm::Sha256);
+        assert_eq!(auth_policy.value(), EK_AUTH_POLICY);
+        assert!(matches!(
+            parameters.symmetric_definition_object(),
+            SymmetricDefinitionObject::Aes {
+                key_bits: AesKeyBits::Aes128,
+                mode: SymmetricMode::Cfb,
+            }
+        ));
+        assert_eq!(parameters.ecc_curve(), EccCurve::NistP256);
+        assert_eq!(unique.x().value(), [0u8; 32]);
+        assert_eq!(unique.y().value(), [0u8; 32]);
+    } else {
+        panic!("The ECC EK template is not an ECC public area");
+    }
+}
+
+#[test]
+fn test_ek_default_template_with_nonce() {
+    let nonce = [0xa5u8; 16];
+
+    let public = ek::create_ek_public_from_default_template(AsymmetricAlgorithm::Rsa, Some(&nonce))
+        .expect("Failed to create the RSA EK template with a nonce");
+    if let Public::Rsa { unique, .. } = public {
+        assert_eq!(unique.value().len(), 256);
+        assert_eq!(&unique.value()[..16], nonce);
+        assert!(unique.value()[16..].iter().all(|b| *b == 0));
+    } else {
+        panic!("The RSA EK template is not an RSA public area");
+    }
+
+    let public = ek::create_ek_public_from_default_template(AsymmetricAlgorithm::Ecc, Some(&nonce))
+        .expect("Failed to create the ECC EK template with a nonce");
+    if let Public::Ecc { unique, .. } = public {
+        assert_eq!(unique.x().value().len(), 32);
+        assert_eq!(&unique.x().value()[..16], nonce);
+        assert!(unique.x().value()[16..].iter().all(|b| *b == 0));
+        assert_eq!(unique.y().value(), [0u8; 32]);
+    } else {
+        panic!("The ECC EK template is not an ECC public area");
+    }
+}
+
+#[test]
+fn test_ek_default_template_invalid_params() {
+    if let Err(Error::WrapperError(kind)) =
+        ek::create_ek_public_from_default_template(AsymmetricAlgorithm::Ecc, Some(&[0xa5u8; 33]))
+    {
+        assert_eq!(kind, WrapperErrorKind::WrongParamSize);
+    } else {
+        panic!("A nonce larger than the ECC unique field should fail");
+    }
+
+    if let Err(Error::WrapperError(kind)) =
+        ek::create_ek_public_from_default_template(AsymmetricAlgorithm::Null, None)
+    {
+        assert_eq!(kind, WrapperErrorKind::UnsupportedParam);
+    } else {
+        panic!("The Null algorithm should not be supported");
+    }
+}
@@ -0,0 +1,59 @@
+// Copyright 2021 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+mod common;
+
+use tss_esapi::{
+    constants::{tss::TPM2_TRANSIENT_FIRST, CapabilityType},
+    handles::TpmHandle,
+    interface_types::resource_handles::Hierarchy,
+    structures::CapabilityData,
+    Context,
+};
+
+fn transient_handles(context: &mut Context) -> Vec<TpmHandle> {
+    match context
+        .get_capability(CapabilityType::Handles, TPM2_TRANSIENT_FIRST, 80)
+        .expect("Failed to get transient handles")
+        .0
+    {
+        CapabilityData::Handles(handles) => handles,
+        _ => panic!("Got wrong type of capability data"),
+    }
+}
+
+#[test]
+fn test_guarded_key_is_flushed() {
+    let mut context = common::create_ctx_with_session();
+    let prim_key_handle = context
+        .create_primary(
+            Hierarchy::Owner,
+            &common::decryption_key_pub(),
+            None,
+            None,
+            None,
+            None,
+        )
+        .expect("Failed to create primary key")
+        .key_handle;
+    let result = context
+        .create(
+            prim_key_handle,
+            &common::signing_key_pub(),
+            None,
+            None,
+            None,
+            None,
+        )
+        .expect("Failed to create key");
+    let handles_before = transient_handles(&mut context);
+
+    {
+        let mut guard = context
+            .load_guarded(prim_key_handle, result.out_private, &result.out_public)
+            .expect("Failed to load key");
+        let handles_loaded = transient_handles(guard.context());
+        assert_eq!(handles_before.len() + 1, handles_loaded.len());
+    }
+
+    assert_eq!(handles_before, transient_handles(&mut context));
+}
@@ -0,0 +1,30 @@
+// Copyright 2021 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+#![cfg(feature = "rng")]
+mod common;
+
+use rand_core::RngCore;
+use tss_esapi::abstraction::rng::TpmRng;
+
+#[test]
+fn test_fill_bytes() {
+    let mut context = common::create_ctx_without_session();
+    let mut rng = TpmRng::new(&mut context);
+    // Larger than the amount of random bytes returned by a single call.
+    let mut random = vec![0u8; 1024];
+    rng.fill_bytes(&mut random);
+    for (block, next_block) in random.chunks(32).zip(random.chunks(32).skip(1)) {
+        assert_ne!(block, next_block);
+    }
+}
+
+#[test]
+fn test_try_fill_bytes() {
+    let mut context = common::create_ctx_without_session();
+    let mut rng = TpmRng::new(&mut context);
+    let mut random = [0u8; 100];
+    rng.try_fill_bytes(&mut random)
+        .expect("Failed to get random bytes");
+    assert_ne!([0u8; 100], random);
+    assert_ne!(rng.next_u64(), rng.next_u64());
+}
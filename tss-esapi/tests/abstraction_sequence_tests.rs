@@ -0,0 +1,110 @@
+// Copyright 2021 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use sha2::{Digest as _, Sha256};
+use std::convert::TryFrom;
+use tss_esapi::{
+    abstraction::{HashSequence, HmacSequence},
+    attributes::ObjectAttributesBuilder,
+    interface_types::{
+        algorithm::{HashingAlgorithm, PublicAlgorithm},
+        resource_handles::Hierarchy,
+    },
+    structures::{KeyedHashScheme, MaxBuffer, PublicBuilder, PublicKeyedHashParameters},
+};
+
+mod common;
+use common::create_ctx_with_session;
+
+#[test]
+fn test_hash_sequence_large_input() {
+    let mut context = create_ctx_with_session();
+    let data: Vec<u8> = (0..10 * 1024 * 1024).map(|i| (i % 251) as u8).collect();
+
+    let mut sequence = HashSequence::new(&mut context, HashingAlgorithm::Sha256, Hierarchy::Owner)
+        .expect("Failed to start the hash sequence");
+    // Parts that are not aligned on the size of the TPM buffers.
+    for part in data.chunks(4099) {
+        sequence
+            .update(part)
+            .expect("Failed to update the hash sequence");
+    }
+    let (digest, ticket) = sequence
+        .finish()
+        .expect("Failed to finish the hash sequence");
+
+    assert_eq!(digest.value(), &Sha256::digest(&data)[..]);
+    assert!(ticket.is_some());
+}
+
+#[test]
+fn test_hash_sequence_empty_input() {
+    let mut context = create_ctx_with_session();
+
+    let sequence = HashSequence::new(&mut context, HashingAlgorithm::Sha256, Hierarchy::Owner)
+        .expect("Failed to start the hash sequence");
+    let (digest, _) = sequence
+        .finish()
+        .expect("Failed to finish the hash sequence");
+
+    assert_eq!(digest.value(), &Sha256::digest([])[..]);
+}
+
+#[test]
+fn test_hmac_sequence() {
+    let mut context = create_ctx_with_session();
+
+    let object_attributes = ObjectAttributesBuilder::new()
+        .with_sign_encrypt(true)
+        .with_sensitive_data_origin(true)
+        .with_user_with_auth(true)
+        .build()
+        .expect("Failed to build object attributes");
+    let key_pub = PublicBuilder::new()
+        .with_public_algorithm(PublicAlgorithm::KeyedHash)
+        .with_name_hashing_algorithm(HashingAlgorithm::Sha256)
+        .with_object_attributes(object_attributes)
+        .with_keyed_hash_parameters(PublicKeyedHashParameters::new(
+            KeyedHashScheme::HMAC_SHA_256,
+        ))
+        .with_keyed_hash_unique_identifier(&Default::default())
+        .build()
+        .expect("Failed to build public strucuture for key.");
+    let key_handle = context
+        .create_primary(Hierarchy::Owner, &key_pub, None, None, None, None)
+        .expect("Failed to create HMAC key")
+        .key_handle;
+
+    let data: Vec<u8> = (0..3000).map(|i| (i % 251) as u8).collect();
+    let one_shot_mac = context
+        .mac(
+            key_handle,
+            MaxBuffer::try_from(data[..1000].to_vec()).expect("Failed to create MaxBuffer"),
+            HashingAlgorithm::Sha256,
+        )
+        .expect("Failed to compute MAC");
+
+    let mut sequence = HmacSequence::new(&mut context, key_handle, HashingAlgorithm::Sha256)
+        .expect("Failed to start the HMAC sequence");
+    for part in data[..1000].chunks(300) {
+        sequence
+            .update(part)
+            .expect("Failed to update the HMAC sequence");
+    }
+    let (mac, ticket) = sequence
+        .finish()
+        .expect("Failed to finish the HMAC sequence");
+    assert_eq!(one_shot_mac, mac);
+    assert!(ticket.is_none());
+
+    // A sequence dropped before being finished is flushed.
+    let mut sequence = HmacSequence::new(&mut context, key_handle, HashingAlgorithm::Sha256)
+        .expect("Failed to start the HMAC sequence");
+    sequence
+        .update(&data)
+        .expect("Failed to update the HMAC sequence");
+    drop(sequence);
+
+    context
+        .flush_context(key_handle.into())
+        .expect("Failed to flush the HMAC key");
+}
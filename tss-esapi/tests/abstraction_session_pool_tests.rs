@@ -0,0 +1,132 @@
+// Copyright 2021 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+mod common;
+
+use std::convert::TryFrom;
+use tss_esapi::{
+    abstraction::SessionPool,
+    constants::{tss::TPM2_LOADED_SESSION_FIRST, CapabilityType},
+    interface_types::{algorithm::HashingAlgorithm, resource_handles::Hierarchy},
+    structures::{CapabilityData, MaxBuffer, SymmetricDefinition},
+    Context, Error, WrapperErrorKind,
+};
+
+fn session_pool(max_size: usize) -> SessionPool {
+    SessionPool::new(
+        SymmetricDefinition::AES_256_CFB,
+        HashingAlgorithm::Sha256,
+        max_size,
+    )
+}
+
+fn loaded_sessions(context: &mut Context) -> usize {
+    match context
+        .get_capability(CapabilityType::Handles, TPM2_LOADED_SESSION_FIRST, 80)
+        .expect("Failed to get loaded sessions")
+        .0
+    {
+        CapabilityData::Handles(handles) => handles.len(),
+        _ => panic!("Got wrong type of capability data"),
+    }
+}
+
+#[test]
+fn test_session_reuse() {
+    let mut context = common::create_ctx_without_session();
+    let pool = session_pool(2);
+
+    let first_session = {
+        let pooled = pool
+            .acquire(&mut context)
+            .expect("Failed to acquire session");
+        // Use the session for a command.
+        context.set_sessions((Some(pooled.session()), None, None));
+        let _ = context
+            .hash(
+                &MaxBuffer::try_from(vec![1, 2, 3]).unwrap(),
+                HashingAlgorithm::Sha256,
+                Hierarchy::Owner,
+            )
+            .expect("Failed to hash data in pooled session");
+        context.clear_sessions();
+        pooled.session()
+    };
+    assert_eq!(1, pool.idle_sessions());
+
+    let pooled = pool
+        .acquire(&mut context)
+        .expect("Failed to acquire session");
+    assert_eq!(first_session, pooled.session());
+    assert_eq!(0, pool.idle_sessions());
+    drop(pooled);
+
+    pool.flush(&mut context).expect("Failed to flush pool");
+    assert_eq!(0, pool.idle_sessions());
+}
+
+#[test]
+fn test_pool_size_is_capped() {
+    let mut context = common::create_ctx_without_session();
+    let pool = session_pool(2);
+
+    for _ in 0..3 {
+        // Acquire more sessions than the pool can hold at once.
+        let acquired = (0..4)
+            .map(|_| {
+                pool.acquire(&mut context)
+                    .expect("Failed to acquire session")
+            })
+            .collect::<Vec<_>>();
+        assert!(pool.idle_sessions() <= pool.max_size());
+        drop(acquired);
+        assert_eq!(pool.max_size(), pool.idle_sessions());
+    }
+
+    pool.flush(&mut context).expect("Failed to flush pool");
+    assert_eq!(0, pool.idle_sessions());
+}
+
+#[test]
+fn test_evicted_session_is_flushed_on_release() {
+    let mut context = common::create_ctx_without_session();
+    let pool = session_pool(1);
+    let loaded_before = loaded_sessions(&mut context);
+
+    let first = pool
+        .acquire(&mut context)
+        .expect("Failed to acquire session");
+    let second = pool
+        .acquire(&mut context)
+        .expect("Failed to acquire session");
+    assert_eq!(loaded_before + 2, loaded_sessions(&mut context));
+
+    pool.release(&mut context, first)
+        .expect("Failed to release session");
+    pool.release(&mut context, second)
+        .expect("Failed to release session");
+    // The oldest session is evicted and flushed straight away.
+    assert_eq!(1, pool.idle_sessions());
+    assert_eq!(loaded_before + 1, loaded_sessions(&mut context));
+
+    pool.flush(&mut context).expect("Failed to flush pool");
+    assert_eq!(loaded_before, loaded_sessions(&mut context));
+}
+
+#[test]
+fn test_release_to_another_pool() {
+    let mut context = common::create_ctx_without_session();
+    let pool = session_pool(1);
+    let other_pool = session_pool(1);
+
+    let pooled = pool
+        .acquire(&mut context)
+        .expect("Failed to acquire session");
+    match other_pool.release(&mut context, pooled) {
+        Err(Error::WrapperError(kind)) => assert_eq!(kind, WrapperErrorKind::InvalidParam),
+        other => panic!("Unexpected result: {:?}", other),
+    }
+    assert_eq!(0, other_pool.idle_sessions());
+    assert_eq!(1, pool.idle_sessions());
+
+    pool.flush(&mut context).expect("Failed to flush pool");
+}
@@ -0,0 +1,93 @@
+// Copyright 2021 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+#![cfg(feature = "ecdsa-signer")]
+mod common;
+
+use p256::ecdsa::{
+    signature::{Signer, Verifier},
+    Signature, VerifyingKey,
+};
+use tss_esapi::{
+    abstraction::signer::TpmEcdsaSigner,
+    interface_types::{
+        algorithm::{EccSchemeAlgorithm, HashingAlgorithm},
+        ecc::EccCurve,
+        resource_handles::Hierarchy,
+    },
+    structures::{EccScheme, Public},
+    utils, Error, WrapperErrorKind,
+};
+
+const MESSAGE: &[u8] = b"Message signed by the TPM";
+
+fn ecc_signing_key_pub() -> Public {
+    utils::create_unrestricted_signing_ecc_public(
+        EccScheme::create(
+            EccSchemeAlgorithm::EcDsa,
+            Some(HashingAlgorithm::Sha256),
+            None,
+        )
+        .expect("Failed to create ECC scheme"),
+        EccCurve::NistP256,
+    )
+    .expect("Failed to create ECC public structure")
+}
+
+#[test]
+fn test_ecdsa_signer() {
+    let mut context = common::create_ctx_with_session();
+    let key = context
+        .create_primary(
+            Hierarchy::Owner,
+            &ecc_signing_key_pub(),
+            None,
+            None,
+            None,
+            None,
+        )
+        .expect("Failed to create primary key");
+
+    let mut sec1_point = vec![0x04];
+    match key.out_public {
+        Public::Ecc { unique, .. } => {
+            for coordinate in &[unique.x().value(), unique.y().value()] {
+                sec1_point.resize(sec1_point.len() + 32 - coordinate.len(), 0);
+                sec1_point.extend_from_slice(coordinate);
+            }
+        }
+        _ => panic!("Got wrong type of key"),
+    }
+    let verifying_key =
+        VerifyingKey::from_sec1_bytes(&sec1_point).expect("Failed to create verifying key");
+
+    let signer =
+        TpmEcdsaSigner::new(&mut context, key.key_handle).expect("Failed to create signer");
+    let signature: Signature = signer.sign(MESSAGE);
+    verifying_key
+        .verify(MESSAGE, &signature)
+        .expect("Failed to verify the signature");
+    verifying_key
+        .verify(b"Another message", &signature)
+        .expect_err("Verified the signature of the wrong message");
+}
+
+#[test]
+fn test_ecdsa_signer_rsa_key() {
+    let mut context = common::create_ctx_with_session();
+    let key_handle = context
+        .create_primary(
+            Hierarchy::Owner,
+            &common::signing_key_pub(),
+            None,
+            None,
+            None,
+            None,
+        )
+        .expect("Failed to create primary key")
+        .key_handle;
+    if let Err(Error::WrapperError(kind)) = TpmEcdsaSigner::new(&mut context, key_handle) {
+        assert_eq!(kind, WrapperErrorKind::InvalidParam);
+    } else {
+        panic!("Created an ECDSA signer with an RSA key");
+    }
+}
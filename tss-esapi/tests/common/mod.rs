@@ -142,6 +142,25 @@ pub fn signing_key_pub() -> Public {
     .expect("Failed to create an unrestricted signing rsa public structure")
 }
 
+#[allow(dead_code)]
+pub fn start_policy_session(context: &mut Context, session_type: SessionType) -> PolicySession {
+    let policy_auth_session = context
+        .execute_without_session(|ctx| {
+            ctx.start_auth_session(
+                None,
+                None,
+                None,
+                session_type,
+                SymmetricDefinition::AES_256_CFB,
+                HashingAlgorithm::Sha256,
+            )
+        })
+        .expect("Start auth session failed")
+        .expect("Start auth session returned a NONE handle");
+    PolicySession::try_from(policy_auth_session)
+        .expect("Failed to convert auth session into policy session")
+}
+
 #[allow(dead_code)]
 pub fn get_pcr_policy_digest(
     context: &mut Context,
@@ -0,0 +1,36 @@
+// Copyright 2021 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+mod test_command_code {
+    use std::convert::TryFrom;
+    use tss_esapi::{
+        constants::{
+            tss::{TPM2_CC_ACT_SetTimeout, TPM2_CC_LAST},
+            CommandCode,
+        },
+        Error, WrapperErrorKind,
+    };
+
+    #[test]
+    fn test_act_set_timeout_conversion() {
+        assert_eq!(TPM2_CC_ACT_SetTimeout, CommandCode::ActSetTimeout.into());
+        assert_eq!(
+            CommandCode::ActSetTimeout,
+            CommandCode::try_from(TPM2_CC_ACT_SetTimeout)
+                .expect("Failed to convert TPM2_CC_ACT_SetTimeout")
+        );
+    }
+
+    #[test]
+    fn test_last_command_code() {
+        assert_eq!(TPM2_CC_ACT_SetTimeout, TPM2_CC_LAST);
+    }
+
+    #[test]
+    fn test_invalid_command_code() {
+        // 0x197 is not assigned to any command.
+        match CommandCode::try_from(0x0000_0197) {
+            Err(Error::WrapperError(kind)) => assert_eq!(kind, WrapperErrorKind::InvalidParam),
+            other => panic!("Unexpected result: {:?}", other),
+        }
+    }
+}
@@ -0,0 +1,143 @@
+// Copyright 2021 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+mod test_tpm_response_code {
+    use crate::common::{create_ctx_with_session, decryption_key_pub, signing_key_pub};
+    use std::convert::{TryFrom, TryInto};
+    use tss_esapi::{
+        constants::{
+            response_code::{TpmResponseCode, Tss2ResponseCode},
+            tss::{
+                TPM2_ALG_NULL, TPM2_PT_LOCKOUT_INTERVAL, TPM2_PT_LOCKOUT_RECOVERY,
+                TPM2_PT_MAX_AUTH_FAIL, TPM2_RH_NULL, TPM2_ST_HASHCHECK,
+            },
+            CapabilityType,
+        },
+        interface_types::resource_handles::{Hierarchy, Lockout, Provision},
+        structures::{Auth, CapabilityData, Digest},
+        tss2_esys::{TPM2_PT, TPMT_SIG_SCHEME, TPMT_TK_HASHCHECK},
+        Context, Error, WrapperErrorKind,
+    };
+
+    fn read_property(context: &mut Context, property: TPM2_PT) -> u32 {
+        let (capability_data, _) = context
+            .execute_without_session(|ctx| {
+                ctx.get_capability(CapabilityType::TPMProperties, property, 1)
+            })
+            .expect("Failed to get TPM properties");
+        match capability_data {
+            CapabilityData::TPMProperties(properties) => *properties
+                .get(&property)
+                .expect("The TPM did not return the property"),
+            _ => panic!("Received unexpected capability data"),
+        }
+    }
+
+    #[test]
+    fn test_wrapper_error() {
+        let error = Error::WrapperError(WrapperErrorKind::InvalidParam);
+        assert_eq!(error.tpm_response_code(), None);
+    }
+
+    #[test]
+    fn test_unknown_response_code() {
+        // Format-one response code with the unassigned error number 0x006.
+        let unknown_rc = 0x086;
+        let error = Error::Tss2Error(Tss2ResponseCode::from(unknown_rc));
+        assert_eq!(
+            error.tpm_response_code(),
+            Some(TpmResponseCode::Unknown(unknown_rc))
+        );
+    }
+
+    #[test]
+    fn test_auth_fail() {
+        let mut context = create_ctx_with_session();
+        let key_auth = Auth::try_from(vec![1, 2, 3, 4]).unwrap();
+
+        let prim_key_handle = context
+            .create_primary(
+                Hierarchy::Owner,
+                &decryption_key_pub(),
+                None,
+                None,
+                None,
+                None,
+            )
+            .unwrap()
+            .key_handle;
+        let result = context
+            .create(
+                prim_key_handle,
+                &signing_key_pub(),
+                Some(&key_auth),
+                None,
+                None,
+                None,
+            )
+            .unwrap();
+        let key_handle = context
+            .load(prim_key_handle, result.out_private, &result.out_public)
+            .unwrap();
+
+        // The key is subject to dictionary attack protection, so
+        // using the wrong auth fails with TPM_RC_AUTH_FAIL.
+        context
+            .tr_set_auth(
+                key_handle.into(),
+                &Auth::try_from(vec![5, 6, 7, 8]).unwrap(),
+            )
+            .expect("Failed to set the wrong auth");
+        let error = context
+            .sign(
+                key_handle,
+                &Digest::try_from(vec![0xab; 32]).unwrap(),
+                TPMT_SIG_SCHEME {
+                    scheme: TPM2_ALG_NULL,
+                    details: Default::default(),
+                },
+                TPMT_TK_HASHCHECK {
+                    tag: TPM2_ST_HASHCHECK,
+                    hierarchy: TPM2_RH_NULL,
+                    digest: Default::default(),
+                }
+                .try_into()
+                .unwrap(),
+            )
+            .expect_err("Signing with the wrong auth should fail");
+
+        // Setting the dictionary attack parameters resets the failed
+        // tries counter that was incremented above.
+        let max_tries = read_property(&mut context, TPM2_PT_MAX_AUTH_FAIL);
+        let recovery_time = read_property(&mut context, TPM2_PT_LOCKOUT_INTERVAL);
+        let lockout_recovery = read_property(&mut context, TPM2_PT_LOCKOUT_RECOVERY);
+        context
+            .dictionary_attack_parameters(
+                Lockout::Lockout,
+                max_tries,
+                recovery_time,
+                lockout_recovery,
+            )
+            .expect("Failed to reset the dictionary attack counter");
+        context.flush_context(key_handle.into()).unwrap();
+        context.flush_context(prim_key_handle.into()).unwrap();
+
+        assert_eq!(error.tpm_response_code(), Some(TpmResponseCode::AuthFail));
+    }
+
+    #[test]
+    fn test_value() {
+        let mut context = create_ctx_with_session();
+        let current_clock = context
+            .read_clock()
+            .expect("Failed to read clock")
+            .clock_info()
+            .clock();
+        context
+            .clock_set(Provision::Owner, current_clock + 1000)
+            .expect("Failed to set clock");
+        let error = context
+            .clock_set(Provision::Owner, current_clock)
+            .expect_err("Setting the clock backwards should fail");
+        assert_eq!(error.tpm_response_code(), Some(TpmResponseCode::Value));
+    }
+}
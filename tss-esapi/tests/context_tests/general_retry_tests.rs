@@ -0,0 +1,73 @@
+// Copyright 2021 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+mod test_execute_with_retry {
+    use crate::common::create_ctx_without_session;
+    use tss_esapi::{
+        constants::{
+            tss::{TPM2_RC_RETRY, TPM2_RC_VALUE},
+            Tss2ResponseCode,
+        },
+        Error, WrapperErrorKind,
+    };
+
+    #[test]
+    fn test_retry_until_success() {
+        let mut context = create_ctx_without_session();
+        let mut invocations = 0;
+        let random = context
+            .execute_with_retry(5, |ctx| {
+                invocations += 1;
+                if invocations <= 2 {
+                    Err(Error::Tss2Error(Tss2ResponseCode::from(TPM2_RC_RETRY)))
+                } else {
+                    ctx.get_random(16)
+                }
+            })
+            .expect("Call to execute_with_retry failed");
+        assert_eq!(invocations, 3);
+        assert_eq!(random.len(), 16);
+    }
+
+    #[test]
+    fn test_retry_gives_up() {
+        let mut context = create_ctx_without_session();
+        let mut invocations = 0;
+        let error = context
+            .execute_with_retry(3, |_| -> tss_esapi::Result<()> {
+                invocations += 1;
+                Err(Error::Tss2Error(Tss2ResponseCode::from(TPM2_RC_RETRY)))
+            })
+            .expect_err("All the attempts should have failed");
+        assert_eq!(invocations, 3);
+        assert!(error.is_retryable());
+    }
+
+    #[test]
+    fn test_no_retry_on_error() {
+        let mut context = create_ctx_without_session();
+        let mut invocations = 0;
+        let error = context
+            .execute_with_retry(3, |_| -> tss_esapi::Result<()> {
+                invocations += 1;
+                Err(Error::Tss2Error(Tss2ResponseCode::from(TPM2_RC_VALUE)))
+            })
+            .expect_err("The attempt should have failed");
+        assert_eq!(invocations, 1);
+        assert_eq!(
+            error,
+            Error::Tss2Error(Tss2ResponseCode::from(TPM2_RC_VALUE))
+        );
+    }
+
+    #[test]
+    fn test_zero_attempts() {
+        let mut context = create_ctx_without_session();
+        if let Err(Error::WrapperError(kind)) =
+            context.execute_with_retry(0, |_| -> tss_esapi::Result<()> { Ok(()) })
+        {
+            assert_eq!(kind, WrapperErrorKind::InvalidParam);
+        } else {
+            panic!("Executing the closure zero times should fail");
+        }
+    }
+}
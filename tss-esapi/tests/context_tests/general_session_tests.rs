@@ -0,0 +1,43 @@
+mod test_execute_with_sessions {
+    use crate::common::create_ctx_with_session;
+    use std::panic::{self, AssertUnwindSafe};
+    use tss_esapi::interface_types::session_handles::AuthSession;
+
+    #[test]
+    fn test_sessions_restored_after_closure() {
+        let mut context = create_ctx_with_session();
+        let original_sessions = context.sessions();
+
+        let sessions_in_closure = context.execute_with_sessions(
+            (
+                Some(AuthSession::Password),
+                None,
+                Some(AuthSession::Password),
+            ),
+            |ctx| ctx.sessions(),
+        );
+        assert_eq!(
+            (
+                Some(AuthSession::Password),
+                None,
+                Some(AuthSession::Password)
+            ),
+            sessions_in_closure
+        );
+        assert_eq!(original_sessions, context.sessions());
+    }
+
+    #[test]
+    fn test_sessions_restored_after_panic() {
+        let mut context = create_ctx_with_session();
+        let original_sessions = context.sessions();
+
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            context.execute_with_sessions((None, None, None), |_| {
+                panic!("Panicking inside the closure");
+            })
+        }));
+        assert!(result.is_err());
+        assert_eq!(original_sessions, context.sessions());
+    }
+}
@@ -1,4 +1,7 @@
 #[path = "../common/mod.rs"]
 mod common;
+mod general_error_tests;
 mod general_esys_tr_tests;
+mod general_retry_tests;
+mod general_session_tests;
 mod tpm_commands;
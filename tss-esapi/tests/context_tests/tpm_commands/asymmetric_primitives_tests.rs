@@ -104,3 +104,500 @@ mod test_rsa_encrypt_decrypt {
         assert_eq!(z_point.x().value(), param.x().value());
     }
 }
+
+mod test_rsa_oaep {
+    use crate::common::{create_ctx_with_session, encryption_decryption_key_pub};
+    use rsa::{rand_core::OsRng, BigUint, Oaep, RsaPublicKey};
+    use sha2::Sha256;
+    use std::convert::TryFrom;
+    use tss_esapi::{
+        handles::KeyHandle,
+        interface_types::{algorithm::HashingAlgorithm, resource_handles::Hierarchy},
+        structures::{Public, PublicKeyRsa, RsaExponent},
+        Context, Error, WrapperErrorKind,
+    };
+
+    const MESSAGE: &[u8] = b"Message encrypted with RSA-OAEP";
+
+    fn create_key(context: &mut Context) -> (KeyHandle, RsaPublicKey) {
+        let key = context
+            .create_primary(
+                Hierarchy::Owner,
+                &encryption_decryption_key_pub(),
+                None,
+                None,
+                None,
+                None,
+            )
+            .unwrap();
+        let public_key = match key.out_public {
+            Public::Rsa { unique, .. } => RsaPublicKey::new(
+                BigUint::from_bytes_be(unique.value()),
+                BigUint::from(RsaExponent::default().value()),
+            )
+            .expect("Failed to create RSA public key"),
+            _ => panic!("Got wrong type of key"),
+        };
+        (key.key_handle, public_key)
+    }
+
+    #[test]
+    fn test_rsa_oaep_decrypt_with_label() {
+        let mut context = create_ctx_with_session();
+        let (key_handle, public_key) = create_key(&mut context);
+
+        // The TPM includes the terminating null byte in the label.
+        let cipher_text = public_key
+            .encrypt(
+                &mut OsRng,
+                Oaep::new_with_label::<Sha256, _>("label\0"),
+                MESSAGE,
+            )
+            .expect("Failed to encrypt the message");
+        let decrypted = context
+            .rsa_oaep_decrypt(
+                key_handle,
+                PublicKeyRsa::try_from(cipher_text).unwrap(),
+                HashingAlgorithm::Sha256,
+                Some(b"label"),
+            )
+            .expect("Call to rsa_oaep_decrypt failed");
+        assert_eq!(MESSAGE, decrypted.value());
+    }
+
+    #[test]
+    fn test_rsa_oaep_decrypt_without_label() {
+        let mut context = create_ctx_with_session();
+        let (key_handle, public_key) = create_key(&mut context);
+
+        let cipher_text = public_key
+            .encrypt(&mut OsRng, Oaep::new::<Sha256>(), MESSAGE)
+            .expect("Failed to encrypt the message");
+        let decrypted = context
+            .rsa_oaep_decrypt(
+                key_handle,
+                PublicKeyRsa::try_from(cipher_text).unwrap(),
+                HashingAlgorithm::Sha256,
+                None,
+            )
+            .expect("Call to rsa_oaep_decrypt failed");
+        assert_eq!(MESSAGE, decrypted.value());
+    }
+
+    #[test]
+    fn test_rsa_oaep_round_trip() {
+        let mut context = create_ctx_with_session();
+        let (key_handle, _) = create_key(&mut context);
+
+        for label in [None, Some(&b"label"[..]), Some(&b"label\0"[..])] {
+            let cipher_text = context
+                .rsa_oaep_encrypt(
+                    key_handle,
+                    PublicKeyRsa::try_from(MESSAGE).unwrap(),
+                    HashingAlgorithm::Sha256,
+                    label,
+                )
+                .expect("Call to rsa_oaep_encrypt failed");
+            assert_ne!(MESSAGE, cipher_text.value());
+            let decrypted = context
+                .rsa_oaep_decrypt(key_handle, cipher_text, HashingAlgorithm::Sha256, label)
+                .expect("Call to rsa_oaep_decrypt failed");
+            assert_eq!(MESSAGE, decrypted.value());
+        }
+    }
+
+    #[test]
+    fn test_rsa_oaep_encrypt_label_mismatch() {
+        let mut context = create_ctx_with_session();
+        let (key_handle, _) = create_key(&mut context);
+
+        let cipher_text = context
+            .rsa_oaep_encrypt(
+                key_handle,
+                PublicKeyRsa::try_from(MESSAGE).unwrap(),
+                HashingAlgorithm::Sha256,
+                Some(b"label"),
+            )
+            .expect("Call to rsa_oaep_encrypt failed");
+        let _ = context
+            .rsa_oaep_decrypt(
+                key_handle,
+                cipher_text,
+                HashingAlgorithm::Sha256,
+                Some(b"other label"),
+            )
+            .expect_err("Decrypting with another label should fail");
+    }
+
+    #[test]
+    fn test_rsa_oaep_decrypt_label_too_large() {
+        let mut context = create_ctx_with_session();
+        let (key_handle, _) = create_key(&mut context);
+
+        // The terminating null byte does not fit in the label.
+        if let Err(Error::WrapperError(kind)) = context.rsa_oaep_decrypt(
+            key_handle,
+            PublicKeyRsa::try_from(vec![0xab; 256]).unwrap(),
+            HashingAlgorithm::Sha256,
+            Some(&[0x61; 64]),
+        ) {
+            assert_eq!(kind, WrapperErrorKind::WrongParamSize);
+        } else {
+            panic!("Decrypting with a label that is too large should fail");
+        }
+    }
+
+    #[test]
+    fn test_rsa_oaep_decrypt_message_too_large() {
+        let mut context = create_ctx_with_session();
+        let (key_handle, public_key) = create_key(&mut context);
+
+        // The decrypted message does not fit in a Data buffer.
+        let cipher_text = public_key
+            .encrypt(&mut OsRng, Oaep::new::<Sha256>(), &[0xab; 65])
+            .expect("Failed to encrypt the message");
+        if let Err(Error::WrapperError(kind)) = context.rsa_oaep_decrypt(
+            key_handle,
+            PublicKeyRsa::try_from(cipher_text).unwrap(),
+            HashingAlgorithm::Sha256,
+            None,
+        ) {
+            assert_eq!(kind, WrapperErrorKind::WrongParamSize);
+        } else {
+            panic!("Decrypting a message that is too large should fail");
+        }
+    }
+}
+
+mod test_ecdh_key_gen {
+    use crate::common::create_ctx_with_session;
+    use std::convert::TryFrom;
+    use tss_esapi::{
+        attributes::ObjectAttributesBuilder,
+        interface_types::{
+            algorithm::{HashingAlgorithm, PublicAlgorithm},
+            ecc::EccCurve,
+            resource_handles::Hierarchy,
+        },
+        structures::{
+            Auth, EccPoint, EccScheme, HashScheme, KeyDerivationFunctionScheme, Public,
+            PublicBuilder, PublicEccParametersBuilder,
+        },
+    };
+
+    fn ecdh_p256_public(tpm_resident: bool, unique: &EccPoint) -> Public {
+        let ecc_parms = PublicEccParametersBuilder::new()
+            .with_ecc_scheme(EccScheme::EcDh(HashScheme::new(HashingAlgorithm::Sha256)))
+            .with_curve(EccCurve::NistP256)
+            .with_is_signing_key(false)
+            .with_is_decryption_key(true)
+            .with_restricted(false)
+            .with_key_derivation_function_scheme(KeyDerivationFunctionScheme::Null)
+            .build()
+            .unwrap();
+
+        let object_attributes = ObjectAttributesBuilder::new()
+            .with_fixed_tpm(tpm_resident)
+            .with_fixed_parent(tpm_resident)
+            .with_sensitive_data_origin(tpm_resident)
+            .with_user_with_auth(true)
+            .with_decrypt(true)
+            .with_sign_encrypt(false)
+            .with_restricted(false)
+            .build()
+            .unwrap();
+
+        PublicBuilder::new()
+            .with_public_algorithm(PublicAlgorithm::Ecc)
+            .with_name_hashing_algorithm(HashingAlgorithm::Sha256)
+            .with_object_attributes(object_attributes)
+            .with_ecc_parameters(ecc_parms)
+            .with_ecc_unique_identifier(unique)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_ecdh_key_gen() {
+        let mut context = create_ctx_with_session();
+        let random_digest = context.get_random(16).unwrap();
+        let key_auth = Auth::try_from(random_digest.value().to_vec()).unwrap();
+
+        let key_handle = context
+            .create_primary(
+                Hierarchy::Owner,
+                &ecdh_p256_public(true, &EccPoint::default()),
+                Some(&key_auth),
+                None,
+                None,
+                None,
+            )
+            .unwrap()
+            .key_handle;
+
+        let (z_point, pub_point) = context
+            .ecdh_key_gen(key_handle)
+            .expect("Failed to generate ECDH key pair");
+
+        // Both points should be P-256 points, i.e. have 32 byte coordinates.
+        for point in [&z_point, &pub_point].iter() {
+            assert_eq!(point.x().value().len(), 32);
+            assert_eq!(point.y().value().len(), 32);
+        }
+
+        // The TPM validates that a public ECC point lies on the curve
+        // when it is loaded, so loading them as external public keys
+        // verifies that both points are on the curve.
+        for point in [z_point, pub_point].iter() {
+            let external_key_handle = context
+                .load_external_public(&ecdh_p256_public(false, point), Hierarchy::Null)
+                .expect("Point returned from ecdh_key_gen is not on the curve");
+            context
+                .flush_context(external_key_handle.into())
+                .expect("Failed to flush external key");
+        }
+    }
+}
+
+mod test_ecdh_z_gen {
+    use crate::common::create_ctx_with_session;
+    use std::convert::TryFrom;
+    use tss_esapi::{
+        attributes::ObjectAttributesBuilder,
+        handles::KeyHandle,
+        interface_types::{
+            algorithm::{HashingAlgorithm, PublicAlgorithm},
+            ecc::EccCurve,
+            resource_handles::Hierarchy,
+        },
+        structures::{
+            EccParameter, EccPoint, EccScheme, HashScheme, KeyDerivationFunctionScheme, Public,
+            PublicBuilder, PublicEccParametersBuilder,
+        },
+        Context, Error, WrapperErrorKind,
+    };
+
+    fn create_ecdh_key(context: &mut Context, curve: EccCurve) -> (KeyHandle, EccPoint) {
+        let ecc_parms = PublicEccParametersBuilder::new()
+            .with_ecc_scheme(EccScheme::EcDh(HashScheme::new(HashingAlgorithm::Sha256)))
+            .with_curve(curve)
+            .with_is_signing_key(false)
+            .with_is_decryption_key(true)
+            .with_restricted(false)
+            .with_key_derivation_function_scheme(KeyDerivationFunctionScheme::Null)
+            .build()
+            .unwrap();
+
+        let object_attributes = ObjectAttributesBuilder::new()
+            .with_fixed_tpm(true)
+            .with_fixed_parent(true)
+            .with_sensitive_data_origin(true)
+            .with_user_with_auth(true)
+            .with_decrypt(true)
+            .with_sign_encrypt(false)
+            .with_restricted(false)
+            .build()
+            .unwrap();
+
+        let public = PublicBuilder::new()
+            .with_public_algorithm(PublicAlgorithm::Ecc)
+            .with_name_hashing_algorithm(HashingAlgorithm::Sha256)
+            .with_object_attributes(object_attributes)
+            .with_ecc_parameters(ecc_parms)
+            .with_ecc_unique_identifier(&EccPoint::default())
+            .build()
+            .unwrap();
+
+        let result = context
+            .create_primary(Hierarchy::Owner, &public, None, None, None, None)
+            .unwrap();
+
+        match result.out_public {
+            Public::Ecc { unique, .. } => (result.key_handle, unique),
+            _ => panic!("Created key is not an ECC key"),
+        }
+    }
+
+    #[test]
+    fn test_ecdh_between_two_keys() {
+        let mut context = create_ctx_with_session();
+        let (key_handle_a, public_point_a) = create_ecdh_key(&mut context, EccCurve::NistP256);
+        let (key_handle_b, public_point_b) = create_ecdh_key(&mut context, EccCurve::NistP256);
+
+        let z_point_a = context
+            .ecdh_z_gen(key_handle_a, public_point_b)
+            .expect("Failed to compute Z point with key A");
+        let z_point_b = context
+            .ecdh_z_gen(key_handle_b, public_point_a)
+            .expect("Failed to compute Z point with key B");
+
+        assert_eq!(z_point_a.x().value(), z_point_b.x().value());
+        assert_eq!(z_point_a.y().value(), z_point_b.y().value());
+    }
+
+    #[test]
+    fn test_ecdh_z_gen_wrong_curve() {
+        let mut context = create_ctx_with_session();
+        let (key_handle, _) = create_ecdh_key(&mut context, EccCurve::NistP256);
+        let (_, public_point_p384) = create_ecdh_key(&mut context, EccCurve::NistP384);
+
+        match context.ecdh_z_gen(key_handle, public_point_p384) {
+            Err(Error::WrapperError(kind)) => assert_eq!(kind, WrapperErrorKind::InvalidParam),
+            other => panic!("Unexpected result: {:?}", other.map(|_| ())),
+        }
+    }
+
+    #[test]
+    fn test_ecdh_z_gen_point_not_on_curve() {
+        let mut context = create_ctx_with_session();
+        let (key_handle, public_point) = create_ecdh_key(&mut context, EccCurve::NistP256);
+
+        // Changing the y coordinate moves the public point off the curve.
+        let mut y = public_point.y().value().to_vec();
+        let last = y.len() - 1;
+        y[last] ^= 0x01;
+        let invalid_point = EccPoint::new(
+            public_point.x().clone(),
+            EccParameter::try_from(y).expect("Failed to create y coordinate"),
+        );
+
+        match context.ecdh_z_gen(key_handle, invalid_point) {
+            Err(Error::WrapperError(kind)) => assert_eq!(kind, WrapperErrorKind::InvalidParam),
+            other => panic!("Unexpected result: {:?}", other.map(|_| ())),
+        }
+    }
+}
+
+mod test_ecc_parameters {
+    use crate::common::create_ctx_without_session;
+    use tss_esapi::interface_types::ecc::EccCurve;
+
+    #[test]
+    fn test_ecc_parameters_nist_p256() {
+        let mut context = create_ctx_without_session();
+
+        let parameters = context
+            .ecc_parameters(EccCurve::NistP256)
+            .expect("Failed to get ECC parameters for NIST P-256");
+
+        // The prime p = 2^256 - 2^224 + 2^192 + 2^96 - 1 as defined in FIPS 186-4
+        let nist_p256_prime: [u8; 32] = [
+            0xff, 0xff, 0xff, 0xff, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+            0xff, 0xff, 0xff, 0xff,
+        ];
+
+        assert_eq!(parameters.ecc_curve(), EccCurve::NistP256);
+        assert_eq!(parameters.key_size(), 256);
+        assert_eq!(parameters.p().value(), &nist_p256_prime[..]);
+        assert_eq!(parameters.g_x().value().len(), 32);
+        assert_eq!(parameters.g_y().value().len(), 32);
+        assert_eq!(parameters.n().value().len(), 32);
+    }
+}
+
+mod test_zgen_2phase {
+    use crate::common::create_ctx_with_session;
+    use tss_esapi::{
+        attributes::ObjectAttributesBuilder,
+        handles::KeyHandle,
+        interface_types::{
+            algorithm::{EccSchemeAlgorithm, HashingAlgorithm, PublicAlgorithm},
+            ecc::EccCurve,
+            resource_handles::Hierarchy,
+        },
+        structures::{
+            EccPoint, EccScheme, HashScheme, KeyDerivationFunctionScheme, Public, PublicBuilder,
+            PublicEccParametersBuilder,
+        },
+        Context, Error, WrapperErrorKind,
+    };
+
+    fn create_ecdh_key(context: &mut Context) -> (KeyHandle, EccPoint) {
+        let ecc_parms = PublicEccParametersBuilder::new()
+            .with_ecc_scheme(EccScheme::EcDh(HashScheme::new(HashingAlgorithm::Sha256)))
+            .with_curve(EccCurve::NistP256)
+            .with_is_signing_key(false)
+            .with_is_decryption_key(true)
+            .with_restricted(false)
+            .with_key_derivation_function_scheme(KeyDerivationFunctionScheme::Null)
+            .build()
+            .unwrap();
+
+        let object_attributes = ObjectAttributesBuilder::new()
+            .with_fixed_tpm(true)
+            .with_fixed_parent(true)
+            .with_sensitive_data_origin(true)
+            .with_user_with_auth(true)
+            .with_decrypt(true)
+            .with_sign_encrypt(false)
+            .with_restricted(false)
+            .build()
+            .unwrap();
+
+        let public = PublicBuilder::new()
+            .with_public_algorithm(PublicAlgorithm::Ecc)
+            .with_name_hashing_algorithm(HashingAlgorithm::Sha256)
+            .with_object_attributes(object_attributes)
+            .with_ecc_parameters(ecc_parms)
+            .with_ecc_unique_identifier(&EccPoint::default())
+            .build()
+            .unwrap();
+
+        let result = context
+            .create_primary(Hierarchy::Owner, &public, None, None, None, None)
+            .unwrap();
+
+        match result.out_public {
+            Public::Ecc { unique, .. } => (result.key_handle, unique),
+            _ => panic!("Created key is not an ECC key"),
+        }
+    }
+
+    #[test]
+    fn test_zgen_2phase_ecdh() {
+        let mut context = create_ctx_with_session();
+        let (key_a, _) = create_ecdh_key(&mut context);
+        let (_, static_point_b) = create_ecdh_key(&mut context);
+        let (_, ephemeral_point_b) = create_ecdh_key(&mut context);
+
+        let (_, counter) = context
+            .ec_ephemeral(EccCurve::NistP256)
+            .expect("Failed to create ephemeral key");
+
+        let (out_z1, _) = context
+            .zgen_2phase(
+                key_a,
+                static_point_b.clone(),
+                ephemeral_point_b,
+                EccSchemeAlgorithm::EcDh,
+                counter,
+            )
+            .expect("Failed to perform ZGen 2Phase");
+
+        // For the ECDH variant Z1 is the product of the static private key
+        // of A and the static public key of B.
+        let z_point = context
+            .ecdh_z_gen(key_a, static_point_b)
+            .expect("Failed to perform ECDH ZGen");
+        assert_eq!(out_z1.x().value(), z_point.x().value());
+    }
+
+    #[test]
+    fn test_zgen_2phase_invalid_scheme() {
+        let mut context = create_ctx_with_session();
+        let (key_a, static_point_b) = create_ecdh_key(&mut context);
+
+        let result = context.zgen_2phase(
+            key_a,
+            static_point_b.clone(),
+            static_point_b,
+            EccSchemeAlgorithm::EcDsa,
+            0,
+        );
+        assert_eq!(
+            result.map(|_| ()),
+            Err(Error::WrapperError(WrapperErrorKind::InvalidParam))
+        );
+    }
+}
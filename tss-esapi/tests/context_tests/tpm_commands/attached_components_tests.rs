@@ -1,2 +1,235 @@
 // Copyright 2021 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
+#[cfg(all(feature = "generate-bindings", tpm2_tss_version = "3"))]
+mod test_ac_get_capability {
+    use tss_esapi::{
+        constants::{
+            tss::{
+                TPM2_CC_AC_GetCapability, TPM2_AC_FIRST, TPM2_AT_PV1, TPM2_RC_SUCCESS,
+                TPM2_ST_NO_SESSIONS,
+            },
+            AcCapability,
+        },
+        handles::{AttachedComponentHandle, AttachedComponentTpmHandle, TpmHandle},
+        structures::AcOutput,
+        tcti_ldr::{MockTcti, TctiNameConf},
+        Context,
+    };
+
+    #[test]
+    fn test_ac_get_capability() {
+        let mock_tcti = MockTcti::new();
+        // Marshalled TPM2_AC_GetCapability response: header, followed by
+        // moreData and a TPML_AC_CAPABILITIES with a single value.
+        let mut response = Vec::new();
+        response.extend_from_slice(&TPM2_ST_NO_SESSIONS.to_be_bytes());
+        response.extend_from_slice(&23u32.to_be_bytes());
+        response.extend_from_slice(&TPM2_RC_SUCCESS.to_be_bytes());
+        response.push(1);
+        response.extend_from_slice(&1u32.to_be_bytes());
+        response.extend_from_slice(&TPM2_AT_PV1.to_be_bytes());
+        response.extend_from_slice(&0x1234_5678u32.to_be_bytes());
+        mock_tcti.push_response(response);
+
+        let mut context =
+            Context::new(TctiNameConf::Mock(mock_tcti.clone())).expect("Failed to create context");
+        // The ESYS handles of attached components are created without
+        // sending a command to the TPM.
+        let ac_handle = AttachedComponentHandle::from(
+            context
+                .tr_from_tpm_public(TpmHandle::AttachedComponent(
+                    AttachedComponentTpmHandle::new(TPM2_AC_FIRST)
+                        .expect("Failed to create AC handle"),
+                ))
+                .expect("Failed to get the ESYS handle of the AC"),
+        );
+        let (more_data, ac_outputs) = context
+            .ac_get_capability(ac_handle, AcCapability::Pv1, 4)
+            .expect("Failed to get the AC capabilities through the mock TCTI");
+        assert!(more_data);
+        assert_eq!(
+            ac_outputs,
+            vec![AcOutput::new(AcCapability::Pv1, 0x1234_5678)]
+        );
+
+        let commands = mock_tcti.commands();
+        assert_eq!(commands.len(), 1);
+        let command = &commands[0];
+        assert_eq!(command.len(), 22);
+        assert_eq!(command[0..2], TPM2_ST_NO_SESSIONS.to_be_bytes());
+        assert_eq!(command[2..6], 22u32.to_be_bytes());
+        assert_eq!(command[6..10], TPM2_CC_AC_GetCapability.to_be_bytes());
+        assert_eq!(command[10..14], TPM2_AC_FIRST.to_be_bytes());
+        assert_eq!(command[14..18], TPM2_AT_PV1.to_be_bytes());
+        assert_eq!(command[18..22], 4u32.to_be_bytes());
+    }
+}
+
+#[cfg(all(feature = "generate-bindings", tpm2_tss_version = "3"))]
+mod test_ac_send {
+    use std::convert::TryFrom;
+    use tss_esapi::{
+        constants::{
+            tss::{
+                TPM2_CC_AC_Send, TPM2_AC_FIRST, TPM2_AT_PV1, TPM2_RC_SUCCESS, TPM2_RH_ENDORSEMENT,
+                TPM2_RH_OWNER, TPM2_RS_PW, TPM2_ST_SESSIONS,
+            },
+            AcCapability,
+        },
+        handles::{
+            AttachedComponentHandle, AttachedComponentTpmHandle, AuthHandle, ObjectHandle,
+            TpmHandle,
+        },
+        interface_types::session_handles::AuthSession,
+        structures::{AcOutput, MaxBuffer},
+        tcti_ldr::{MockTcti, TctiNameConf},
+        Context,
+    };
+
+    #[test]
+    fn test_ac_send() {
+        let mock_tcti = MockTcti::new();
+        // Marshalled TPM2_AC_Send response: header, followed by the parameter
+        // size, the TPMS_AC_OUTPUT and the responses of the two password sessions.
+        let mut response = Vec::new();
+        response.extend_from_slice(&TPM2_ST_SESSIONS.to_be_bytes());
+        response.extend_from_slice(&32u32.to_be_bytes());
+        response.extend_from_slice(&TPM2_RC_SUCCESS.to_be_bytes());
+        response.extend_from_slice(&8u32.to_be_bytes());
+        response.extend_from_slice(&TPM2_AT_PV1.to_be_bytes());
+        response.extend_from_slice(&0x1234_5678u32.to_be_bytes());
+        for _ in 0..2 {
+            response.extend_from_slice(&[0x00, 0x00, 0x01, 0x00, 0x00]);
+        }
+        mock_tcti.push_response(response);
+
+        let mut context =
+            Context::new(TctiNameConf::Mock(mock_tcti.clone())).expect("Failed to create context");
+        // The ESYS handles of attached components are created without
+        // sending a command to the TPM.
+        let ac_handle = AttachedComponentHandle::from(
+            context
+                .tr_from_tpm_public(TpmHandle::AttachedComponent(
+                    AttachedComponentTpmHandle::new(TPM2_AC_FIRST)
+                        .expect("Failed to create AC handle"),
+                ))
+                .expect("Failed to get the ESYS handle of the AC"),
+        );
+        // The ESAPI does not check the type of the handles, so a hierarchy
+        // stands in for the object being sent, which would otherwise have
+        // to be loaded through the mock TCTI.
+        let ac_data = [0x1, 0x2, 0x3];
+        context.set_sessions((
+            Some(AuthSession::Password),
+            Some(AuthSession::Password),
+            None,
+        ));
+        let ac_output = context
+            .ac_send(
+                ObjectHandle::Endorsement,
+                AuthHandle::Owner,
+                ac_handle,
+                MaxBuffer::try_from(ac_data.to_vec()).expect("Failed to create buffer"),
+            )
+            .expect("Failed to send the object through the mock TCTI");
+        assert_eq!(ac_output, AcOutput::new(AcCapability::Pv1, 0x1234_5678));
+
+        let commands = mock_tcti.commands();
+        assert_eq!(commands.len(), 1);
+        let command = &commands[0];
+        assert_eq!(command.len(), 49);
+        assert_eq!(command[0..2], TPM2_ST_SESSIONS.to_be_bytes());
+        assert_eq!(command[2..6], 49u32.to_be_bytes());
+        assert_eq!(command[6..10], TPM2_CC_AC_Send.to_be_bytes());
+        // The handles are marshalled in the order sendObject, authHandle, ac.
+        assert_eq!(command[10..14], TPM2_RH_ENDORSEMENT.to_be_bytes());
+        assert_eq!(command[14..18], TPM2_RH_OWNER.to_be_bytes());
+        assert_eq!(command[18..22], TPM2_AC_FIRST.to_be_bytes());
+        // Two password sessions with empty authorization values.
+        assert_eq!(command[22..26], 18u32.to_be_bytes());
+        assert_eq!(command[26..30], TPM2_RS_PW.to_be_bytes());
+        assert_eq!(command[35..39], TPM2_RS_PW.to_be_bytes());
+        assert_eq!(command[44..46], 3u16.to_be_bytes());
+        assert_eq!(command[46..49], ac_data);
+    }
+}
+
+#[cfg(all(feature = "generate-bindings", tpm2_tss_version = "3"))]
+mod test_policy_ac_send_select {
+    use crate::common::create_ctx_without_session;
+    use std::convert::TryFrom;
+    use tss_esapi::{
+        constants::{
+            tss::{TPM2_AC_FIRST, TPM2_RH_OWNER},
+            SessionType,
+        },
+        handles::SessionHandle,
+        interface_types::{algorithm::HashingAlgorithm, session_handles::PolicySession},
+        structures::{Digest, Name, SymmetricDefinition},
+        Context,
+    };
+
+    fn policy_ac_send_select_digest(context: &mut Context, include_object: bool) -> Digest {
+        let trial_policy_auth_session = context
+            .start_auth_session(
+                None,
+                None,
+                None,
+                SessionType::Trial,
+                SymmetricDefinition::AES_256_CFB,
+                HashingAlgorithm::Sha256,
+            )
+            .expect("Start auth session failed")
+            .expect("Start auth session returned a NONE handle");
+        let trial_policy_session = PolicySession::try_from(trial_policy_auth_session)
+            .expect("Failed to convert auth session into policy session");
+        // A SHA-256 object name, the owner hierarchy and the first AC.
+        let mut object_name = vec![0x00, 0x0b];
+        object_name.extend_from_slice(&[0x11; 32]);
+        context
+            .policy_ac_send_select(
+                trial_policy_session,
+                Name::try_from(object_name).expect("Failed to create object name"),
+                Name::try_from(TPM2_RH_OWNER.to_be_bytes().to_vec())
+                    .expect("Failed to create auth handle name"),
+                Name::try_from(TPM2_AC_FIRST.to_be_bytes().to_vec())
+                    .expect("Failed to create AC name"),
+                include_object,
+            )
+            .expect("Failed to call policy_ac_send_select");
+        let digest = context
+            .policy_get_digest(trial_policy_session)
+            .expect("Failed to call policy_get_digest");
+        context
+            .flush_context(SessionHandle::from(trial_policy_session).into())
+            .expect("Failed to flush the trial session");
+        digest
+    }
+
+    #[test]
+    fn test_policy_ac_send_select() {
+        let mut context = create_ctx_without_session();
+
+        // SHA256(0x00 * 32 || TPM2_CC_Policy_AC_SendSelect || objectName || authHandleName || acName || 0x01)
+        let expected_digest = Digest::try_from(vec![
+            239, 226, 33, 50, 190, 44, 91, 120, 209, 42, 35, 74, 124, 135, 199, 103, 2, 40, 233,
+            39, 177, 174, 205, 111, 12, 100, 8, 190, 235, 122, 29, 14,
+        ])
+        .expect("Failed to create digest from data");
+        assert_eq!(
+            expected_digest,
+            policy_ac_send_select_digest(&mut context, true)
+        );
+
+        // SHA256(0x00 * 32 || TPM2_CC_Policy_AC_SendSelect || authHandleName || acName || 0x00)
+        let expected_digest = Digest::try_from(vec![
+            133, 140, 104, 140, 234, 247, 246, 246, 236, 47, 226, 119, 39, 92, 143, 30, 138, 152,
+            116, 199, 201, 158, 242, 236, 228, 95, 193, 6, 161, 42, 29, 204,
+        ])
+        .expect("Failed to create digest from data");
+        assert_eq!(
+            expected_digest,
+            policy_ac_send_select_digest(&mut context, false)
+        );
+    }
+}
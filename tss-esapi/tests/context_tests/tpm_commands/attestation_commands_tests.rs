@@ -40,3 +40,339 @@ mod test_quote {
         assert!(res.0.size != 0);
     }
 }
+
+mod test_get_command_audit_digest {
+    use crate::common::{create_ctx_with_session, signing_key_pub};
+    use std::convert::TryFrom;
+    use tss_esapi::{
+        constants::{CommandCode, StructureTag},
+        handles::{AuthHandle, KeyHandle},
+        interface_types::{
+            algorithm::HashingAlgorithm,
+            resource_handles::{Hierarchy, Provision},
+        },
+        structures::{AttestInfo, CommandCodeList, CommandCodeListBuilder, Data, SignatureScheme},
+        Context,
+    };
+
+    fn get_audit_counter(context: &mut Context, key_handle: KeyHandle) -> u64 {
+        let (attest, _signature) = context
+            .get_command_audit_digest(
+                AuthHandle::Endorsement,
+                key_handle,
+                Data::default(),
+                SignatureScheme::Null,
+            )
+            .expect("Failed to get command audit digest");
+        match attest.attested() {
+            AttestInfo::CommandAudit { info } => info.audit_counter(),
+            _ => panic!("Attested did not contain the expected variant."),
+        }
+    }
+
+    #[test]
+    fn test_get_command_audit_digest() {
+        let mut context = create_ctx_with_session();
+        // Both the privacy handle and the signing key requires authorization.
+        let (session_1, _, _) = context.sessions();
+        context.set_sessions((session_1, session_1, None));
+
+        let key_handle = context
+            .create_primary(Hierarchy::Owner, &signing_key_pub(), None, None, None, None)
+            .expect("Failed to create primary key")
+            .key_handle;
+        let qualifying_data = Data::try_from(vec![0xff; 16]).unwrap();
+
+        let audit_counters = (0..2)
+            .map(|_| {
+                let (attest, _signature) = context
+                    .get_command_audit_digest(
+                        AuthHandle::Endorsement,
+                        key_handle,
+                        qualifying_data.clone(),
+                        SignatureScheme::Null,
+                    )
+                    .expect("Failed to get command audit digest");
+                assert_eq!(StructureTag::AttestCommandAudit, attest.attestation_type());
+                assert_eq!(&qualifying_data, attest.extra_data());
+                match attest.attested() {
+                    AttestInfo::CommandAudit { info } => info.audit_counter(),
+                    _ => panic!("Attested did not contain the expected variant."),
+                }
+            })
+            .collect::<Vec<u64>>();
+
+        // No audited command has been executed between the calls.
+        assert_eq!(audit_counters[0], audit_counters[1]);
+    }
+
+    #[test]
+    fn test_get_command_audit_digest_counter_advances() {
+        let mut context = create_ctx_with_session();
+        let (session_1, _, _) = context.sessions();
+        context.set_sessions((session_1, session_1, None));
+
+        let key_handle = context
+            .create_primary(Hierarchy::Owner, &signing_key_pub(), None, None, None, None)
+            .expect("Failed to create primary key")
+            .key_handle;
+        let get_random_list = CommandCodeListBuilder::new()
+            .with_command_code(CommandCode::GetRandom)
+            .build()
+            .expect("Failed to build command code list");
+
+        context
+            .set_command_code_audit_status(
+                Provision::Owner,
+                HashingAlgorithm::Null,
+                get_random_list.clone(),
+                CommandCodeList::new(),
+            )
+            .expect("Failed to enable command code audit");
+        let counter_before = get_audit_counter(&mut context, key_handle);
+        let _ = context
+            .execute_without_session(|ctx| ctx.get_random(16))
+            .expect("Failed to get random bytes");
+        let counter_after = get_audit_counter(&mut context, key_handle);
+        context
+            .set_command_code_audit_status(
+                Provision::Owner,
+                HashingAlgorithm::Null,
+                CommandCodeList::new(),
+                get_random_list,
+            )
+            .expect("Failed to disable command code audit");
+
+        assert!(counter_after > counter_before);
+    }
+}
+
+mod test_get_session_audit_digest {
+    use crate::common::{create_ctx_with_session, signing_key_pub};
+    use std::convert::TryFrom;
+    use tss_esapi::{
+        attributes::SessionAttributesBuilder,
+        constants::{SessionType, StructureTag},
+        handles::{AuthHandle, KeyHandle},
+        interface_types::{
+            algorithm::HashingAlgorithm, resource_handles::Hierarchy, session_handles::HmacSession,
+        },
+        structures::{AttestInfo, Data, Digest, MaxBuffer, SignatureScheme, SymmetricDefinition},
+        Context,
+    };
+
+    fn audited_session_digest(context: &mut Context, key_handle: KeyHandle) -> Digest {
+        let audit_auth_session = context
+            .start_auth_session(
+                None,
+                None,
+                None,
+                SessionType::Hmac,
+                SymmetricDefinition::Null,
+                HashingAlgorithm::Sha256,
+            )
+            .expect("Start auth session failed")
+            .expect("Start auth session returned a NONE handle");
+        let (audit_session_attributes, audit_session_attributes_mask) =
+            SessionAttributesBuilder::new()
+                .with_continue_session(true)
+                .with_audit(true)
+                .build();
+        context
+            .tr_sess_set_attributes(
+                audit_auth_session,
+                audit_session_attributes,
+                audit_session_attributes_mask,
+            )
+            .expect("tr_sess_set_attributes call failed");
+
+        // Run the same commands in the audit session.
+        let auth_sessions = context.sessions();
+        context.set_sessions((Some(audit_auth_session), None, None));
+        for data in [vec![1, 2, 3], vec![4, 5, 6]].iter() {
+            let _ = context
+                .hash(
+                    &MaxBuffer::try_from(data.clone()).unwrap(),
+                    HashingAlgorithm::Sha256,
+                    Hierarchy::Owner,
+                )
+                .expect("Failed to hash data in audit session");
+        }
+        context.set_sessions(auth_sessions);
+
+        let (attest, _signature) = context
+            .get_session_audit_digest(
+                AuthHandle::Endorsement,
+                key_handle,
+                HmacSession::try_from(audit_auth_session)
+                    .expect("Failed to convert auth session into hmac session"),
+                Data::default(),
+                SignatureScheme::Null,
+            )
+            .expect("Failed to get session audit digest");
+        assert_eq!(StructureTag::AttestSessionAudit, attest.attestation_type());
+        match attest.attested() {
+            AttestInfo::SessionAudit { info } => info.session_digest().clone(),
+            _ => panic!("Attested did not contain the expected variant."),
+        }
+    }
+
+    #[test]
+    fn test_get_session_audit_digest() {
+        let mut context = create_ctx_with_session();
+        // Both the privacy handle and the signing key requires authorization.
+        let (session_1, _, _) = context.sessions();
+        context.set_sessions((session_1, session_1, None));
+
+        let key_handle = context
+            .create_primary(Hierarchy::Owner, &signing_key_pub(), None, None, None, None)
+            .expect("Failed to create primary key")
+            .key_handle;
+
+        let first_digest = audited_session_digest(&mut context, key_handle);
+        let second_digest = audited_session_digest(&mut context, key_handle);
+        // Identical command sequences produce identical audit digests.
+        assert_eq!(first_digest, second_digest);
+        assert_ne!(first_digest, Digest::try_from(vec![0; 32]).unwrap());
+    }
+}
+
+mod test_certify {
+    use crate::common::{create_ctx_with_session, decryption_key_pub, signing_key_pub};
+    use tss_esapi::{
+        constants::StructureTag,
+        interface_types::resource_handles::Hierarchy,
+        structures::{AttestInfo, Data, SignatureScheme},
+    };
+
+    #[test]
+    fn test_certify() {
+        let mut context = create_ctx_with_session();
+        // Both the certified object and the signing key requires authorization.
+        let (session_1, _, _) = context.sessions();
+        context.set_sessions((session_1, session_1, None));
+
+        let sign_key_handle = context
+            .create_primary(Hierarchy::Owner, &signing_key_pub(), None, None, None, None)
+            .expect("Failed to create signing key")
+            .key_handle;
+        let object_handle = context
+            .create_primary(
+                Hierarchy::Owner,
+                &decryption_key_pub(),
+                None,
+                None,
+                None,
+                None,
+            )
+            .expect("Failed to create key to certify")
+            .key_handle;
+
+        let (attest, _signature) = context
+            .certify(
+                object_handle.into(),
+                sign_key_handle,
+                Data::default(),
+                SignatureScheme::Null,
+            )
+            .expect("Failed to certify object");
+        assert_eq!(StructureTag::AttestCertify, attest.attestation_type());
+
+        let (_, expected_name, _) = context
+            .read_public(object_handle)
+            .expect("Failed to read public area of certified object");
+        match attest.attested() {
+            AttestInfo::Certify { info } => assert_eq!(&expected_name, info.name()),
+            _ => panic!("Attested did not contain the expected variant."),
+        }
+    }
+}
+
+mod test_certify_creation {
+    use crate::common::{create_ctx_with_session, signing_key_pub};
+    use tss_esapi::{
+        constants::StructureTag,
+        interface_types::resource_handles::Hierarchy,
+        structures::{AttestInfo, Data, SignatureScheme},
+    };
+
+    #[test]
+    fn test_certify_creation() {
+        let mut context = create_ctx_with_session();
+
+        let create_primary_result = context
+            .create_primary(Hierarchy::Owner, &signing_key_pub(), None, None, None, None)
+            .expect("Failed to create signing key");
+        let key_handle = create_primary_result.key_handle;
+
+        let (attest, _signature) = context
+            .certify_creation(
+                key_handle,
+                key_handle.into(),
+                Data::default(),
+                create_primary_result.creation_hash.clone(),
+                SignatureScheme::Null,
+                create_primary_result.creation_ticket,
+            )
+            .expect("Failed to certify creation");
+        assert_eq!(StructureTag::AttestCreation, attest.attestation_type());
+
+        let (_, expected_name, _) = context
+            .read_public(key_handle)
+            .expect("Failed to read public area of the key");
+        match attest.attested() {
+            AttestInfo::Creation { info } => {
+                assert_eq!(&expected_name, info.object_name());
+                assert_eq!(&create_primary_result.creation_hash, info.creation_hash());
+            }
+            _ => panic!("Attested did not contain the expected variant."),
+        }
+    }
+}
+
+mod test_get_time {
+    use crate::common::{create_ctx_with_session, signing_key_pub};
+    use tss_esapi::{
+        constants::StructureTag,
+        handles::{AuthHandle, KeyHandle},
+        interface_types::resource_handles::Hierarchy,
+        structures::{AttestInfo, Data, SignatureScheme, TimeAttestInfo},
+        Context,
+    };
+
+    fn attested_time(context: &mut Context, key_handle: KeyHandle) -> TimeAttestInfo {
+        let (attest, _signature) = context
+            .get_time(
+                AuthHandle::Endorsement,
+                key_handle,
+                Data::default(),
+                SignatureScheme::Null,
+            )
+            .expect("Failed to get time");
+        assert_eq!(StructureTag::AttestTime, attest.attestation_type());
+        match attest.attested() {
+            AttestInfo::Time { info } => *info,
+            _ => panic!("Attested did not contain the expected variant."),
+        }
+    }
+
+    #[test]
+    fn test_get_time() {
+        let mut context = create_ctx_with_session();
+        // Both the privacy handle and the signing key requires authorization.
+        let (session_1, _, _) = context.sessions();
+        context.set_sessions((session_1, session_1, None));
+
+        let key_handle = context
+            .create_primary(Hierarchy::Owner, &signing_key_pub(), None, None, None, None)
+            .expect("Failed to create primary key")
+            .key_handle;
+
+        let first = attested_time(&mut context, key_handle);
+        let second = attested_time(&mut context, key_handle);
+        assert!(first.clock() <= second.clock());
+        assert!(first.time() <= second.time());
+        assert_eq!(first.reset_count(), second.reset_count());
+        assert_eq!(first.restart_count(), second.restart_count());
+    }
+}
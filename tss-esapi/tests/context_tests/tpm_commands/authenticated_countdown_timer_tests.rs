@@ -1,2 +1,71 @@
 // Copyright 2021 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
+#[cfg(all(
+    feature = "act-tests",
+    feature = "generate-bindings",
+    tpm2_tss_version = "3"
+))]
+mod test_act_set_timeout {
+    use crate::common::create_ctx_with_session;
+    use tss_esapi::handles::ActHandle;
+
+    #[test]
+    fn test_act_set_timeout() {
+        let mut context = create_ctx_with_session();
+        context
+            .act_set_timeout(ActHandle::Act0, 60)
+            .expect("Failed to set the timeout of the ACT");
+        // Stop the timer again.
+        context
+            .act_set_timeout(ActHandle::Act0, 0)
+            .expect("Failed to stop the ACT");
+    }
+}
+
+#[cfg(all(feature = "generate-bindings", tpm2_tss_version = "3"))]
+mod test_act_set_timeout_marshalling {
+    use tss_esapi::{
+        constants::tss::{
+            TPM2_CC_ACT_SetTimeout, TPM2_RC_SUCCESS, TPM2_RH_ACT_0, TPM2_RS_PW, TPM2_ST_SESSIONS,
+        },
+        handles::ActHandle,
+        interface_types::session_handles::AuthSession,
+        tcti_ldr::{MockTcti, TctiNameConf},
+        Context,
+    };
+
+    #[test]
+    fn test_act_set_timeout_marshalling() {
+        let mock_tcti = MockTcti::new();
+        // Marshalled TPM2_ACT_SetTimeout response: header, followed by an
+        // empty parameter area and the response of the password session.
+        let mut response = Vec::new();
+        response.extend_from_slice(&TPM2_ST_SESSIONS.to_be_bytes());
+        response.extend_from_slice(&19u32.to_be_bytes());
+        response.extend_from_slice(&TPM2_RC_SUCCESS.to_be_bytes());
+        response.extend_from_slice(&0u32.to_be_bytes());
+        response.extend_from_slice(&[0x00, 0x00, 0x01, 0x00, 0x00]);
+        mock_tcti.push_response(response);
+
+        let mut context =
+            Context::new(TctiNameConf::Mock(mock_tcti.clone())).expect("Failed to create context");
+        context
+            .execute_with_session(Some(AuthSession::Password), |ctx| {
+                ctx.act_set_timeout(ActHandle::Act0, 0x0102_0304)
+            })
+            .expect("Failed to set the timeout of the ACT through the mock TCTI");
+
+        let commands = mock_tcti.commands();
+        assert_eq!(commands.len(), 1);
+        let command = &commands[0];
+        assert_eq!(command.len(), 31);
+        assert_eq!(command[0..2], TPM2_ST_SESSIONS.to_be_bytes());
+        assert_eq!(command[2..6], 31u32.to_be_bytes());
+        assert_eq!(command[6..10], TPM2_CC_ACT_SetTimeout.to_be_bytes());
+        assert_eq!(command[10..14], TPM2_RH_ACT_0.to_be_bytes());
+        // A password session with an empty authorization value.
+        assert_eq!(command[14..18], 9u32.to_be_bytes());
+        assert_eq!(command[18..22], TPM2_RS_PW.to_be_bytes());
+        assert_eq!(command[27..31], 0x0102_0304u32.to_be_bytes());
+    }
+}
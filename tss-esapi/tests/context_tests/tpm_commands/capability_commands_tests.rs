@@ -38,3 +38,217 @@ mod test_get_capability {
         assert_ne!(year, 0);
     }
 }
+
+mod test_get_algorithm_properties {
+    use crate::common::create_ctx_without_session;
+    use tss_esapi::constants::AlgorithmIdentifier;
+
+    #[test]
+    fn test_get_algorithm_properties() {
+        let mut context = create_ctx_without_session();
+        let algorithm_properties = context
+            .get_algorithm_properties()
+            .expect("Failed to call get_algorithm_properties");
+
+        let sha256 = algorithm_properties
+            .iter()
+            .find(|p| p.algorithm_identifier() == AlgorithmIdentifier::Sha256)
+            .expect("SHA-256 was not among the algorithm properties");
+        assert!(sha256.algorithm_attributes().hash());
+
+        let rsa = algorithm_properties
+            .iter()
+            .find(|p| p.algorithm_identifier() == AlgorithmIdentifier::Rsa)
+            .expect("RSA was not among the algorithm properties");
+        assert!(rsa.algorithm_attributes().asymmetric());
+        assert!(rsa.algorithm_attributes().object());
+    }
+}
+
+mod test_capability_iter {
+    use crate::common::{create_ctx_with_session, signing_key_pub};
+    use tss_esapi::{
+        constants::{tss::TPM2_TRANSIENT_FIRST, CapabilityType},
+        handles::TpmHandle,
+        interface_types::resource_handles::Hierarchy,
+        structures::CapabilityData,
+    };
+
+    #[test]
+    fn test_iterate_transient_handles() {
+        let mut context = create_ctx_with_session();
+        for _ in 0..2 {
+            let _ = context
+                .create_primary(Hierarchy::Owner, &signing_key_pub(), None, None, None, None)
+                .expect("Failed to create primary key");
+        }
+
+        let mut transient_handles = Vec::new();
+        for capability_data in
+            context.capability_iter(CapabilityType::Handles, TPM2_TRANSIENT_FIRST)
+        {
+            match capability_data.expect("Failed to get capability") {
+                CapabilityData::Handles(handles) => transient_handles.extend(handles),
+                _ => panic!("Invalid capability data returned"),
+            }
+        }
+        assert!(transient_handles.len() >= 2);
+        assert!(transient_handles
+            .iter()
+            .all(|handle| matches!(handle, TpmHandle::Transient(_))));
+    }
+
+    #[test]
+    fn test_iterate_pcr_banks() {
+        let mut context = create_ctx_with_session();
+        let capability_data = context
+            .capability_iter(CapabilityType::AssignedPCR, 0)
+            .collect::<Vec<_>>();
+        // All the PCR banks are returned in a single chunk.
+        assert_eq!(capability_data.len(), 1);
+        match capability_data.into_iter().next().unwrap() {
+            Ok(CapabilityData::AssignedPCR(pcr_selection_list)) => {
+                assert!(!pcr_selection_list.is_empty())
+            }
+            other => panic!("Invalid capability data returned: {:?}", other),
+        }
+    }
+}
+
+mod test_get_handles {
+    use crate::common::{create_ctx_without_session, decryption_key_pub};
+    use tss_esapi::{
+        constants::HandleArea,
+        handles::{ObjectHandle, PersistentTpmHandle, TpmHandle},
+        interface_types::{
+            dynamic_handles::Persistent,
+            resource_handles::{Hierarchy, Provision},
+            session_handles::AuthSession,
+        },
+    };
+
+    #[test]
+    fn test_get_persistent_handles() {
+        let persistent_tpm_handle =
+            PersistentTpmHandle::new(u32::from_be_bytes([0x81, 0x00, 0x00, 0x02]))
+                .expect("Failed to create persistent tpm handle");
+        let persistent = Persistent::Persistent(persistent_tpm_handle);
+        let mut context = create_ctx_without_session();
+
+        // Make sure the handle is not already persistent
+        if context
+            .get_handles(HandleArea::Persistent)
+            .expect("Failed to get persistent handles")
+            .contains(&TpmHandle::Persistent(persistent_tpm_handle))
+        {
+            let handle = context
+                .tr_from_tpm_public(TpmHandle::Persistent(persistent_tpm_handle))
+                .expect("Failed to retrieve handle from TPM");
+            context.execute_with_session(Some(AuthSession::Password), |ctx| {
+                ctx.evict_control(Provision::Owner, handle, persistent)
+                    .expect("Failed to evict persistent handle")
+            });
+        }
+
+        context.set_sessions((Some(AuthSession::Password), None, None));
+        let primary_key_handle = context
+            .create_primary(
+                Hierarchy::Owner,
+                &decryption_key_pub(),
+                None,
+                None,
+                None,
+                None,
+            )
+            .expect("Failed to create primary key")
+            .key_handle;
+        let persistent_primary_key_handle = context
+            .evict_control(Provision::Owner, primary_key_handle.into(), persistent)
+            .expect("Failed to make the primary key handle persistent");
+        context
+            .flush_context(ObjectHandle::from(primary_key_handle))
+            .expect("Failed to flush context");
+
+        let persistent_handles = context
+            .get_handles(HandleArea::Persistent)
+            .expect("Failed to get persistent handles");
+        assert!(persistent_handles.contains(&TpmHandle::Persistent(persistent_tpm_handle)));
+        assert!(persistent_handles
+            .iter()
+            .all(|handle| matches!(handle, TpmHandle::Persistent(_))));
+
+        context
+            .evict_control(Provision::Owner, persistent_primary_key_handle, persistent)
+            .expect("Failed to evict persistent handle");
+        context.clear_sessions();
+        assert!(!context
+            .get_handles(HandleArea::Persistent)
+            .expect("Failed to get persistent handles")
+            .contains(&TpmHandle::Persistent(persistent_tpm_handle)));
+    }
+
+    #[test]
+    fn test_get_transient_handles() {
+        let mut context = create_ctx_without_session();
+        context.set_sessions((Some(AuthSession::Password), None, None));
+        let _ = context
+            .create_primary(
+                Hierarchy::Owner,
+                &decryption_key_pub(),
+                None,
+                None,
+                None,
+                None,
+            )
+            .expect("Failed to create primary key");
+        let transient_handles = context
+            .get_handles(HandleArea::Transient)
+            .expect("Failed to get transient handles");
+        assert!(!transient_handles.is_empty());
+        assert!(transient_handles
+            .iter()
+            .all(|handle| matches!(handle, TpmHandle::Transient(_))));
+    }
+}
+
+mod test_test_parms {
+    use crate::common::create_ctx_without_session;
+    use tss_esapi::{
+        constants::response_code::Tss2ResponseCodeKind,
+        interface_types::{algorithm::HashingAlgorithm, ecc::EccCurve},
+        structures::{EccScheme, HashScheme, PublicEccParametersBuilder, PublicParameters},
+        Error,
+    };
+
+    fn ecc_signing_parameters(curve: EccCurve) -> PublicParameters {
+        PublicParameters::Ecc(
+            PublicEccParametersBuilder::new_unrestricted_signing_key(
+                EccScheme::EcDsa(HashScheme::new(HashingAlgorithm::Sha256)),
+                curve,
+            )
+            .build()
+            .expect("Failed to build ECC parameters"),
+        )
+    }
+
+    #[test]
+    fn test_supported_parameters() {
+        let mut context = create_ctx_without_session();
+        context
+            .test_parms(ecc_signing_parameters(EccCurve::NistP256))
+            .expect("NIST P-256 should be supported");
+    }
+
+    #[test]
+    fn test_unsupported_parameters() {
+        let mut context = create_ctx_without_session();
+        // The reference implementation does not include NIST P-192.
+        if let Err(Error::Tss2Error(rc)) =
+            context.test_parms(ecc_signing_parameters(EccCurve::NistP192))
+        {
+            assert_eq!(rc.kind(), Some(Tss2ResponseCodeKind::Curve));
+        } else {
+            panic!("NIST P-192 should not be supported");
+        }
+    }
+}
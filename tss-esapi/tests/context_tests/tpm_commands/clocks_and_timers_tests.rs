@@ -1,2 +1,90 @@
 // Copyright 2021 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
+mod test_clock_rate_adjust {
+    use crate::common::create_ctx_with_session;
+    use tss_esapi::interface_types::{clock::ClockAdjust, resource_handles::Provision};
+
+    #[test]
+    fn test_clock_rate_adjust() {
+        let mut context = create_ctx_with_session();
+        for rate_adjust in [
+            ClockAdjust::CoarseSlower,
+            ClockAdjust::MediumSlower,
+            ClockAdjust::FineSlower,
+            ClockAdjust::NoChange,
+            ClockAdjust::FineFaster,
+            ClockAdjust::MediumFaster,
+            ClockAdjust::CoarseFaster,
+        ] {
+            context
+                .clock_rate_adjust(Provision::Owner, rate_adjust)
+                .unwrap_or_else(|e| panic!("Failed to adjust clock rate {:?}: {}", rate_adjust, e));
+        }
+    }
+}
+
+mod test_read_clock {
+    use crate::common::create_ctx_without_session;
+    use std::{thread, time::Duration};
+
+    #[test]
+    fn test_read_clock() {
+        let mut context = create_ctx_without_session();
+        let first = context.read_clock().expect("Failed to read clock");
+        thread::sleep(Duration::from_millis(50));
+        let second = context.read_clock().expect("Failed to read clock");
+        assert!(first.time() < second.time());
+        assert!(first.clock_info().clock() < second.clock_info().clock());
+        assert_eq!(
+            first.clock_info().reset_count(),
+            second.clock_info().reset_count()
+        );
+    }
+}
+
+mod test_clock_set {
+    use crate::common::create_ctx_with_session;
+    use tss_esapi::{
+        constants::response_code::Tss2ResponseCodeKind,
+        interface_types::resource_handles::Provision, Error,
+    };
+
+    #[test]
+    fn test_clock_set() {
+        let mut context = create_ctx_with_session();
+        let delta = 60 * 60 * 1000;
+        let current_clock = context
+            .read_clock()
+            .expect("Failed to read clock")
+            .clock_info()
+            .clock();
+        context
+            .clock_set(Provision::Owner, current_clock + delta)
+            .expect("Failed to set clock");
+        let new_clock = context
+            .read_clock()
+            .expect("Failed to read clock")
+            .clock_info()
+            .clock();
+        assert!(new_clock >= current_clock + delta);
+    }
+
+    #[test]
+    fn test_clock_set_backwards() {
+        let mut context = create_ctx_with_session();
+        let current_clock = context
+            .read_clock()
+            .expect("Failed to read clock")
+            .clock_info()
+            .clock();
+        context
+            .clock_set(Provision::Owner, current_clock + 1000)
+            .expect("Failed to set clock");
+        // The clock is now ahead of the previously read value.
+        if let Err(Error::Tss2Error(rc)) = context.clock_set(Provision::Owner, current_clock) {
+            assert_eq!(rc.kind(), Some(Tss2ResponseCodeKind::Value));
+        } else {
+            panic!("Setting the clock backwards should have failed with a value error.");
+        }
+    }
+}
@@ -1,2 +1,39 @@
 // Copyright 2021 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
+mod test_set_command_code_audit_status {
+    use crate::common::create_ctx_with_session;
+    use tss_esapi::{
+        constants::CommandCode,
+        interface_types::{algorithm::HashingAlgorithm, resource_handles::Provision},
+        structures::{CommandCodeList, CommandCodeListBuilder},
+    };
+
+    #[test]
+    fn test_set_command_code_audit_status() {
+        let mut context = create_ctx_with_session();
+        let get_random_list = CommandCodeListBuilder::new()
+            .with_command_code(CommandCode::GetRandom)
+            .build()
+            .expect("Failed to build command code list");
+
+        // Enable audit for GetRandom
+        context
+            .set_command_code_audit_status(
+                Provision::Owner,
+                HashingAlgorithm::Null,
+                get_random_list.clone(),
+                CommandCodeList::new(),
+            )
+            .expect("Failed to enable command code audit");
+
+        // Disable audit for GetRandom
+        context
+            .set_command_code_audit_status(
+                Provision::Owner,
+                HashingAlgorithm::Null,
+                CommandCodeList::new(),
+                get_random_list,
+            )
+            .expect("Failed to disable command code audit");
+    }
+}
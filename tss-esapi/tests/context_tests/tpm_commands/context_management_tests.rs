@@ -107,6 +107,86 @@ mod test_ctx_load {
     }
 }
 
+mod test_ctx_save_and_load {
+    use crate::common::{create_ctx_with_session, signing_key_pub};
+    use std::convert::{TryFrom, TryInto};
+    use tss_esapi::{
+        constants::tss::{TPM2_ALG_NULL, TPM2_RH_NULL, TPM2_ST_HASHCHECK},
+        handles::KeyHandle,
+        interface_types::resource_handles::Hierarchy,
+        structures::{Digest, Signature},
+        tss2_esys::{TPMT_SIG_SCHEME, TPMT_TK_HASHCHECK},
+        utils::TpmsContext,
+    };
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_ctx_save_flush_load_and_sign() {
+        let mut context = create_ctx_with_session();
+
+        let key_handle = context
+            .create_primary(Hierarchy::Owner, &signing_key_pub(), None, None, None, None)
+            .unwrap()
+            .key_handle;
+
+        // Save the context in serialized form and free the slot in the TPM.
+        let key_ctx = context.context_save(key_handle.into()).unwrap();
+        let serialized_key_ctx = serde_json::to_string(&key_ctx).unwrap();
+        context.flush_context(key_handle.into()).unwrap();
+
+        let key_ctx: TpmsContext = serde_json::from_str(&serialized_key_ctx).unwrap();
+        let key_handle = context.context_load(key_ctx).map(KeyHandle::from).unwrap();
+
+        let scheme = TPMT_SIG_SCHEME {
+            scheme: TPM2_ALG_NULL,
+            details: Default::default(),
+        };
+        let validation = TPMT_TK_HASHCHECK {
+            tag: TPM2_ST_HASHCHECK,
+            hierarchy: TPM2_RH_NULL,
+            digest: Default::default(),
+        };
+        let signature = context
+            .sign(
+                key_handle,
+                &Digest::try_from(vec![0xab; 32]).unwrap(),
+                scheme,
+                validation.try_into().unwrap(),
+            )
+            .expect("Failed to sign with the loaded key");
+        assert!(matches!(signature, Signature::RsaSsa(_)));
+    }
+
+    #[test]
+    fn test_ctx_save_to_bytes_and_load() {
+        let mut context = create_ctx_with_session();
+
+        let key_handle = context
+            .create_primary(Hierarchy::Owner, &signing_key_pub(), None, None, None, None)
+            .unwrap()
+            .key_handle;
+        let (_, expected_name, _) = context.read_public(key_handle).unwrap();
+
+        let key_ctx = context.context_save(key_handle.into()).unwrap();
+        let key_ctx_bytes = key_ctx
+            .to_bytes()
+            .expect("Failed to convert context to bytes");
+        context.flush_context(key_handle.into()).unwrap();
+
+        let restored_key_ctx =
+            TpmsContext::from_bytes(&key_ctx_bytes).expect("Failed to create context from bytes");
+        assert_eq!(key_ctx.context_blob(), restored_key_ctx.context_blob());
+        assert_eq!(key_ctx_bytes, restored_key_ctx.to_bytes().unwrap());
+
+        let key_handle = context
+            .context_load(restored_key_ctx)
+            .map(KeyHandle::from)
+            .expect("Failed to load the context restored from bytes");
+        let (_, actual_name, _) = context.read_public(key_handle).unwrap();
+        assert_eq!(expected_name, actual_name);
+    }
+}
+
 mod test_flush_context {
     use crate::common::{create_ctx_with_session, decryption_key_pub, signing_key_pub};
     use std::convert::TryFrom;
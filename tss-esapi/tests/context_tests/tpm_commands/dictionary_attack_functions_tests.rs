@@ -1,2 +1,63 @@
 // Copyright 2021 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
+mod test_dictionary_attack_parameters {
+    use crate::common::create_ctx_with_session;
+    use tss_esapi::{
+        constants::{
+            tss::{TPM2_PT_LOCKOUT_INTERVAL, TPM2_PT_LOCKOUT_RECOVERY, TPM2_PT_MAX_AUTH_FAIL},
+            CapabilityType,
+        },
+        interface_types::resource_handles::Lockout,
+        structures::CapabilityData,
+        tss2_esys::TPM2_PT,
+        Context,
+    };
+
+    // Reads the property directly from the TPM, get_tpm_property
+    // would return the cached value.
+    fn read_property(context: &mut Context, property: TPM2_PT) -> u32 {
+        let (capability_data, _) = context
+            .execute_without_session(|ctx| {
+                ctx.get_capability(CapabilityType::TPMProperties, property, 1)
+            })
+            .expect("Failed to get TPM properties");
+        match capability_data {
+            CapabilityData::TPMProperties(properties) => *properties
+                .get(&property)
+                .expect("The TPM did not return the property"),
+            _ => panic!("Received unexpected capability data"),
+        }
+    }
+
+    #[test]
+    fn test_dictionary_attack_parameters() {
+        let mut context = create_ctx_with_session();
+
+        let max_tries = read_property(&mut context, TPM2_PT_MAX_AUTH_FAIL);
+        let recovery_time = read_property(&mut context, TPM2_PT_LOCKOUT_INTERVAL);
+        let lockout_recovery = read_property(&mut context, TPM2_PT_LOCKOUT_RECOVERY);
+
+        let new_max_tries = if max_tries == 8 { 16 } else { 8 };
+        context
+            .dictionary_attack_parameters(Lockout::Lockout, new_max_tries, 60, 120)
+            .expect("Call to dictionary_attack_parameters failed");
+
+        let actual_max_tries = read_property(&mut context, TPM2_PT_MAX_AUTH_FAIL);
+        let actual_recovery_time = read_property(&mut context, TPM2_PT_LOCKOUT_INTERVAL);
+        let actual_lockout_recovery = read_property(&mut context, TPM2_PT_LOCKOUT_RECOVERY);
+
+        // Restore the previous parameters.
+        context
+            .dictionary_attack_parameters(
+                Lockout::Lockout,
+                max_tries,
+                recovery_time,
+                lockout_recovery,
+            )
+            .expect("Call to dictionary_attack_parameters failed when restoring the parameters");
+
+        assert_eq!(new_max_tries, actual_max_tries);
+        assert_eq!(60, actual_recovery_time);
+        assert_eq!(120, actual_lockout_recovery);
+    }
+}
@@ -1,20 +1,61 @@
 // Copyright 2021 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
 mod test_policy_signed {
-    use crate::common::{create_ctx_with_session, signing_key_pub};
+    use crate::common::{create_ctx_with_session, signing_key_pub, start_policy_session};
     use std::{
         convert::{TryFrom, TryInto},
         time::Duration,
     };
     use tss_esapi::{
         attributes::SessionAttributesBuilder,
-        constants::SessionType,
+        constants::{
+            tss::{TPM2_CC_PolicySigned, TPM2_ALG_NULL, TPM2_RH_NULL, TPM2_ST_HASHCHECK},
+            SessionType, Tss2ResponseCodeKind,
+        },
+        handles::KeyHandle,
         interface_types::{
             algorithm::HashingAlgorithm, resource_handles::Hierarchy,
             session_handles::PolicySession,
         },
-        structures::{Digest, Nonce, PublicKeyRsa, RsaSignature, Signature, SymmetricDefinition},
+        structures::{
+            Digest, MaxBuffer, Nonce, PublicKeyRsa, RsaSignature, Signature, SymmetricDefinition,
+        },
+        tss2_esys::{TPMT_SIG_SCHEME, TPMT_TK_HASHCHECK},
+        Context, Error,
     };
+
+    fn sha256(context: &mut Context, data: Vec<u8>) -> Digest {
+        context
+            .hash(
+                &MaxBuffer::try_from(data).expect("Failed to create MaxBuffer"),
+                HashingAlgorithm::Sha256,
+                Hierarchy::Null,
+            )
+            .expect("Failed to compute hash")
+            .0
+    }
+
+    fn sign(context: &mut Context, key_handle: KeyHandle, digest: &Digest) -> Signature {
+        let scheme = TPMT_SIG_SCHEME {
+            scheme: TPM2_ALG_NULL,
+            details: Default::default(),
+        };
+        let validation = TPMT_TK_HASHCHECK {
+            tag: TPM2_ST_HASHCHECK,
+            hierarchy: TPM2_RH_NULL,
+            digest: Default::default(),
+        };
+        context
+            .sign(
+                key_handle,
+                digest,
+                scheme,
+                validation
+                    .try_into()
+                    .expect("Failed to create validation ticket"),
+            )
+            .expect("Failed to sign digest")
+    }
     #[test]
     fn test_policy_signed() {
         let mut context = create_ctx_with_session();
@@ -79,6 +120,72 @@ mod test_policy_signed {
             )
             .expect("Call to policy_signed failed");
     }
+
+    #[test]
+    fn test_policy_signed_authorizes_session() {
+        let mut context = create_ctx_with_session();
+        let key_handle = context
+            .create_primary(Hierarchy::Owner, &signing_key_pub(), None, None, None, None)
+            .expect("Failed to create signing key")
+            .key_handle;
+        let key_name = context
+            .tr_get_name(key_handle.into())
+            .expect("Failed to get name of signing key");
+
+        // With an empty nonce_tpm, cp_hash_a and policy_ref and no
+        // expiration the aHash is just the hash of the zero INT32 expiration.
+        let a_hash = sha256(&mut context, vec![0u8; 4]);
+        let signature = sign(&mut context, key_handle, &a_hash);
+
+        let policy_session = start_policy_session(&mut context, SessionType::Policy);
+        let _ = context
+            .policy_signed(
+                policy_session,
+                key_handle.into(),
+                Nonce::default(),
+                Digest::default(),
+                Nonce::default(),
+                None,
+                signature,
+            )
+            .expect("Call to policy_signed failed");
+        let policy_digest = context
+            .policy_get_digest(policy_session)
+            .expect("Failed to call policy_get_digest");
+
+        // policyDigest' = H(policyDigest_old || TPM_CC_PolicySigned || authObject->Name)
+        // policyDigest_new = H(policyDigest' || policyRef)
+        let mut extension = vec![0u8; 32];
+        extension.extend_from_slice(&TPM2_CC_PolicySigned.to_be_bytes());
+        extension.extend_from_slice(key_name.value());
+        let intermediate_digest = sha256(&mut context, extension);
+        assert_eq!(
+            sha256(&mut context, intermediate_digest.value().to_vec()),
+            policy_digest
+        );
+
+        // A signature over anything other than the aHash is rejected.
+        let other_digest = sha256(&mut context, vec![1u8; 4]);
+        let other_signature = sign(&mut context, key_handle, &other_digest);
+        let policy_session = start_policy_session(&mut context, SessionType::Policy);
+        match context.policy_signed(
+            policy_session,
+            key_handle.into(),
+            Nonce::default(),
+            Digest::default(),
+            Nonce::default(),
+            None,
+            other_signature,
+        ) {
+            Err(Error::Tss2Error(rc)) => {
+                assert_eq!(rc.kind(), Some(Tss2ResponseCodeKind::Signature))
+            }
+            other => panic!(
+                "Policy signed with a wrong signature did not fail: {:?}",
+                other
+            ),
+        }
+    }
 }
 
 mod test_policy_secret {
@@ -141,6 +248,53 @@ mod test_policy_secret {
     }
 }
 
+mod test_policy_ticket {
+    use crate::common::{create_ctx_with_session, start_policy_session};
+    use std::{convert::TryFrom, time::Duration};
+    use tss_esapi::{
+        constants::{tss::TPM2_RH_ENDORSEMENT, SessionType},
+        handles::AuthHandle,
+        structures::{Digest, Name, Nonce},
+        Error,
+    };
+
+    #[test]
+    fn test_policy_ticket() {
+        let mut context = create_ctx_with_session();
+
+        let policy_session = start_policy_session(&mut context, SessionType::Policy);
+        let (timeout, ticket) = context
+            .policy_secret(
+                policy_session,
+                AuthHandle::Endorsement,
+                Nonce::default(),
+                Digest::default(),
+                Nonce::default(),
+                Some(Duration::from_secs(3600)),
+            )
+            .expect("Failed to call policy_secret");
+
+        // A positive expiration only yields a NULL ticket, and the TPM
+        // must refuse to accept it as proof of the authorization.
+        let replay_policy_session = start_policy_session(&mut context, SessionType::Policy);
+        let result = context.execute_without_session(|ctx| {
+            ctx.policy_ticket(
+                replay_policy_session,
+                timeout,
+                Digest::default(),
+                Nonce::default(),
+                Name::try_from(TPM2_RH_ENDORSEMENT.to_be_bytes().to_vec())
+                    .expect("Failed to create name"),
+                ticket,
+            )
+        });
+        match result {
+            Err(Error::Tss2Error(_)) => (),
+            other => panic!("Replaying a NULL ticket did not fail: {:?}", other),
+        }
+    }
+}
+
 mod test_policy_or {
     use crate::common::{create_ctx_without_session, get_pcr_policy_digest};
     use std::convert::TryFrom;
@@ -377,9 +531,12 @@ mod test_policy_physical_presence {
     use std::convert::TryFrom;
     use tss_esapi::{
         attributes::SessionAttributesBuilder,
-        constants::SessionType,
-        interface_types::{algorithm::HashingAlgorithm, session_handles::PolicySession},
-        structures::SymmetricDefinition,
+        constants::{tss::TPM2_CC_PolicyPhysicalPresence, SessionType},
+        interface_types::{
+            algorithm::HashingAlgorithm, resource_handles::Hierarchy,
+            session_handles::PolicySession,
+        },
+        structures::{MaxBuffer, SymmetricDefinition},
     };
     #[test]
     fn test_policy_physical_presence() {
@@ -414,17 +571,151 @@ mod test_policy_physical_presence {
             .policy_physical_presence(trial_policy_session)
             .unwrap();
     }
+
+    #[test]
+    fn test_policy_physical_presence_digest() {
+        let mut context = create_ctx_without_session();
+        let trial_policy_auth_session = context
+            .start_auth_session(
+                None,
+                None,
+                None,
+                SessionType::Trial,
+                SymmetricDefinition::AES_256_CFB,
+                HashingAlgorithm::Sha256,
+            )
+            .expect("Start auth session failed")
+            .expect("Start auth session returned a NONE handle");
+        let trial_policy_session = PolicySession::try_from(trial_policy_auth_session)
+            .expect("Failed to convert auth session into policy session");
+        context
+            .policy_physical_presence(trial_policy_session)
+            .expect("Failed to call policy_physical_presence");
+        let policy_digest = context
+            .policy_get_digest(trial_policy_session)
+            .expect("Failed to call policy_get_digest");
+
+        // policyDigest_new = H(policyDigest_old || TPM_CC_PolicyPhysicalPresence)
+        let mut extension = vec![0u8; 32];
+        extension.extend_from_slice(&TPM2_CC_PolicyPhysicalPresence.to_be_bytes());
+        let (expected_digest, _) = context
+            .hash(
+                &MaxBuffer::try_from(extension).expect("Failed to create MaxBuffer"),
+                HashingAlgorithm::Sha256,
+                Hierarchy::Null,
+            )
+            .expect("Failed to compute the expected policy digest");
+        assert_eq!(expected_digest, policy_digest);
+    }
+}
+
+mod test_policy_counter_timer {
+    use crate::common::{create_ctx_without_session, start_policy_session};
+    use std::convert::TryFrom;
+    use tss_esapi::{
+        constants::{tss::TPM2_CC_PolicyCounterTimer, ArithmeticOperation, SessionType},
+        interface_types::{algorithm::HashingAlgorithm, resource_handles::Hierarchy},
+        structures::{Digest, MaxBuffer},
+        Context,
+    };
+
+    // Offset of clockInfo.clock in TPMS_TIME_INFO.
+    const CLOCK_OFFSET: u16 = 8;
+
+    fn sha256(context: &mut Context, data: Vec<u8>) -> Digest {
+        context
+            .hash(
+                &MaxBuffer::try_from(data).expect("Failed to create MaxBuffer"),
+                HashingAlgorithm::Sha256,
+                Hierarchy::Null,
+            )
+            .expect("Failed to compute hash")
+            .0
+    }
+
+    fn after_clock_digest(context: &mut Context, clock: u64) -> Digest {
+        let trial_policy_session = start_policy_session(context, SessionType::Trial);
+        context
+            .policy_counter_timer(
+                trial_policy_session,
+                MaxBuffer::try_from(clock.to_be_bytes().to_vec())
+                    .expect("Failed to create MaxBuffer"),
+                CLOCK_OFFSET,
+                ArithmeticOperation::UnsignedGe,
+            )
+            .expect("Failed to call policy_counter_timer");
+        context
+            .policy_get_digest(trial_policy_session)
+            .expect("Failed to call policy_get_digest")
+    }
+
+    #[test]
+    fn test_policy_counter_timer() {
+        let mut context = create_ctx_without_session();
+        let clock = 0x1234_5678u64;
+        let digest = after_clock_digest(&mut context, clock);
+        assert_eq!(digest, after_clock_digest(&mut context, clock));
+        assert_ne!(digest, after_clock_digest(&mut context, clock + 1));
+
+        // args = H(operandB || offset || operation)
+        // policyDigest_new = H(policyDigest_old || TPM_CC_PolicyCounterTimer || args)
+        let mut args = clock.to_be_bytes().to_vec();
+        args.extend_from_slice(&CLOCK_OFFSET.to_be_bytes());
+        args.extend_from_slice(&u16::from(ArithmeticOperation::UnsignedGe).to_be_bytes());
+        let args = sha256(&mut context, args);
+        let mut extension = vec![0u8; 32];
+        extension.extend_from_slice(&TPM2_CC_PolicyCounterTimer.to_be_bytes());
+        extension.extend_from_slice(args.value());
+        assert_eq!(sha256(&mut context, extension), digest);
+
+        // The clock is always at or after zero.
+        let policy_session = start_policy_session(&mut context, SessionType::Policy);
+        context
+            .policy_counter_timer(
+                policy_session,
+                MaxBuffer::try_from(0u64.to_be_bytes().to_vec())
+                    .expect("Failed to create MaxBuffer"),
+                CLOCK_OFFSET,
+                ArithmeticOperation::UnsignedGe,
+            )
+            .expect("Failed to call policy_counter_timer");
+    }
 }
 
 mod test_policy_cp_hash {
-    use crate::common::create_ctx_without_session;
+    use crate::common::{create_ctx_without_session, start_policy_session};
     use std::convert::TryFrom;
     use tss_esapi::{
         attributes::SessionAttributesBuilder,
-        constants::SessionType,
-        interface_types::{algorithm::HashingAlgorithm, session_handles::PolicySession},
-        structures::{Digest, SymmetricDefinition},
+        constants::{
+            tss::{TPM2_CC_GetRandom, TPM2_CC_PolicyCpHash},
+            SessionType, Tss2ResponseCodeKind,
+        },
+        interface_types::{
+            algorithm::HashingAlgorithm, resource_handles::Hierarchy,
+            session_handles::PolicySession,
+        },
+        structures::{Digest, MaxBuffer, SymmetricDefinition},
+        Context, Error, WrapperErrorKind,
     };
+
+    fn sha256(context: &mut Context, data: Vec<u8>) -> Digest {
+        context
+            .hash(
+                &MaxBuffer::try_from(data).expect("Failed to create MaxBuffer"),
+                HashingAlgorithm::Sha256,
+                Hierarchy::Null,
+            )
+            .expect("Failed to compute hash")
+            .0
+    }
+
+    // cpHash = H(commandCode || bytesRequested), GetRandom has no handles.
+    fn get_random_cp_hash(context: &mut Context, bytes_requested: u16) -> Digest {
+        let mut cp = TPM2_CC_GetRandom.to_be_bytes().to_vec();
+        cp.extend_from_slice(&bytes_requested.to_be_bytes());
+        sha256(context, cp)
+    }
     #[test]
     fn test_policy_cp_hash() {
         let mut context = create_ctx_without_session();
@@ -464,17 +755,78 @@ mod test_policy_cp_hash {
             .policy_cp_hash(trial_policy_session, &test_dig)
             .unwrap();
     }
+
+    #[test]
+    fn test_policy_cp_hash_get_random() {
+        let mut context = create_ctx_without_session();
+        let cp_hash = get_random_cp_hash(&mut context, 16);
+
+        let trial_policy_session = start_policy_session(&mut context, SessionType::Trial);
+        context
+            .policy_cp_hash(trial_policy_session, &cp_hash)
+            .expect("Failed to call policy_cp_hash");
+        let policy_digest = context
+            .policy_get_digest(trial_policy_session)
+            .expect("Failed to call policy_get_digest");
+
+        // policyDigest_new = H(policyDigest_old || TPM_CC_PolicyCpHash || cpHashA)
+        let mut extension = vec![0u8; 32];
+        extension.extend_from_slice(&TPM2_CC_PolicyCpHash.to_be_bytes());
+        extension.extend_from_slice(cp_hash.value());
+        assert_eq!(sha256(&mut context, extension), policy_digest);
+
+        // Once bound, the session only accepts the exact same command parameters.
+        let policy_session = start_policy_session(&mut context, SessionType::Policy);
+        context
+            .policy_cp_hash(policy_session, &cp_hash)
+            .expect("Failed to call policy_cp_hash");
+        context
+            .policy_cp_hash(policy_session, &cp_hash)
+            .expect("Failed to call policy_cp_hash with the same cpHash");
+        let other_cp_hash = get_random_cp_hash(&mut context, 32);
+        match context.policy_cp_hash(policy_session, &other_cp_hash) {
+            Err(Error::Tss2Error(rc)) => assert_eq!(rc.kind(), Some(Tss2ResponseCodeKind::CpHash)),
+            other => panic!("Binding a different cpHash did not fail: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_policy_cp_hash_invalid_size() {
+        let mut context = create_ctx_without_session();
+        let trial_policy_session = start_policy_session(&mut context, SessionType::Trial);
+        let invalid_cp_hash =
+            Digest::try_from(vec![0xffu8; 10]).expect("Failed to create digest from data");
+        match context.policy_cp_hash(trial_policy_session, &invalid_cp_hash) {
+            Err(Error::WrapperError(kind)) => assert_eq!(kind, WrapperErrorKind::WrongParamSize),
+            other => panic!("Invalid cpHash size was not rejected: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_policy_cp_hash_session_algorithm_mismatch() {
+        let mut context = create_ctx_without_session();
+        // The session uses SHA-256, so a SHA-1 sized cpHash does not match.
+        let trial_policy_session = start_policy_session(&mut context, SessionType::Trial);
+        let sha1_cp_hash =
+            Digest::try_from(vec![0xffu8; 20]).expect("Failed to create digest from data");
+        match context.policy_cp_hash(trial_policy_session, &sha1_cp_hash) {
+            Err(Error::WrapperError(kind)) => assert_eq!(kind, WrapperErrorKind::WrongParamSize),
+            other => panic!("Mismatching cpHash size was not rejected: {:?}", other),
+        }
+    }
 }
 
 mod test_policy_name_hash {
-    use crate::common::create_ctx_without_session;
+    use crate::common::{create_ctx_without_session, start_policy_session};
     use std::convert::TryFrom;
     use tss_esapi::{
         attributes::SessionAttributesBuilder,
         constants::SessionType,
         interface_types::{algorithm::HashingAlgorithm, session_handles::PolicySession},
         structures::{Digest, SymmetricDefinition},
+        Error, WrapperErrorKind,
     };
+
     #[test]
     fn test_policy_name_hash() {
         let mut context = create_ctx_without_session();
@@ -514,6 +866,229 @@ mod test_policy_name_hash {
             .policy_name_hash(trial_policy_session, &test_dig)
             .expect("Call to policy_name_hash failed");
     }
+
+    #[test]
+    fn test_policy_name_hash_digest() {
+        let mut context = create_ctx_without_session();
+        let trial_policy_session = start_policy_session(&mut context, SessionType::Trial);
+        let name_hash = Digest::try_from(vec![
+            252, 200, 17, 232, 137, 217, 130, 51, 54, 22, 184, 131, 2, 134, 99, 130, 175, 216, 159,
+            174, 203, 165, 35, 19, 187, 56, 167, 208, 3, 128, 11, 12,
+        ])
+        .expect("Failed to create digest from data");
+        context
+            .policy_name_hash(trial_policy_session, &name_hash)
+            .expect("Call to policy_name_hash failed");
+
+        // SHA256(0x00 * 32 || TPM2_CC_PolicyNameHash || name_hash)
+        let expected_digest = Digest::try_from(vec![
+            181, 153, 215, 162, 227, 189, 169, 206, 40, 150, 190, 226, 208, 202, 64, 188, 230, 180,
+            75, 133, 160, 224, 56, 107, 31, 47, 228, 43, 220, 253, 150, 33,
+        ])
+        .expect("Failed to create digest from data");
+        assert_eq!(
+            expected_digest,
+            context
+                .policy_get_digest(trial_policy_session)
+                .expect("Failed to call policy_get_digest")
+        );
+    }
+
+    #[test]
+    fn test_policy_name_hash_invalid_size() {
+        let mut context = create_ctx_without_session();
+        let trial_policy_session = start_policy_session(&mut context, SessionType::Trial);
+        let invalid_name_hash =
+            Digest::try_from(vec![0xffu8; 10]).expect("Failed to create digest from data");
+        match context.policy_name_hash(trial_policy_session, &invalid_name_hash) {
+            Err(Error::WrapperError(kind)) => assert_eq!(kind, WrapperErrorKind::WrongParamSize),
+            other => panic!("Invalid name hash size was not rejected: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_policy_name_hash_session_algorithm_mismatch() {
+        let mut context = create_ctx_without_session();
+        let trial_policy_session = start_policy_session(&mut context, SessionType::Trial);
+        // A SHA-1 sized digest does not match the SHA-256 session
+        let sha1_name_hash =
+            Digest::try_from(vec![0xffu8; 20]).expect("Failed to create digest from data");
+        match context.policy_name_hash(trial_policy_session, &sha1_name_hash) {
+            Err(Error::WrapperError(kind)) => assert_eq!(kind, WrapperErrorKind::WrongParamSize),
+            other => panic!("Mismatched name hash size was not rejected: {:?}", other),
+        }
+    }
+}
+
+mod test_policy_duplication_select {
+    use crate::common::{create_ctx_with_session, start_policy_session};
+    use std::convert::TryFrom;
+    use tss_esapi::{
+        attributes::ObjectAttributesBuilder,
+        constants::{SessionType, Tss2ResponseCodeKind},
+        handles::ObjectHandle,
+        interface_types::{
+            algorithm::{HashingAlgorithm, PublicAlgorithm},
+            ecc::EccCurve,
+            resource_handles::Hierarchy,
+        },
+        structures::{
+            EccPoint, EccScheme, KeyDerivationFunctionScheme, Name, Public, PublicBuilder,
+            PublicEccParametersBuilder, SymmetricDefinitionObject,
+        },
+        Error,
+    };
+
+    fn parent_public() -> Public {
+        let object_attributes = ObjectAttributesBuilder::new()
+            .with_fixed_tpm(true)
+            .with_fixed_parent(true)
+            .with_sensitive_data_origin(true)
+            .with_user_with_auth(true)
+            .with_decrypt(true)
+            .with_sign_encrypt(false)
+            .with_restricted(true)
+            .build()
+            .expect("Attributes to be valid");
+        PublicBuilder::new()
+            .with_public_algorithm(PublicAlgorithm::Ecc)
+            .with_name_hashing_algorithm(HashingAlgorithm::Sha256)
+            .with_object_attributes(object_attributes)
+            .with_ecc_parameters(
+                PublicEccParametersBuilder::new()
+                    .with_ecc_scheme(EccScheme::Null)
+                    .with_curve(EccCurve::NistP256)
+                    .with_is_signing_key(false)
+                    .with_is_decryption_key(true)
+                    .with_restricted(true)
+                    .with_symmetric(SymmetricDefinitionObject::AES_128_CFB)
+                    .with_key_derivation_function_scheme(KeyDerivationFunctionScheme::Null)
+                    .build()
+                    .expect("Params to be valid"),
+            )
+            .with_ecc_unique_identifier(&EccPoint::default())
+            .build()
+            .expect("Public to be valid")
+    }
+
+    #[test]
+    fn test_policy_duplication_select() {
+        let mut context = create_ctx_with_session();
+
+        // The primaries are created in different hierarchies so that they
+        // have different names.
+        let parent_handle = context
+            .create_primary(Hierarchy::Owner, &parent_public(), None, None, None, None)
+            .expect("Failed to create parent")
+            .key_handle;
+        let new_parent_handle: ObjectHandle = context
+            .create_primary(Hierarchy::Null, &parent_public(), None, None, None, None)
+            .expect("Failed to create new parent")
+            .key_handle
+            .into();
+        let new_parent_name = context
+            .tr_get_name(new_parent_handle)
+            .expect("Failed to get name of new parent");
+
+        // The object name is not part of the digest when include_object
+        // is false, so any name can be used in the trial session.
+        let digest = context.execute_without_session(|ctx| {
+            let trial_policy_session = start_policy_session(ctx, SessionType::Trial);
+            ctx.policy_duplication_select(
+                trial_policy_session,
+                Name::try_from(Vec::<u8>::new()).expect("Failed to create empty name"),
+                new_parent_name.clone(),
+                false,
+            )
+            .expect("Failed to call policy_duplication_select");
+            ctx.policy_get_digest(trial_policy_session)
+                .expect("Failed to call policy_get_digest")
+        });
+
+        let object_attributes = ObjectAttributesBuilder::new()
+            .with_fixed_tpm(false)
+            .with_fixed_parent(false)
+            .with_sensitive_data_origin(true)
+            .with_user_with_auth(true)
+            .with_decrypt(true)
+            .with_sign_encrypt(true)
+            .with_restricted(false)
+            .build()
+            .expect("Attributes to be valid");
+        let object_public = PublicBuilder::new()
+            .with_public_algorithm(PublicAlgorithm::Ecc)
+            .with_name_hashing_algorithm(HashingAlgorithm::Sha256)
+            .with_object_attributes(object_attributes)
+            .with_auth_policy(&digest)
+            .with_ecc_parameters(
+                PublicEccParametersBuilder::new()
+                    .with_ecc_scheme(EccScheme::Null)
+                    .with_curve(EccCurve::NistP256)
+                    .with_is_signing_key(false)
+                    .with_is_decryption_key(true)
+                    .with_restricted(false)
+                    .with_key_derivation_function_scheme(KeyDerivationFunctionScheme::Null)
+                    .build()
+                    .expect("Params to be valid"),
+            )
+            .with_ecc_unique_identifier(&EccPoint::default())
+            .build()
+            .expect("Public to be valid");
+        let result = context
+            .create(parent_handle, &object_public, None, None, None, None)
+            .expect("Failed to create object");
+        let object_handle: ObjectHandle = context
+            .load(parent_handle, result.out_private, &result.out_public)
+            .expect("Failed to load object")
+            .into();
+        let object_name = context
+            .tr_get_name(object_handle)
+            .expect("Failed to get name of object");
+
+        context.set_sessions((None, None, None));
+
+        // Duplicating onto a parent other than the selected one fails.
+        let policy_session = start_policy_session(&mut context, SessionType::Policy);
+        context
+            .policy_duplication_select(
+                policy_session,
+                object_name.clone(),
+                new_parent_name.clone(),
+                false,
+            )
+            .expect("Failed to call policy_duplication_select");
+        context.set_sessions((Some(policy_session.into()), None, None));
+        match context.duplicate(
+            object_handle,
+            parent_handle.into(),
+            None,
+            SymmetricDefinitionObject::Null,
+        ) {
+            Err(Error::Tss2Error(rc)) => {
+                assert_eq!(rc.kind(), Some(Tss2ResponseCodeKind::PolicyFail))
+            }
+            other => panic!(
+                "Duplicating onto the wrong parent did not fail: {:?}",
+                other
+            ),
+        }
+
+        // Duplicating onto the selected parent succeeds.
+        context.set_sessions((None, None, None));
+        let policy_session = start_policy_session(&mut context, SessionType::Policy);
+        context
+            .policy_duplication_select(policy_session, object_name, new_parent_name, false)
+            .expect("Failed to call policy_duplication_select");
+        context.set_sessions((Some(policy_session.into()), None, None));
+        let _ = context
+            .duplicate(
+                object_handle,
+                new_parent_handle,
+                None,
+                SymmetricDefinitionObject::Null,
+            )
+            .expect("Failed to duplicate object onto the selected parent");
+    }
 }
 
 mod test_policy_authorize {
@@ -778,10 +1353,36 @@ mod test_policy_nv_written {
     use std::convert::TryFrom;
     use tss_esapi::{
         attributes::SessionAttributesBuilder,
-        constants::SessionType,
-        interface_types::{algorithm::HashingAlgorithm, session_handles::PolicySession},
-        structures::SymmetricDefinition,
+        constants::{tss::TPM2_CC_PolicyNvWritten, SessionType},
+        interface_types::{
+            algorithm::HashingAlgorithm, resource_handles::Hierarchy,
+            session_handles::PolicySession,
+        },
+        structures::{Digest, MaxBuffer, SymmetricDefinition},
+        Context,
     };
+
+    fn policy_nv_written_digest(context: &mut Context, written_set: bool) -> Digest {
+        let trial_policy_auth_session = context
+            .start_auth_session(
+                None,
+                None,
+                None,
+                SessionType::Trial,
+                SymmetricDefinition::AES_256_CFB,
+                HashingAlgorithm::Sha256,
+            )
+            .expect("Start auth session failed")
+            .expect("Start auth session returned a NONE handle");
+        let trial_policy_session = PolicySession::try_from(trial_policy_auth_session)
+            .expect("Failed to convert auth session into policy session");
+        context
+            .policy_nv_written(trial_policy_session, written_set)
+            .expect("Failed to call policy_nv_written");
+        context
+            .policy_get_digest(trial_policy_session)
+            .expect("Failed to call policy_get_digest")
+    }
     #[test]
     fn test_policy_nv_written() {
         let mut context = create_ctx_without_session();
@@ -816,15 +1417,41 @@ mod test_policy_nv_written {
             .policy_nv_written(trial_policy_session, true)
             .unwrap();
     }
+
+    #[test]
+    fn test_policy_nv_written_states() {
+        let mut context = create_ctx_without_session();
+        let written_digest = policy_nv_written_digest(&mut context, true);
+        let not_written_digest = policy_nv_written_digest(&mut context, false);
+        assert_ne!(written_digest, not_written_digest);
+
+        // policyDigest_new = H(policyDigest_old || TPM_CC_PolicyNvWritten || writtenSet)
+        for (written_set, actual_digest) in [(1u8, written_digest), (0u8, not_written_digest)] {
+            let mut extension = vec![0u8; 32];
+            extension.extend_from_slice(&TPM2_CC_PolicyNvWritten.to_be_bytes());
+            extension.push(written_set);
+            let (expected_digest, _) = context
+                .hash(
+                    &MaxBuffer::try_from(extension).expect("Failed to create MaxBuffer"),
+                    HashingAlgorithm::Sha256,
+                    Hierarchy::Null,
+                )
+                .expect("Failed to compute the expected policy digest");
+            assert_eq!(expected_digest, actual_digest);
+        }
+    }
 }
 
 mod test_policy_template {
     use crate::common::create_ctx_without_session;
     use std::convert::TryFrom;
     use tss_esapi::{
-        constants::SessionType,
-        interface_types::{algorithm::HashingAlgorithm, session_handles::PolicySession},
-        structures::{Digest, Nonce, SymmetricDefinition},
+        constants::{tss::TPM2_CC_PolicyTemplate, SessionType},
+        interface_types::{
+            algorithm::HashingAlgorithm, resource_handles::Hierarchy,
+            session_handles::PolicySession,
+        },
+        structures::{Digest, MaxBuffer, Nonce, SymmetricDefinition},
     };
     #[test]
     fn basic_policy_template_test() {
@@ -873,4 +1500,147 @@ mod test_policy_template {
 
         assert_eq!(expected_policy_template, policy_digest);
     }
+
+    #[test]
+    fn computed_policy_template_test() {
+        let mut context = create_ctx_without_session();
+
+        let trial_policy_auth_session = context
+            .start_auth_session(
+                None,
+                None,
+                None,
+                SessionType::Trial,
+                SymmetricDefinition::AES_128_CFB,
+                HashingAlgorithm::Sha256,
+            )
+            .expect("Call to start_auth_session failed")
+            .expect("Failed to get proper session");
+        let trial_policy_session = PolicySession::try_from(trial_policy_auth_session)
+            .expect("Failed to convert auth session into policy session");
+
+        let template_hash =
+            Digest::try_from(vec![0xab; 32]).expect("Failed to create template hash digest");
+        context
+            .policy_template(trial_policy_session, &template_hash)
+            .expect("Failed to call policy_template");
+        let policy_digest = context
+            .policy_get_digest(trial_policy_session)
+            .expect("Failed to get policy digest for trial session");
+
+        // policyDigest_new = H(policyDigest_old || TPM_CC_PolicyTemplate || templateHash)
+        // where policyDigest_old is all zeros for a new session.
+        let mut extension = vec![0u8; 32];
+        extension.extend_from_slice(&TPM2_CC_PolicyTemplate.to_be_bytes());
+        extension.extend_from_slice(template_hash.value());
+        let (expected_policy_digest, _) = context
+            .hash(
+                &MaxBuffer::try_from(extension).expect("Failed to create MaxBuffer"),
+                HashingAlgorithm::Sha256,
+                Hierarchy::Null,
+            )
+            .expect("Failed to compute the expected policy digest");
+
+        assert_eq!(expected_policy_digest, policy_digest);
+    }
+}
+
+mod test_policy_authorize_nv {
+    use crate::common::{create_ctx_with_session, start_policy_session};
+    use std::convert::TryFrom;
+    use tss_esapi::{
+        attributes::NvIndexAttributesBuilder,
+        constants::{
+            tss::{TPM2_CC_PolicyAuthorizeNV, TPM2_CC_Unseal, TPM2_ALG_SHA256},
+            SessionType,
+        },
+        handles::{AuthHandle, NvIndexTpmHandle},
+        interface_types::{
+            algorithm::HashingAlgorithm,
+            resource_handles::{Hierarchy, NvAuth, Provision},
+        },
+        nv::storage::NvPublicBuilder,
+        structures::{MaxBuffer, MaxNvBuffer},
+    };
+
+    #[test]
+    fn test_policy_authorize_nv() {
+        let mut context = create_ctx_with_session();
+
+        // Compute the approved policy.
+        let trial_policy_session = start_policy_session(&mut context, SessionType::Trial);
+        context
+            .policy_command_code(trial_policy_session, TPM2_CC_Unseal)
+            .expect("Failed to call policy_command_code");
+        let approved_policy = context
+            .policy_get_digest(trial_policy_session)
+            .expect("Failed to call policy_get_digest");
+
+        // Store the approved policy as a TPMT_HA in an NV index.
+        let nv_index = NvIndexTpmHandle::new(0x01500028).unwrap();
+        let owner_nv_index_attributes = NvIndexAttributesBuilder::new()
+            .with_owner_write(true)
+            .with_owner_read(true)
+            .build()
+            .expect("Failed to create owner nv index attributes");
+        let owner_nv_public = NvPublicBuilder::new()
+            .with_nv_index(nv_index)
+            .with_index_name_algorithm(HashingAlgorithm::Sha256)
+            .with_index_attributes(owner_nv_index_attributes)
+            .with_data_area_size(34)
+            .build()
+            .expect("Failed to build NvPublic for owner");
+        let owner_nv_index_handle = context
+            .nv_define_space(Provision::Owner, None, &owner_nv_public)
+            .expect("Call to nv_define_space failed");
+
+        let mut approved_policy_ha = TPM2_ALG_SHA256.to_be_bytes().to_vec();
+        approved_policy_ha.extend_from_slice(approved_policy.value());
+        let write_result = context.nv_write(
+            NvAuth::Owner,
+            owner_nv_index_handle,
+            &MaxNvBuffer::try_from(approved_policy_ha).expect("Failed to create MaxNvBuffer"),
+            0,
+        );
+        // The name of the index changes when it has been written.
+        let read_public_result = context.nv_read_public(owner_nv_index_handle);
+
+        // Satisfy the approved policy and then authorize it using the NV index.
+        let policy_session = start_policy_session(&mut context, SessionType::Policy);
+        context
+            .policy_command_code(policy_session, TPM2_CC_Unseal)
+            .expect("Failed to call policy_command_code");
+        let authorize_nv_result =
+            context.policy_authorize_nv(AuthHandle::Owner, owner_nv_index_handle, policy_session);
+        let policy_digest_result = context.policy_get_digest(policy_session);
+
+        context
+            .nv_undefine_space(Provision::Owner, owner_nv_index_handle)
+            .expect("Call to nv_undefine_space failed");
+
+        // Report error
+        if let Err(e) = write_result {
+            panic!("Failed to perform nv write: {}", e);
+        }
+        if let Err(e) = authorize_nv_result {
+            panic!("Failed to call policy_authorize_nv: {}", e);
+        }
+        let (_, nv_name) = read_public_result.expect("Failed to read public of nv index");
+        let policy_digest = policy_digest_result.expect("Failed to call policy_get_digest");
+
+        // policyDigest_new = H(0...0 || TPM_CC_PolicyAuthorizeNV || nvIndex->Name)
+        let mut extension = vec![0u8; 32];
+        extension.extend_from_slice(&TPM2_CC_PolicyAuthorizeNV.to_be_bytes());
+        extension.extend_from_slice(nv_name.value());
+        let (expected_policy_digest, _) = context
+            .hash(
+                &MaxBuffer::try_from(extension).expect("Failed to create MaxBuffer"),
+                HashingAlgorithm::Sha256,
+                Hierarchy::Null,
+            )
+            .expect("Failed to compute the expected policy digest");
+
+        assert_eq!(expected_policy_digest, policy_digest);
+        assert_ne!(approved_policy, policy_digest);
+    }
 }
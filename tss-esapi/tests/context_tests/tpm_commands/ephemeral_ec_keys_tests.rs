@@ -1,2 +1,144 @@
 // Copyright 2021 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
+mod test_commit {
+    use crate::common::create_ctx_with_session;
+    use tss_esapi::{
+        attributes::ObjectAttributesBuilder,
+        interface_types::{
+            algorithm::{EccSchemeAlgorithm, HashingAlgorithm, PublicAlgorithm},
+            ecc::EccCurve,
+            resource_handles::Hierarchy,
+        },
+        structures::{
+            EccParameter, EccPoint, EccScheme, KeyDerivationFunctionScheme, PublicBuilder,
+            PublicEccParametersBuilder, SensitiveData,
+        },
+    };
+
+    #[test]
+    fn test_commit() {
+        let mut context = create_ctx_with_session();
+
+        let ecc_parms = PublicEccParametersBuilder::new()
+            .with_ecc_scheme(
+                EccScheme::create(
+                    EccSchemeAlgorithm::EcDaa,
+                    Some(HashingAlgorithm::Sha256),
+                    Some(0),
+                )
+                .expect("Failed to create ECDAA scheme"),
+            )
+            .with_curve(EccCurve::BnP256)
+            .with_is_signing_key(true)
+            .with_is_decryption_key(false)
+            .with_restricted(false)
+            .with_key_derivation_function_scheme(KeyDerivationFunctionScheme::Null)
+            .build()
+            .expect("Failed to build ECDAA parameters");
+
+        let object_attributes = ObjectAttributesBuilder::new()
+            .with_fixed_tpm(true)
+            .with_fixed_parent(true)
+            .with_sensitive_data_origin(true)
+            .with_user_with_auth(true)
+            .with_decrypt(false)
+            .with_sign_encrypt(true)
+            .with_restricted(false)
+            .build()
+            .expect("Failed to build object attributes");
+
+        let public = PublicBuilder::new()
+            .with_public_algorithm(PublicAlgorithm::Ecc)
+            .with_name_hashing_algorithm(HashingAlgorithm::Sha256)
+            .with_object_attributes(object_attributes)
+            .with_ecc_parameters(ecc_parms)
+            .with_ecc_unique_identifier(&EccPoint::default())
+            .build()
+            .expect("Failed to build public");
+
+        let key_handle = context
+            .create_primary(Hierarchy::Owner, &public, None, None, None, None)
+            .expect("Failed to create ECDAA key")
+            .key_handle;
+
+        let (_, _, _, first_counter) = context
+            .commit(
+                key_handle,
+                EccPoint::default(),
+                SensitiveData::default(),
+                EccParameter::default(),
+            )
+            .expect("Failed to call commit");
+
+        let (_, _, _, second_counter) = context
+            .commit(
+                key_handle,
+                EccPoint::default(),
+                SensitiveData::default(),
+                EccParameter::default(),
+            )
+            .expect("Failed to call commit a second time");
+
+        assert!(second_counter > first_counter);
+    }
+}
+
+mod test_ec_ephemeral {
+    use crate::common::create_ctx_without_session;
+    use tss_esapi::{
+        attributes::ObjectAttributesBuilder,
+        interface_types::{
+            algorithm::{HashingAlgorithm, PublicAlgorithm},
+            ecc::EccCurve,
+            resource_handles::Hierarchy,
+        },
+        structures::{
+            EccScheme, HashScheme, KeyDerivationFunctionScheme, PublicBuilder,
+            PublicEccParametersBuilder,
+        },
+    };
+
+    #[test]
+    fn test_ec_ephemeral() {
+        let mut context = create_ctx_without_session();
+
+        let (q, counter) = context
+            .ec_ephemeral(EccCurve::NistP256)
+            .expect("Failed to create ephemeral key");
+
+        assert_ne!(counter, 0);
+        assert_eq!(q.x().value().len(), 32);
+        assert_eq!(q.y().value().len(), 32);
+
+        // The TPM validates that a public ECC point lies on the curve
+        // when it is loaded.
+        let ecc_parms = PublicEccParametersBuilder::new()
+            .with_ecc_scheme(EccScheme::EcDh(HashScheme::new(HashingAlgorithm::Sha256)))
+            .with_curve(EccCurve::NistP256)
+            .with_is_signing_key(false)
+            .with_is_decryption_key(true)
+            .with_restricted(false)
+            .with_key_derivation_function_scheme(KeyDerivationFunctionScheme::Null)
+            .build()
+            .expect("Failed to build ECC parameters");
+
+        let object_attributes = ObjectAttributesBuilder::new()
+            .with_user_with_auth(true)
+            .with_decrypt(true)
+            .build()
+            .expect("Failed to build object attributes");
+
+        let public = PublicBuilder::new()
+            .with_public_algorithm(PublicAlgorithm::Ecc)
+            .with_name_hashing_algorithm(HashingAlgorithm::Sha256)
+            .with_object_attributes(object_attributes)
+            .with_ecc_parameters(ecc_parms)
+            .with_ecc_unique_identifier(&q)
+            .build()
+            .expect("Failed to build public");
+
+        let _ = context
+            .load_external_public(&public, Hierarchy::Null)
+            .expect("Ephemeral point is not on the curve");
+    }
+}
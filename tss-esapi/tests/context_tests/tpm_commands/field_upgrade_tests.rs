@@ -1,2 +1,76 @@
 // Copyright 2021 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
+#[cfg(feature = "field-upgrade-tests")]
+mod test_field_upgrade {
+    use crate::common::{create_ctx_with_session, signing_key_pub};
+    use std::convert::{TryFrom, TryInto};
+    use tss_esapi::{
+        constants::tss::{TPM2_ALG_NULL, TPM2_RH_NULL, TPM2_ST_HASHCHECK},
+        interface_types::resource_handles::{Hierarchy, Provision},
+        structures::{Digest, MaxBuffer},
+        tss2_esys::{TPMT_SIG_SCHEME, TPMT_TK_HASHCHECK},
+        Error,
+    };
+
+    #[test]
+    fn test_field_upgrade_start_and_data() {
+        let mut context = create_ctx_with_session();
+        let key_handle = context
+            .create_primary(Hierarchy::Owner, &signing_key_pub(), None, None, None, None)
+            .expect("Failed to create signing key")
+            .key_handle;
+        let fu_digest = Digest::try_from(vec![0xab; 32]).expect("Failed to create digest");
+        let manifest_signature = context
+            .sign(
+                key_handle,
+                &fu_digest,
+                TPMT_SIG_SCHEME {
+                    scheme: TPM2_ALG_NULL,
+                    details: Default::default(),
+                },
+                TPMT_TK_HASHCHECK {
+                    tag: TPM2_ST_HASHCHECK,
+                    hierarchy: TPM2_RH_NULL,
+                    digest: Default::default(),
+                }
+                .try_into()
+                .expect("Failed to create validation ticket"),
+            )
+            .expect("Failed to sign field upgrade digest");
+
+        // The manifest format is vendor specific so the TPM is not expected
+        // to accept it, only to receive and respond to the commands.
+        match context.field_upgrade_start(
+            Provision::Platform,
+            key_handle,
+            fu_digest,
+            manifest_signature,
+        ) {
+            Ok(()) | Err(Error::Tss2Error(_)) => (),
+            Err(e) => panic!("Failed to send field upgrade start: {}", e),
+        }
+        match context.field_upgrade_data(
+            MaxBuffer::try_from(vec![0xcd; 64]).expect("Failed to create field upgrade data"),
+        ) {
+            Ok(_) | Err(Error::Tss2Error(_)) => (),
+            Err(e) => panic!("Failed to send field upgrade data: {}", e),
+        }
+    }
+}
+
+#[cfg(feature = "field-upgrade-tests")]
+mod test_firmware_read {
+    use crate::common::create_ctx_without_session;
+
+    #[test]
+    fn test_firmware_read_all() {
+        let mut context = create_ctx_without_session();
+        let first_block = context
+            .firmware_read(0)
+            .expect("Failed to read first firmware block");
+        let firmware = context
+            .firmware_read_all()
+            .expect("Failed to read firmware");
+        assert!(firmware.starts_with(first_block.value()));
+    }
+}
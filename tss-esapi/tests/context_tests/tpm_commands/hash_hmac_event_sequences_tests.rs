@@ -1,2 +1,352 @@
 // Copyright 2021 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
+mod test_mac_start {
+    use crate::common::create_ctx_with_session;
+    use std::convert::TryFrom;
+    use tss_esapi::{
+        attributes::ObjectAttributesBuilder,
+        interface_types::{
+            algorithm::{HashingAlgorithm, PublicAlgorithm},
+            resource_handles::Hierarchy,
+        },
+        structures::{Auth, KeyedHashScheme, MaxBuffer, PublicBuilder, PublicKeyedHashParameters},
+    };
+
+    #[test]
+    fn test_mac_one_shot_and_sequence() {
+        let mut context = create_ctx_with_session();
+
+        let object_attributes = ObjectAttributesBuilder::new()
+            .with_sign_encrypt(true)
+            .with_sensitive_data_origin(true)
+            .with_user_with_auth(true)
+            .build()
+            .expect("Failed to build object attributes");
+
+        let key_pub = PublicBuilder::new()
+            .with_public_algorithm(PublicAlgorithm::KeyedHash)
+            .with_name_hashing_algorithm(HashingAlgorithm::Sha256)
+            .with_object_attributes(object_attributes)
+            .with_keyed_hash_parameters(PublicKeyedHashParameters::new(
+                KeyedHashScheme::HMAC_SHA_256,
+            ))
+            .with_keyed_hash_unique_identifier(&Default::default())
+            .build()
+            .expect("Failed to build public strucuture for key.");
+
+        let key_handle = context
+            .create_primary(Hierarchy::Owner, &key_pub, None, None, None, None)
+            .expect("Failed to create HMAC key")
+            .key_handle;
+
+        let first_part = vec![1, 2, 3, 4];
+        let second_part = vec![5, 6, 7, 8];
+
+        let one_shot_mac = context
+            .mac(
+                key_handle,
+                MaxBuffer::try_from([first_part.clone(), second_part.clone()].concat())
+                    .expect("Failed to create MaxBuffer"),
+                HashingAlgorithm::Sha256,
+            )
+            .expect("Failed to compute MAC");
+
+        let sequence_handle = context
+            .mac_start(key_handle, Auth::default(), HashingAlgorithm::Sha256)
+            .expect("Failed to start MAC sequence");
+
+        context
+            .sequence_update(
+                sequence_handle.into(),
+                MaxBuffer::try_from(first_part).expect("Failed to create MaxBuffer"),
+            )
+            .expect("Failed to update MAC sequence");
+
+        let (sequence_mac, _) = context
+            .sequence_complete(
+                sequence_handle.into(),
+                MaxBuffer::try_from(second_part).expect("Failed to create MaxBuffer"),
+                Hierarchy::Null,
+            )
+            .expect("Failed to complete MAC sequence");
+
+        assert_eq!(one_shot_mac.value().len(), 32);
+        assert_eq!(one_shot_mac, sequence_mac);
+    }
+}
+
+mod test_hmac_start {
+    use crate::common::create_ctx_with_session;
+    use std::convert::TryFrom;
+    use tss_esapi::{
+        attributes::ObjectAttributesBuilder,
+        interface_types::{
+            algorithm::{HashingAlgorithm, PublicAlgorithm},
+            resource_handles::Hierarchy,
+        },
+        structures::{Auth, KeyedHashScheme, MaxBuffer, PublicBuilder, PublicKeyedHashParameters},
+    };
+
+    #[test]
+    fn test_hmac_one_shot_and_sequence() {
+        let mut context = create_ctx_with_session();
+
+        let object_attributes = ObjectAttributesBuilder::new()
+            .with_sign_encrypt(true)
+            .with_sensitive_data_origin(true)
+            .with_user_with_auth(true)
+            .build()
+            .expect("Failed to build object attributes");
+
+        let key_pub = PublicBuilder::new()
+            .with_public_algorithm(PublicAlgorithm::KeyedHash)
+            .with_name_hashing_algorithm(HashingAlgorithm::Sha256)
+            .with_object_attributes(object_attributes)
+            .with_keyed_hash_parameters(PublicKeyedHashParameters::new(
+                KeyedHashScheme::HMAC_SHA_256,
+            ))
+            .with_keyed_hash_unique_identifier(&Default::default())
+            .build()
+            .expect("Failed to build public strucuture for key.");
+
+        let key_handle = context
+            .create_primary(Hierarchy::Owner, &key_pub, None, None, None, None)
+            .expect("Failed to create HMAC key")
+            .key_handle;
+
+        let first_part = vec![1, 2, 3, 4];
+        let second_part = vec![5, 6, 7, 8];
+
+        let one_shot_hmac = context
+            .hmac(
+                key_handle.into(),
+                &MaxBuffer::try_from([first_part.clone(), second_part.clone()].concat())
+                    .expect("Failed to create MaxBuffer"),
+                HashingAlgorithm::Sha256,
+            )
+            .expect("Failed to compute HMAC");
+
+        let sequence_handle = context
+            .hmac_start(key_handle, Auth::default(), HashingAlgorithm::Sha256)
+            .expect("Failed to start HMAC sequence");
+
+        context
+            .sequence_update(
+                sequence_handle.into(),
+                MaxBuffer::try_from(first_part).expect("Failed to create MaxBuffer"),
+            )
+            .expect("Failed to update HMAC sequence");
+
+        let (sequence_hmac, _) = context
+            .sequence_complete(
+                sequence_handle.into(),
+                MaxBuffer::try_from(second_part).expect("Failed to create MaxBuffer"),
+                Hierarchy::Null,
+            )
+            .expect("Failed to complete HMAC sequence");
+
+        assert_eq!(one_shot_hmac.value().len(), 32);
+        assert_eq!(one_shot_hmac, sequence_hmac);
+    }
+}
+
+mod test_hash_sequence_start {
+    use crate::common::create_ctx_with_session;
+    use std::convert::TryFrom;
+    use tss_esapi::{
+        constants::tss::{
+            TPM2_CC_SequenceComplete, TPM2_RC_SUCCESS, TPM2_RH_OWNER, TPM2_RS_PW,
+            TPM2_ST_HASHCHECK, TPM2_ST_NO_SESSIONS, TPM2_ST_SESSIONS,
+        },
+        interface_types::{
+            algorithm::HashingAlgorithm, resource_handles::Hierarchy, session_handles::AuthSession,
+        },
+        structures::{Auth, MaxBuffer, Ticket},
+        tcti_ldr::{MockTcti, TctiNameConf},
+        Context,
+    };
+
+    #[test]
+    fn test_hash_one_shot_and_sequence() {
+        let mut context = create_ctx_with_session();
+
+        let first_part = vec![1, 2, 3, 4];
+        let second_part = vec![5, 6, 7, 8];
+
+        let (one_shot_digest, _) = context
+            .hash(
+                &MaxBuffer::try_from([first_part.clone(), second_part.clone()].concat())
+                    .expect("Failed to create MaxBuffer"),
+                HashingAlgorithm::Sha256,
+                Hierarchy::Owner,
+            )
+            .expect("Failed to compute hash");
+
+        let sequence_handle = context
+            .hash_sequence_start(Auth::default(), HashingAlgorithm::Sha256)
+            .expect("Failed to start hash sequence");
+
+        context
+            .sequence_update(
+                sequence_handle.into(),
+                MaxBuffer::try_from(first_part).expect("Failed to create MaxBuffer"),
+            )
+            .expect("Failed to update hash sequence");
+
+        let (sequence_digest, _) = context
+            .sequence_complete(
+                sequence_handle.into(),
+                MaxBuffer::try_from(second_part).expect("Failed to create MaxBuffer"),
+                Hierarchy::Owner,
+            )
+            .expect("Failed to complete hash sequence");
+
+        assert_eq!(one_shot_digest.value().len(), 32);
+        assert_eq!(one_shot_digest, sequence_digest);
+    }
+
+    #[test]
+    fn test_sequence_complete_hierarchy_marshalling() {
+        let mock_tcti = MockTcti::new();
+        let sequence_tpm_handle = 0x8000_0000u32;
+        // Marshalled TPM2_HashSequenceStart response: header, followed
+        // by the handle of the sequence object.
+        let mut response = Vec::new();
+        response.extend_from_slice(&TPM2_ST_NO_SESSIONS.to_be_bytes());
+        response.extend_from_slice(&14u32.to_be_bytes());
+        response.extend_from_slice(&TPM2_RC_SUCCESS.to_be_bytes());
+        response.extend_from_slice(&sequence_tpm_handle.to_be_bytes());
+        mock_tcti.push_response(response);
+        // Marshalled TPM2_SequenceComplete response: header, followed by the
+        // parameter size, the result, a hash check ticket with an empty digest
+        // and the response of the password session.
+        let mut response = Vec::new();
+        response.extend_from_slice(&TPM2_ST_SESSIONS.to_be_bytes());
+        response.extend_from_slice(&61u32.to_be_bytes());
+        response.extend_from_slice(&TPM2_RC_SUCCESS.to_be_bytes());
+        response.extend_from_slice(&42u32.to_be_bytes());
+        response.extend_from_slice(&32u16.to_be_bytes());
+        response.extend_from_slice(&[0xaa; 32]);
+        response.extend_from_slice(&TPM2_ST_HASHCHECK.to_be_bytes());
+        response.extend_from_slice(&TPM2_RH_OWNER.to_be_bytes());
+        response.extend_from_slice(&0u16.to_be_bytes());
+        response.extend_from_slice(&[0x00, 0x00, 0x01, 0x00, 0x00]);
+        mock_tcti.push_response(response);
+
+        let mut context =
+            Context::new(TctiNameConf::Mock(mock_tcti.clone())).expect("Failed to create context");
+        let sequence_handle = context
+            .hash_sequence_start(Auth::default(), HashingAlgorithm::Sha256)
+            .expect("Failed to start hash sequence through the mock TCTI");
+        context.set_sessions((Some(AuthSession::Password), None, None));
+        let (digest, ticket) = context
+            .sequence_complete(
+                sequence_handle.into(),
+                MaxBuffer::default(),
+                Hierarchy::Owner,
+            )
+            .expect("Failed to complete hash sequence through the mock TCTI");
+        assert_eq!(digest.value(), [0xaa; 32]);
+        assert_eq!(ticket.hierarchy(), Hierarchy::Owner);
+
+        // The hierarchy is passed to the ESAPI as an ESYS handle with version 3
+        // of the library and as a TPM handle with version 2; either way the TPM
+        // handle of the hierarchy has to be sent to the TPM.
+        let commands = mock_tcti.commands();
+        assert_eq!(commands.len(), 2);
+        let command = &commands[1];
+        assert_eq!(command.len(), 33);
+        assert_eq!(command[6..10], TPM2_CC_SequenceComplete.to_be_bytes());
+        assert_eq!(command[10..14], sequence_tpm_handle.to_be_bytes());
+        assert_eq!(command[14..18], 9u32.to_be_bytes());
+        assert_eq!(command[18..22], TPM2_RS_PW.to_be_bytes());
+        assert_eq!(command[27..29], 0u16.to_be_bytes());
+        assert_eq!(command[29..33], TPM2_RH_OWNER.to_be_bytes());
+    }
+}
+
+mod test_event_sequence_complete {
+    use crate::common::create_ctx_with_session;
+    use sha2::{Digest as _, Sha256};
+    use std::convert::TryFrom;
+    use tss_esapi::{
+        handles::PcrHandle,
+        interface_types::{
+            algorithm::HashingAlgorithm, resource_handles::Hierarchy, session_handles::AuthSession,
+        },
+        structures::{Auth, MaxBuffer, PcrSelectionListBuilder, PcrSlot},
+    };
+
+    #[test]
+    fn test_event_sequence_complete_into_pcr() {
+        let mut context = create_ctx_with_session();
+        let pcr_ses = context.sessions().0;
+        context.execute_with_session(pcr_ses, |ctx| ctx.pcr_reset(PcrHandle::Pcr16).unwrap());
+
+        let first_part = vec![1, 2, 3, 4];
+        let second_part = vec![5, 6, 7, 8];
+
+        // An event sequence is started with the Null hashing algorithm.
+        let sequence_handle = context
+            .hash_sequence_start(Auth::default(), HashingAlgorithm::Null)
+            .expect("Failed to start event sequence");
+        context
+            .execute_with_session(Some(AuthSession::Password), |ctx| {
+                ctx.sequence_update(
+                    sequence_handle.into(),
+                    MaxBuffer::try_from(first_part.clone()).expect("Failed to create MaxBuffer"),
+                )
+            })
+            .expect("Failed to update event sequence");
+        let digests = context
+            .execute_with_sessions((pcr_ses, Some(AuthSession::Password), None), |ctx| {
+                ctx.event_sequence_complete(
+                    Some(PcrHandle::Pcr16),
+                    sequence_handle.into(),
+                    MaxBuffer::try_from(second_part.clone()).expect("Failed to create MaxBuffer"),
+                )
+            })
+            .expect("Failed to complete event sequence");
+
+        // The digests are identified by their size, as the list holds one per active bank.
+        let bank_digest = |digest_size: usize| {
+            digests
+                .value()
+                .iter()
+                .find(|digest| digest.len() == digest_size)
+                .expect("Missing digest for an active bank")
+        };
+
+        let data = [first_part, second_part].concat();
+        assert_eq!(bank_digest(32).value(), &Sha256::digest(&data)[..]);
+
+        let pcr_selection_list = PcrSelectionListBuilder::new()
+            .with_selection(HashingAlgorithm::Sha1, &[PcrSlot::Slot16])
+            .with_selection(HashingAlgorithm::Sha256, &[PcrSlot::Slot16])
+            .build();
+        let (_, _, pcr_data) =
+            context.execute_without_session(|ctx| ctx.pcr_read(&pcr_selection_list).unwrap());
+        for (hashing_algorithm, digest_size) in
+            [(HashingAlgorithm::Sha1, 20), (HashingAlgorithm::Sha256, 32)].iter()
+        {
+            let digest = bank_digest(*digest_size);
+            let pcr_value = pcr_data
+                .pcr_bank(*hashing_algorithm)
+                .unwrap()
+                .pcr_value(PcrSlot::Slot16)
+                .unwrap();
+            // The PCR was reset, so it contains the extension of zeros with the digest.
+            let extended = [vec![0; digest.value().len()], digest.value().to_vec()].concat();
+            let (expected_pcr_value, _) = context
+                .execute_without_session(|ctx| {
+                    ctx.hash(
+                        &MaxBuffer::try_from(extended).unwrap(),
+                        *hashing_algorithm,
+                        Hierarchy::Null,
+                    )
+                })
+                .expect("Failed to compute the expected PCR value");
+            assert_eq!(pcr_value, &expected_pcr_value);
+        }
+    }
+}
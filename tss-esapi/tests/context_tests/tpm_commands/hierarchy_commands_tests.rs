@@ -28,6 +28,192 @@ mod test_create_primary {
     }
 }
 
+mod test_hierarchy_control {
+    use crate::common::create_ctx_with_session;
+    use tss_esapi::{
+        abstraction::ek,
+        constants::response_code::Tss2ResponseCodeKind,
+        interface_types::{
+            algorithm::AsymmetricAlgorithm,
+            resource_handles::{Hierarchy, Provision},
+        },
+        Error, WrapperErrorKind,
+    };
+
+    #[test]
+    fn test_hierarchy_control() {
+        let mut context = create_ctx_with_session();
+
+        context
+            .hierarchy_control(Provision::Platform, Hierarchy::Endorsement, false)
+            .expect("Failed to disable the endorsement hierarchy");
+
+        let ek_result = ek::create_ek_object(&mut context, AsymmetricAlgorithm::Rsa, None);
+
+        // Re-enabling requires the platform authorization.
+        context
+            .hierarchy_control(Provision::Platform, Hierarchy::Endorsement, true)
+            .expect("Failed to enable the endorsement hierarchy");
+
+        if let Err(Error::Tss2Error(rc)) = ek_result {
+            assert_eq!(rc.kind(), Some(Tss2ResponseCodeKind::Hierarchy));
+        } else {
+            panic!("Creating an EK in a disabled endorsement hierarchy should fail");
+        }
+
+        let ek_handle = ek::create_ek_object(&mut context, AsymmetricAlgorithm::Rsa, None)
+            .expect("Failed to create an EK after enabling the endorsement hierarchy");
+        context.flush_context(ek_handle.into()).unwrap();
+    }
+
+    #[test]
+    fn test_hierarchy_control_null_hierarchy() {
+        let mut context = create_ctx_with_session();
+
+        if let Err(Error::WrapperError(kind)) =
+            context.hierarchy_control(Provision::Platform, Hierarchy::Null, false)
+        {
+            assert_eq!(kind, WrapperErrorKind::InvalidParam);
+        } else {
+            panic!("Controlling the null hierarchy should fail");
+        }
+    }
+}
+
+mod test_set_primary_policy {
+    use crate::common::{create_ctx_with_session, decryption_key_pub};
+    use std::convert::TryFrom;
+    use tss_esapi::{
+        constants::{tss::TPM2_CC_CreatePrimary, SessionType},
+        handles::SessionHandle,
+        interface_types::{
+            algorithm::HashingAlgorithm,
+            resource_handles::{Hierarchy, Provision},
+            session_handles::PolicySession,
+        },
+        structures::{Digest, SymmetricDefinition},
+    };
+
+    #[test]
+    fn test_set_primary_policy() {
+        let mut context = create_ctx_with_session();
+
+        // Compute a policy allowing only TPM2_CreatePrimary.
+        let trial_policy_auth_session = context
+            .start_auth_session(
+                None,
+                None,
+                None,
+                SessionType::Trial,
+                SymmetricDefinition::AES_256_CFB,
+                HashingAlgorithm::Sha256,
+            )
+            .expect("Start auth session failed")
+            .expect("Start auth session returned a NONE handle");
+        let trial_policy_session = PolicySession::try_from(trial_policy_auth_session)
+            .expect("Failed to convert auth session into policy session");
+        context
+            .policy_command_code(trial_policy_session, TPM2_CC_CreatePrimary)
+            .expect("Failed to call policy_command_code");
+        let policy_digest = context
+            .policy_get_digest(trial_policy_session)
+            .expect("Failed to call policy_get_digest");
+        context
+            .flush_context(SessionHandle::from(trial_policy_auth_session).into())
+            .expect("Failed to flush the trial session");
+
+        context
+            .set_primary_policy(Provision::Owner, policy_digest, HashingAlgorithm::Sha256)
+            .expect("Call to set_primary_policy failed");
+
+        // Authorize the creation of a primary key in the owner hierarchy with the policy.
+        let policy_auth_session = context
+            .start_auth_session(
+                None,
+                None,
+                None,
+                SessionType::Policy,
+                SymmetricDefinition::AES_256_CFB,
+                HashingAlgorithm::Sha256,
+            )
+            .expect("Start auth session failed")
+            .expect("Start auth session returned a NONE handle");
+        let policy_session = PolicySession::try_from(policy_auth_session)
+            .expect("Failed to convert auth session into policy session");
+        context
+            .policy_command_code(policy_session, TPM2_CC_CreatePrimary)
+            .expect("Failed to call policy_command_code");
+        let create_primary_result =
+            context.execute_with_session(Some(policy_auth_session), |ctx| {
+                ctx.create_primary(
+                    Hierarchy::Owner,
+                    &decryption_key_pub(),
+                    None,
+                    None,
+                    None,
+                    None,
+                )
+            });
+
+        // Clear the policy again.
+        context
+            .set_primary_policy(Provision::Owner, Digest::default(), HashingAlgorithm::Null)
+            .expect("Call to set_primary_policy failed when clearing the policy");
+
+        let key_handle = create_primary_result
+            .expect("Failed to create primary key with the policy session")
+            .key_handle;
+        context.flush_context(key_handle.into()).unwrap();
+    }
+}
+
+mod test_change_primary_seeds {
+    use crate::common::{create_ctx_with_session, decryption_key_pub};
+    use tss_esapi::{
+        interface_types::resource_handles::{Hierarchy, Provision},
+        structures::Name,
+        Context,
+    };
+
+    fn primary_key_name(context: &mut Context, hierarchy: Hierarchy) -> Name {
+        let key_handle = context
+            .create_primary(hierarchy, &decryption_key_pub(), None, None, None, None)
+            .expect("Failed to create primary key")
+            .key_handle;
+        let (_, name, _) = context
+            .read_public(key_handle)
+            .expect("Failed to read public of primary key");
+        context.flush_context(key_handle.into()).unwrap();
+        name
+    }
+
+    #[test]
+    fn test_change_pps() {
+        let mut context = create_ctx_with_session();
+
+        let name_before = primary_key_name(&mut context, Hierarchy::Platform);
+        context
+            .change_pps(Provision::Platform)
+            .expect("Call to change_pps failed");
+        let name_after = primary_key_name(&mut context, Hierarchy::Platform);
+
+        assert_ne!(name_before, name_after);
+    }
+
+    #[test]
+    fn test_change_eps() {
+        let mut context = create_ctx_with_session();
+
+        let name_before = primary_key_name(&mut context, Hierarchy::Endorsement);
+        context
+            .change_eps(Provision::Platform)
+            .expect("Call to change_eps failed");
+        let name_after = primary_key_name(&mut context, Hierarchy::Endorsement);
+
+        assert_ne!(name_before, name_after);
+    }
+}
+
 mod test_clear {
     use crate::common::create_ctx_with_session;
     use tss_esapi::handles::AuthHandle;
@@ -59,7 +245,11 @@ mod test_change_auth {
     use crate::common::{create_ctx_with_session, decryption_key_pub};
     use std::convert::TryFrom;
     use tss_esapi::{
-        handles::AuthHandle, interface_types::resource_handles::Hierarchy, structures::Auth,
+        constants::response_code::Tss2ResponseCodeKind,
+        handles::{AuthHandle, ObjectHandle},
+        interface_types::resource_handles::Hierarchy,
+        structures::Auth,
+        Error,
     };
 
     #[test]
@@ -124,4 +314,114 @@ mod test_change_auth {
             .hierarchy_change_auth(AuthHandle::Platform, Default::default())
             .unwrap();
     }
+
+    #[test]
+    fn test_hierarchy_change_auth_enforced() {
+        let mut context = create_ctx_with_session();
+
+        let random_digest = context.get_random(16).unwrap();
+        let new_auth = Auth::try_from(random_digest.value().to_vec()).unwrap();
+
+        context
+            .hierarchy_change_auth(AuthHandle::Owner, new_auth.clone())
+            .expect("Failed to set the owner auth");
+
+        // The previous, empty, auth value is no longer accepted.
+        context
+            .tr_set_auth(ObjectHandle::Owner, &Auth::default())
+            .unwrap();
+        let create_primary_result = context.create_primary(
+            Hierarchy::Owner,
+            &decryption_key_pub(),
+            None,
+            None,
+            None,
+            None,
+        );
+
+        context.tr_set_auth(ObjectHandle::Owner, &new_auth).unwrap();
+        let key_handle = context
+            .create_primary(
+                Hierarchy::Owner,
+                &decryption_key_pub(),
+                None,
+                None,
+                None,
+                None,
+            )
+            .expect("Failed to create a primary key with the new owner auth")
+            .key_handle;
+        context.flush_context(key_handle.into()).unwrap();
+
+        // Clear the owner auth again.
+        context
+            .hierarchy_change_auth(AuthHandle::Owner, Default::default())
+            .expect("Failed to clear the owner auth");
+
+        if let Err(Error::Tss2Error(rc)) = create_primary_result {
+            assert_eq!(rc.kind(), Some(Tss2ResponseCodeKind::BadAuth));
+        } else {
+            panic!("Creating a primary key with the old owner auth should fail");
+        }
+    }
+}
+
+mod test_create_primary_rsa_key_bits {
+    use crate::common::create_ctx_with_session;
+    use tss_esapi::{
+        interface_types::{
+            algorithm::{HashingAlgorithm, RsaSchemeAlgorithm},
+            key_bits::RsaKeyBits,
+            resource_handles::Hierarchy,
+        },
+        structures::{
+            Public, PublicParameters, PublicRsaParametersBuilder, RsaExponent, RsaScheme,
+        },
+        utils,
+    };
+
+    #[test]
+    fn test_create_primary_rsa_3072() {
+        let mut context = create_ctx_with_session();
+        let rsa_scheme =
+            RsaScheme::create(RsaSchemeAlgorithm::RsaSsa, Some(HashingAlgorithm::Sha256))
+                .expect("Failed to create RSA scheme");
+        let key_parameters = PublicRsaParametersBuilder::new_unrestricted_signing_key(
+            rsa_scheme,
+            RsaKeyBits::Rsa3072,
+            RsaExponent::default(),
+        )
+        .build()
+        .expect("Failed to build RSA parameters");
+        // Not all simulators support 3072-bit RSA keys.
+        if context
+            .test_parms(PublicParameters::Rsa(key_parameters))
+            .is_err()
+        {
+            return;
+        }
+
+        let public = utils::create_unrestricted_signing_rsa_public(
+            rsa_scheme,
+            RsaKeyBits::Rsa3072,
+            RsaExponent::default(),
+        )
+        .expect("Failed to create public structure");
+        let key_handle = context
+            .create_primary(Hierarchy::Owner, &public, None, None, None, None)
+            .expect("Failed to create 3072-bit primary key")
+            .key_handle;
+        let (public, _, _) = context
+            .read_public(key_handle)
+            .expect("Failed to read public area");
+        match public {
+            Public::Rsa {
+                parameters, unique, ..
+            } => {
+                assert_eq!(RsaKeyBits::Rsa3072, parameters.key_bits());
+                assert_eq!(384, unique.value().len());
+            }
+            _ => panic!("The key is not an RSA key"),
+        }
+    }
 }
@@ -113,6 +113,58 @@ mod test_pcr_extend_reset {
     }
 }
 
+mod test_pcr_reset {
+    use crate::common::create_ctx_with_session;
+    use tss_esapi::{constants::response_code::Tss2ResponseCodeKind, handles::PcrHandle, Error};
+
+    #[test]
+    fn test_pcr_reset_non_resettable_pcr() {
+        // PCR 0 holds measurements of the platform firmware and
+        // cannot be reset from the locality in which we are running.
+        let mut context = create_ctx_with_session();
+        let pcr_ses = context.sessions().0;
+
+        let result = context.execute_with_session(pcr_ses, |ctx| ctx.pcr_reset(PcrHandle::Pcr0));
+        if let Err(Error::Tss2Error(rc)) = result {
+            assert_eq!(rc.kind(), Some(Tss2ResponseCodeKind::Locality));
+        } else {
+            panic!("Resetting PCR 0 should fail with a locality error");
+        }
+    }
+}
+
+mod test_pcr_allocate {
+    use crate::common::create_ctx_with_session;
+    use tss_esapi::{
+        constants::CapabilityType, interface_types::resource_handles::Provision,
+        structures::CapabilityData,
+    };
+
+    #[test]
+    fn test_pcr_allocate() {
+        let mut context = create_ctx_with_session();
+
+        // Request the current allocation so that the banks
+        // used by the other tests are kept after a reset.
+        let (capability_data, _) = context
+            .execute_without_session(|ctx| ctx.get_capability(CapabilityType::AssignedPCR, 0, 1))
+            .expect("Failed to get the assigned PCRs");
+        let pcr_allocation = match capability_data {
+            CapabilityData::AssignedPCR(pcr_selection_list) => pcr_selection_list,
+            _ => panic!("Received unexpected capability data"),
+        };
+
+        let (allocation_success, max_pcr, size_needed, size_available) = context
+            .pcr_allocate(Provision::Platform, pcr_allocation)
+            .expect("Call to pcr_allocate failed");
+
+        assert!(allocation_success);
+        assert!(max_pcr >= 24);
+        assert_ne!(size_needed, 0);
+        assert!(size_needed <= size_available);
+    }
+}
+
 mod test_pcr_read {
     use crate::common::create_ctx_without_session;
     use tss_esapi::{
@@ -176,6 +228,34 @@ mod test_pcr_read {
         assert_eq!(pcr_value.value().len(), TPM2_SHA256_DIGEST_SIZE as usize);
     }
 
+    #[test]
+    fn test_pcr_read_iter_banks() {
+        let mut context = create_ctx_without_session();
+        // The SHA-384 selection is empty so no values are read for it.
+        let pcr_selection_list = PcrSelectionListBuilder::new()
+            .with_selection(HashingAlgorithm::Sha1, &[PcrSlot::Slot0, PcrSlot::Slot1])
+            .with_selection(HashingAlgorithm::Sha256, &[PcrSlot::Slot0, PcrSlot::Slot1])
+            .with_selection(HashingAlgorithm::Sha384, &[])
+            .build();
+        let (_, _, pcr_data) = context.pcr_read(&pcr_selection_list).unwrap();
+
+        let banks: Vec<_> = pcr_data.iter_banks().collect();
+        assert_eq!(banks.len(), 2);
+        for (hashing_algorithm, pcr_values) in banks.iter() {
+            assert!(
+                *hashing_algorithm == HashingAlgorithm::Sha1
+                    || *hashing_algorithm == HashingAlgorithm::Sha256
+            );
+            let pcr_bank = pcr_data
+                .pcr_bank(*hashing_algorithm)
+                .expect("Missing pcr bank");
+            assert_eq!(*pcr_values, pcr_bank.pcr_values());
+            assert_eq!(pcr_values.len(), 2);
+            assert_eq!(Some(&pcr_values[1]), pcr_bank.pcr_value(PcrSlot::Slot1));
+        }
+        assert_ne!(banks[0].0, banks[1].0);
+    }
+
     #[test]
     fn test_pcr_read_large_pcr_selections() {
         // If the pcr Selection contains more then 16 values
@@ -211,3 +291,86 @@ mod test_pcr_read {
         assert_ne!(pcr_selection_list_in, pcr_selection_list_out);
     }
 }
+
+#[cfg(feature = "pcr-auth-tests")]
+mod test_pcr_set_auth {
+    use crate::common::create_ctx_with_session;
+    use std::convert::TryFrom;
+    use tss_esapi::{
+        constants::response_code::Tss2ResponseCodeKind,
+        handles::PcrHandle,
+        interface_types::{algorithm::HashingAlgorithm, resource_handles::Provision},
+        structures::{Auth, Digest, DigestValues},
+        Error,
+    };
+
+    // In the PC client platform PCR 20 to 22 belong to the
+    // authorization value and authorization policy groups.
+    const GROUP_PCR: PcrHandle = PcrHandle::Pcr20;
+
+    fn extend_digests() -> DigestValues {
+        let mut vals = DigestValues::new();
+        vals.set(
+            HashingAlgorithm::Sha256,
+            Digest::try_from(vec![0xab; 32]).unwrap(),
+        );
+        vals
+    }
+
+    #[test]
+    fn test_pcr_set_auth_policy() {
+        let mut context = create_ctx_with_session();
+
+        context
+            .pcr_set_auth_policy(
+                Provision::Platform,
+                Digest::try_from(vec![0x55; 32]).unwrap(),
+                HashingAlgorithm::Sha256,
+                GROUP_PCR,
+            )
+            .expect("Call to pcr_set_auth_policy failed");
+
+        // Remove the policy again.
+        context
+            .pcr_set_auth_policy(
+                Provision::Platform,
+                Digest::default(),
+                HashingAlgorithm::Null,
+                GROUP_PCR,
+            )
+            .expect("Call to pcr_set_auth_policy failed when removing the policy");
+    }
+
+    #[test]
+    fn test_pcr_set_auth_value() {
+        let mut context = create_ctx_with_session();
+        let auth = Auth::try_from(vec![1, 2, 3, 4]).unwrap();
+
+        context
+            .pcr_set_auth_value(GROUP_PCR, auth.clone())
+            .expect("Call to pcr_set_auth_value failed");
+
+        // The old, empty, auth value is no longer accepted.
+        let result = context.pcr_extend(GROUP_PCR, extend_digests());
+        if let Err(Error::Tss2Error(rc)) = result {
+            assert_eq!(rc.kind(), Some(Tss2ResponseCodeKind::BadAuth));
+        } else {
+            panic!("Extending the PCR with the old auth value should fail");
+        }
+
+        context
+            .tr_set_auth(GROUP_PCR.into(), &auth)
+            .expect("Failed to set the auth of the PCR");
+        context
+            .pcr_extend(GROUP_PCR, extend_digests())
+            .expect("Call to pcr_extend failed with the new auth value");
+
+        // Restore the empty auth value.
+        context
+            .pcr_set_auth_value(GROUP_PCR, Auth::default())
+            .expect("Call to pcr_set_auth_value failed when restoring the auth value");
+        context
+            .tr_set_auth(GROUP_PCR.into(), &Auth::default())
+            .expect("Failed to reset the auth of the PCR");
+    }
+}
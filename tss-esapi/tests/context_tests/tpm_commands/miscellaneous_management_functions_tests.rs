@@ -1,2 +1,50 @@
 // Copyright 2021 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
+mod test_pp_commands {
+    use crate::common::create_ctx_with_session;
+    use tss_esapi::{
+        constants::CommandCode,
+        interface_types::resource_handles::Provision,
+        structures::{CommandCodeList, CommandCodeListBuilder},
+    };
+
+    #[test]
+    fn test_pp_commands() {
+        let mut context = create_ctx_with_session();
+        let clear_command_list = CommandCodeListBuilder::new()
+            .with_command_code(CommandCode::Clear)
+            .build()
+            .expect("Failed to build command code list");
+
+        // Require physical presence for Clear
+        context
+            .pp_commands(
+                Provision::Platform,
+                clear_command_list.clone(),
+                CommandCodeList::new(),
+            )
+            .expect("Failed to add Clear to the physical presence commands");
+
+        // No longer require physical presence for Clear
+        context
+            .pp_commands(
+                Provision::Platform,
+                CommandCodeList::new(),
+                clear_command_list,
+            )
+            .expect("Failed to remove Clear from the physical presence commands");
+    }
+}
+
+mod test_set_algorithm_set {
+    use crate::common::create_ctx_with_session;
+    use tss_esapi::interface_types::resource_handles::Provision;
+
+    #[test]
+    fn test_set_algorithm_set() {
+        let mut context = create_ctx_with_session();
+        context
+            .set_algorithm_set(Provision::Platform, 0)
+            .expect("Failed to select the default algorithm set");
+    }
+}
@@ -2,11 +2,14 @@
 // SPDX-License-Identifier: Apache-2.0
 mod test_nv_define_space {
     use crate::common::create_ctx_with_session;
+    use std::convert::TryInto;
     use tss_esapi::{
         attributes::NvIndexAttributesBuilder,
+        constants::PropertyTag,
         handles::NvIndexTpmHandle,
         interface_types::{algorithm::HashingAlgorithm, resource_handles::Provision},
         nv::storage::NvPublicBuilder,
+        Error, WrapperErrorKind,
     };
 
     #[test]
@@ -112,6 +115,42 @@ mod test_nv_define_space {
             .nv_undefine_space(Provision::Platform, platform_nv_index_handle)
             .expect("Call to nv_undefine_space failed");
     }
+
+    #[test]
+    fn test_nv_define_space_oversized_data() {
+        let mut context = create_ctx_with_session();
+
+        let nv_index_max: usize = context
+            .get_tpm_property(PropertyTag::NvIndexMax)
+            .expect("Failed to get TPM_PT_NV_INDEX_MAX")
+            .expect("TPM_PT_NV_INDEX_MAX is not reported by the TPM")
+            .try_into()
+            .unwrap();
+
+        let nv_index = NvIndexTpmHandle::new(0x0150002A).unwrap();
+
+        let owner_nv_index_attributes = NvIndexAttributesBuilder::new()
+            .with_owner_write(true)
+            .with_owner_read(true)
+            .build()
+            .expect("Failed to create owner nv index attributes");
+
+        let owner_nv_public = NvPublicBuilder::new()
+            .with_nv_index(nv_index)
+            .with_index_name_algorithm(HashingAlgorithm::Sha256)
+            .with_index_attributes(owner_nv_index_attributes)
+            .with_data_area_size(nv_index_max + 1)
+            .build()
+            .expect("Failed to build NvPublic for owner");
+
+        if let Err(Error::WrapperError(kind)) =
+            context.nv_define_space(Provision::Owner, None, &owner_nv_public)
+        {
+            assert_eq!(kind, WrapperErrorKind::InvalidParam);
+        } else {
+            panic!("Defining an index larger than TPM_PT_NV_INDEX_MAX should fail");
+        }
+    }
 }
 
 mod test_nv_undefine_space {
@@ -155,6 +194,82 @@ mod test_nv_undefine_space {
     }
 }
 
+mod test_nv_undefine_space_special {
+    use crate::common::{create_ctx_with_session, start_policy_session};
+    use tss_esapi::{
+        attributes::NvIndexAttributesBuilder,
+        constants::{tss::TPM2_CC_NV_UndefineSpaceSpecial, SessionType},
+        handles::NvIndexTpmHandle,
+        interface_types::{
+            algorithm::HashingAlgorithm,
+            resource_handles::Provision,
+            session_handles::{AuthSession, PolicySession},
+        },
+        nv::storage::NvPublicBuilder,
+        structures::Digest,
+        Context,
+    };
+
+    fn undefine_space_special_policy(
+        context: &mut Context,
+        session_type: SessionType,
+    ) -> (PolicySession, Digest) {
+        let policy_session = start_policy_session(context, session_type);
+        context
+            .policy_command_code(policy_session, TPM2_CC_NV_UndefineSpaceSpecial)
+            .expect("Failed to call policy_command_code");
+        let digest = context
+            .policy_get_digest(policy_session)
+            .expect("Failed to call policy_get_digest");
+        (policy_session, digest)
+    }
+
+    #[test]
+    fn test_nv_undefine_space_special() {
+        let mut context = create_ctx_with_session();
+
+        let nv_index = NvIndexTpmHandle::new(0x01500025).unwrap();
+
+        let (_, policy_digest) = undefine_space_special_policy(&mut context, SessionType::Trial);
+
+        // Create platform nv public that can only be deleted using policy.
+        let platform_nv_index_attributes = NvIndexAttributesBuilder::new()
+            .with_pp_write(true)
+            .with_pp_read(true)
+            .with_platform_create(true)
+            .with_policy_delete(true)
+            .build()
+            .expect("Failed to create platform nv index attributes");
+
+        let platform_nv_public = NvPublicBuilder::new()
+            .with_nv_index(nv_index)
+            .with_index_name_algorithm(HashingAlgorithm::Sha256)
+            .with_index_attributes(platform_nv_index_attributes)
+            .with_index_auth_policy(&policy_digest)
+            .with_data_area_size(32)
+            .build()
+            .expect("Failed to build NvPublic for platform");
+
+        let platform_nv_index_handle = context
+            .nv_define_space(Provision::Platform, None, &platform_nv_public)
+            .expect("Call to nv_define_space failed");
+
+        // Fails, the index can only be deleted using policy.
+        let _ = context
+            .nv_undefine_space(Provision::Platform, platform_nv_index_handle)
+            .unwrap_err();
+
+        // Satisfy the policy and use it for deletion.
+        let (policy_session, _) = undefine_space_special_policy(&mut context, SessionType::Policy);
+        let (auth_session, _, _) = context.sessions();
+        context.set_sessions((Some(AuthSession::from(policy_session)), auth_session, None));
+
+        context
+            .nv_undefine_space_special(platform_nv_index_handle, Provision::Platform)
+            .expect("Call to nv_undefine_space_special failed");
+    }
+}
+
 mod test_nv_read_public {
     use crate::common::create_ctx_with_session;
     use tss_esapi::{
@@ -189,6 +304,7 @@ mod test_nv_read_public {
             .expect("Call to nv_define_space failed");
 
         let read_public_result = context.nv_read_public(nv_index_handle);
+        let expected_name = context.tr_get_name(nv_index_handle.into());
 
         let _ = context
             .nv_undefine_space(Provision::Owner, nv_index_handle)
@@ -200,8 +316,14 @@ mod test_nv_read_public {
         }
 
         // Check result.
-        let (actual_nv_public, _name) = read_public_result.unwrap();
+        let (actual_nv_public, actual_name) = read_public_result.unwrap();
         assert_eq!(expected_nv_public, actual_nv_public);
+        assert_eq!(32, actual_nv_public.data_size());
+        assert_eq!(nv_index_attributes, actual_nv_public.attributes());
+        assert_eq!(
+            expected_name.expect("Failed to get the name of the nv index"),
+            actual_name
+        );
     }
 }
 
@@ -261,6 +383,82 @@ mod test_nv_write {
     }
 }
 
+mod test_nv_global_write_lock {
+    use crate::common::create_ctx_with_session;
+    use std::convert::TryFrom;
+    use tss_esapi::{
+        attributes::NvIndexAttributesBuilder,
+        constants::Tss2ResponseCodeKind,
+        handles::NvIndexTpmHandle,
+        interface_types::{
+            algorithm::HashingAlgorithm,
+            resource_handles::{NvAuth, Provision},
+        },
+        nv::storage::NvPublicBuilder,
+        structures::MaxNvBuffer,
+        Error,
+    };
+
+    #[test]
+    fn test_nv_global_write_lock() {
+        let mut context = create_ctx_with_session();
+
+        // Create owner nv publics with the global lock attribute.
+        let owner_nv_index_attributes = NvIndexAttributesBuilder::new()
+            .with_owner_write(true)
+            .with_owner_read(true)
+            .with_global_lock(true)
+            .build()
+            .expect("Failed to create owner nv index attributes");
+
+        let nv_index_handles = [0x01500026, 0x01500027]
+            .iter()
+            .map(|&index| {
+                let owner_nv_public = NvPublicBuilder::new()
+                    .with_nv_index(NvIndexTpmHandle::new(index).unwrap())
+                    .with_index_name_algorithm(HashingAlgorithm::Sha256)
+                    .with_index_attributes(owner_nv_index_attributes)
+                    .with_data_area_size(32)
+                    .build()
+                    .expect("Failed to build NvPublic for owner");
+                context
+                    .nv_define_space(Provision::Owner, None, &owner_nv_public)
+                    .expect("Call to nv_define_space failed")
+            })
+            .collect::<Vec<_>>();
+
+        let data = MaxNvBuffer::try_from([1, 2, 3, 4, 5, 6, 7].to_vec())
+            .expect("Failed to create MaxBuffer from data");
+
+        let lock_result = context.nv_global_write_lock(Provision::Owner);
+        let write_results = nv_index_handles
+            .iter()
+            .map(|&nv_index_handle| context.nv_write(NvAuth::Owner, nv_index_handle, &data, 0))
+            .collect::<Vec<_>>();
+
+        for nv_index_handle in nv_index_handles {
+            context
+                .nv_undefine_space(Provision::Owner, nv_index_handle)
+                .expect("Call to nv_undefine_space failed");
+        }
+
+        // Report error
+        if let Err(e) = lock_result {
+            panic!("Failed to perform nv global write lock: {}", e);
+        }
+
+        // Check result.
+        for write_result in write_results {
+            match write_result {
+                Err(Error::Tss2Error(rc)) => {
+                    assert_eq!(rc.kind(), Some(Tss2ResponseCodeKind::NvLocked))
+                }
+                other => panic!("Unexpected result: {:?}", other),
+            }
+        }
+    }
+}
+
 mod test_nv_read {
     use crate::common::create_ctx_with_session;
     use std::convert::TryFrom;
@@ -326,3 +524,717 @@ mod test_nv_read {
         assert_eq!(expected_data, actual_data);
     }
 }
+
+mod test_nv_read_full {
+    use crate::common::create_ctx_with_session;
+    use std::convert::{TryFrom, TryInto};
+    use tss_esapi::{
+        attributes::NvIndexAttributesBuilder,
+        constants::PropertyTag,
+        handles::NvIndexTpmHandle,
+        interface_types::{
+            algorithm::HashingAlgorithm,
+            resource_handles::{NvAuth, Provision},
+        },
+        nv::storage::NvPublicBuilder,
+        structures::MaxNvBuffer,
+    };
+
+    #[test]
+    fn test_nv_read_full() {
+        let mut context = create_ctx_with_session();
+
+        let nv_index = NvIndexTpmHandle::new(0x0150002B).unwrap();
+        let data_size = 2048;
+
+        let owner_nv_index_attributes = NvIndexAttributesBuilder::new()
+            .with_owner_write(true)
+            .with_owner_read(true)
+            .build()
+            .expect("Failed to create owner nv index attributes");
+
+        let owner_nv_public = NvPublicBuilder::new()
+            .with_nv_index(nv_index)
+            .with_index_name_algorithm(HashingAlgorithm::Sha256)
+            .with_index_attributes(owner_nv_index_attributes)
+            .with_data_area_size(data_size)
+            .build()
+            .expect("Failed to build NvPublic for owner");
+
+        let owner_nv_index_handle = context
+            .nv_define_space(Provision::Owner, None, &owner_nv_public)
+            .expect("Call to nv_define_space failed");
+
+        // Write patterned data in chunks the TPM accepts.
+        let expected_data: Vec<u8> = (0..data_size).map(|i| (i % 251) as u8).collect();
+        let chunk_size: usize = context
+            .get_tpm_property(PropertyTag::NvBufferMax)
+            .expect("Failed to get TPM_PT_NV_BUFFER_MAX")
+            .unwrap_or(512)
+            .try_into()
+            .unwrap();
+        let write_result =
+            expected_data
+                .chunks(chunk_size)
+                .enumerate()
+                .try_for_each(|(i, chunk)| {
+                    context.nv_write(
+                        NvAuth::Owner,
+                        owner_nv_index_handle,
+                        &MaxNvBuffer::try_from(chunk.to_vec())?,
+                        (i * chunk_size) as u16,
+                    )
+                });
+        let read_result = context.nv_read_full(NvAuth::Owner, owner_nv_index_handle);
+
+        let _ = context
+            .nv_undefine_space(Provision::Owner, owner_nv_index_handle)
+            .expect("Call to nv_undefine_space failed");
+
+        // Report error
+        if let Err(e) = write_result {
+            panic!("Failed to perform nv write: {}", e);
+        }
+        if let Err(e) = read_result {
+            panic!("Failed to perform nv read full: {}", e);
+        }
+
+        // Check result.
+        let actual_data = read_result.unwrap();
+        assert_eq!(expected_data, actual_data.value());
+    }
+}
+
+mod test_nv_write_full {
+    use crate::common::create_ctx_with_session;
+    use std::convert::TryInto;
+    use tss_esapi::{
+        attributes::NvIndexAttributesBuilder,
+        constants::PropertyTag,
+        handles::NvIndexTpmHandle,
+        interface_types::{
+            algorithm::HashingAlgorithm,
+            resource_handles::{NvAuth, Provision},
+        },
+        nv::storage::NvPublicBuilder,
+        structures::MaxNvBuffer,
+        Error, WrapperErrorKind,
+    };
+
+    #[test]
+    fn test_nv_write_full() {
+        let mut context = create_ctx_with_session();
+
+        let nv_index = NvIndexTpmHandle::new(0x0150002C).unwrap();
+        // 4 KiB, limited by what the TPM and nv_read_full support.
+        let nv_index_max: usize = context
+            .get_tpm_property(PropertyTag::NvIndexMax)
+            .expect("Failed to get TPM_PT_NV_INDEX_MAX")
+            .expect("TPM_PT_NV_INDEX_MAX is not reported by the TPM")
+            .try_into()
+            .unwrap();
+        let data_size = std::cmp::min(4096, std::cmp::min(nv_index_max, MaxNvBuffer::MAX_SIZE));
+
+        let owner_nv_index_attributes = NvIndexAttributesBuilder::new()
+            .with_owner_write(true)
+            .with_owner_read(true)
+            .build()
+            .expect("Failed to create owner nv index attributes");
+
+        let owner_nv_public = NvPublicBuilder::new()
+            .with_nv_index(nv_index)
+            .with_index_name_algorithm(HashingAlgorithm::Sha256)
+            .with_index_attributes(owner_nv_index_attributes)
+            .with_data_area_size(data_size)
+            .build()
+            .expect("Failed to build NvPublic for owner");
+
+        let owner_nv_index_handle = context
+            .nv_define_space(Provision::Owner, None, &owner_nv_public)
+            .expect("Call to nv_define_space failed");
+
+        // Fill three quarters of the index.
+        let expected_data: Vec<u8> = (0..data_size * 3 / 4).map(|i| (i % 251) as u8).collect();
+        let write_result =
+            context.nv_write_full(NvAuth::Owner, owner_nv_index_handle, &expected_data);
+        let oversized_write_result = context.nv_write_full(
+            NvAuth::Owner,
+            owner_nv_index_handle,
+            &vec![0xff; data_size + 1],
+        );
+        let read_result = context.nv_read_full(NvAuth::Owner, owner_nv_index_handle);
+
+        let _ = context
+            .nv_undefine_space(Provision::Owner, owner_nv_index_handle)
+            .expect("Call to nv_undefine_space failed");
+
+        // Report error
+        if let Err(e) = write_result {
+            panic!("Failed to perform nv write full: {}", e);
+        }
+        if let Err(Error::WrapperError(kind)) = oversized_write_result {
+            assert_eq!(kind, WrapperErrorKind::WrongParamSize);
+        } else {
+            panic!("Writing more data than the size of the index should fail");
+        }
+        if let Err(e) = read_result {
+            panic!("Failed to perform nv read full: {}", e);
+        }
+
+        // Check result, the oversized write must not have written anything.
+        let actual_data = read_result.unwrap();
+        assert_eq!(data_size, actual_data.len());
+        assert_eq!(expected_data, actual_data[..expected_data.len()]);
+    }
+}
+
+mod test_nv_increment {
+    use crate::common::create_ctx_with_session;
+    use std::convert::{TryFrom, TryInto};
+    use tss_esapi::{
+        attributes::NvIndexAttributesBuilder,
+        constants::{response_code::TpmResponseCode, NvIndexType},
+        handles::NvIndexTpmHandle,
+        interface_types::{
+            algorithm::HashingAlgorithm,
+            resource_handles::{NvAuth, Provision},
+        },
+        nv::storage::NvPublicBuilder,
+        structures::MaxNvBuffer,
+    };
+
+    #[test]
+    fn test_nv_increment() {
+        let mut context = create_ctx_with_session();
+
+        let nv_index = NvIndexTpmHandle::new(0x0150002D).unwrap();
+
+        let counter_nv_index_attributes = NvIndexAttributesBuilder::new()
+            .with_owner_write(true)
+            .with_owner_read(true)
+            .with_nv_index_type(NvIndexType::Counter)
+            .build()
+            .expect("Failed to create counter nv index attributes");
+
+        let counter_nv_public = NvPublicBuilder::new()
+            .with_nv_index(nv_index)
+            .with_index_name_algorithm(HashingAlgorithm::Sha256)
+            .with_index_attributes(counter_nv_index_attributes)
+            .with_data_area_size(8)
+            .build()
+            .expect("Failed to build NvPublic for counter");
+
+        let counter_nv_index_handle = context
+            .nv_define_space(Provision::Owner, None, &counter_nv_public)
+            .expect("Call to nv_define_space failed");
+
+        // The counter can only be read once it has been incremented.
+        let first_increment_result = context.nv_increment(NvAuth::Owner, counter_nv_index_handle);
+        let first_read_result = context.nv_read(NvAuth::Owner, counter_nv_index_handle, 8, 0);
+        let second_increment_result = context.nv_increment(NvAuth::Owner, counter_nv_index_handle);
+        let second_read_result = context.nv_read(NvAuth::Owner, counter_nv_index_handle, 8, 0);
+        // Counters cannot be extended.
+        let extend_result = context.nv_extend(
+            NvAuth::Owner,
+            counter_nv_index_handle,
+            MaxNvBuffer::try_from(vec![1, 2, 3]).unwrap(),
+        );
+
+        context
+            .nv_undefine_space(Provision::Owner, counter_nv_index_handle)
+            .expect("Call to nv_undefine_space failed");
+
+        first_increment_result.expect("Failed to increment the counter");
+        second_increment_result.expect("Failed to increment the counter a second time");
+        let first_value = u64::from_be_bytes(
+            first_read_result
+                .expect("Failed to read the counter")
+                .value()[..]
+                .try_into()
+                .expect("The counter value is not 8 bytes"),
+        );
+        let second_value = u64::from_be_bytes(
+            second_read_result
+                .expect("Failed to read the counter a second time")
+                .value()[..]
+                .try_into()
+                .expect("The counter value is not 8 bytes"),
+        );
+        assert_eq!(first_value + 1, second_value);
+        assert_eq!(
+            extend_result
+                .expect_err("Extending a counter should fail")
+                .tpm_response_code(),
+            Some(TpmResponseCode::AttributesMismatch)
+        );
+    }
+}
+
+mod test_nv_extend {
+    use crate::common::create_ctx_with_session;
+    use sha2::{Digest, Sha256};
+    use std::convert::TryFrom;
+    use tss_esapi::{
+        attributes::NvIndexAttributesBuilder,
+        constants::{response_code::TpmResponseCode, NvIndexType},
+        handles::NvIndexTpmHandle,
+        interface_types::{
+            algorithm::HashingAlgorithm,
+            resource_handles::{NvAuth, Provision},
+        },
+        nv::storage::NvPublicBuilder,
+        structures::MaxNvBuffer,
+    };
+
+    #[test]
+    fn test_nv_extend() {
+        let mut context = create_ctx_with_session();
+
+        let nv_index = NvIndexTpmHandle::new(0x0150002E).unwrap();
+
+        let extend_nv_index_attributes = NvIndexAttributesBuilder::new()
+            .with_owner_write(true)
+            .with_owner_read(true)
+            .with_nv_index_type(NvIndexType::Extend)
+            .build()
+            .expect("Failed to create extend nv index attributes");
+
+        let extend_nv_public = NvPublicBuilder::new()
+            .with_nv_index(nv_index)
+            .with_index_name_algorithm(HashingAlgorithm::Sha256)
+            .with_index_attributes(extend_nv_index_attributes)
+            .with_data_area_size(32)
+            .build()
+            .expect("Failed to build NvPublic for extend index");
+
+        let extend_nv_index_handle = context
+            .nv_define_space(Provision::Owner, None, &extend_nv_public)
+            .expect("Call to nv_define_space failed");
+
+        let data = [1, 2, 3, 4, 5, 6, 7];
+        let extend_result = context.nv_extend(
+            NvAuth::Owner,
+            extend_nv_index_handle,
+            MaxNvBuffer::try_from(data.to_vec()).unwrap(),
+        );
+        let read_result = context.nv_read(NvAuth::Owner, extend_nv_index_handle, 32, 0);
+        // Extend indexes cannot be incremented.
+        let increment_result = context.nv_increment(NvAuth::Owner, extend_nv_index_handle);
+
+        context
+            .nv_undefine_space(Provision::Owner, extend_nv_index_handle)
+            .expect("Call to nv_undefine_space failed");
+
+        extend_result.expect("Failed to extend the nv index");
+        // The extend index starts with a value of zeros.
+        let mut hasher = Sha256::new();
+        hasher.update([0; 32]);
+        hasher.update(data);
+        assert_eq!(
+            read_result.expect("Failed to read the nv index").value(),
+            &hasher.finalize()[..]
+        );
+        assert_eq!(
+            increment_result
+                .expect_err("Incrementing an extend index should fail")
+                .tpm_response_code(),
+            Some(TpmResponseCode::AttributesMismatch)
+        );
+    }
+}
+
+mod test_nv_set_bits {
+    use crate::common::create_ctx_with_session;
+    use std::convert::TryInto;
+    use tss_esapi::{
+        attributes::NvIndexAttributesBuilder,
+        constants::NvIndexType,
+        handles::NvIndexTpmHandle,
+        interface_types::{
+            algorithm::HashingAlgorithm,
+            resource_handles::{NvAuth, Provision},
+        },
+        nv::storage::NvPublicBuilder,
+    };
+
+    #[test]
+    fn test_nv_set_bits() {
+        let mut context = create_ctx_with_session();
+
+        let nv_index = NvIndexTpmHandle::new(0x0150002F).unwrap();
+
+        let bits_nv_index_attributes = NvIndexAttributesBuilder::new()
+            .with_owner_write(true)
+            .with_owner_read(true)
+            .with_nv_index_type(NvIndexType::Bits)
+            .build()
+            .expect("Failed to create bits nv index attributes");
+
+        let bits_nv_public = NvPublicBuilder::new()
+            .with_nv_index(nv_index)
+            .with_index_name_algorithm(HashingAlgorithm::Sha256)
+            .with_index_attributes(bits_nv_index_attributes)
+            .with_data_area_size(8)
+            .build()
+            .expect("Failed to build NvPublic for bits index");
+
+        let bits_nv_index_handle = context
+            .nv_define_space(Provision::Owner, None, &bits_nv_public)
+            .expect("Call to nv_define_space failed");
+
+        let first_set_bits_result =
+            context.nv_set_bits(NvAuth::Owner, bits_nv_index_handle, 0x0000_0000_0000_00f0);
+        let second_set_bits_result =
+            context.nv_set_bits(NvAuth::Owner, bits_nv_index_handle, 0x8000_0000_0000_0001);
+        let read_result = context.nv_read(NvAuth::Owner, bits_nv_index_handle, 8, 0);
+
+        context
+            .nv_undefine_space(Provision::Owner, bits_nv_index_handle)
+            .expect("Call to nv_undefine_space failed");
+
+        first_set_bits_result.expect("Failed to set the first bits");
+        second_set_bits_result.expect("Failed to set the second bits");
+        let value = u64::from_be_bytes(
+            read_result.expect("Failed to read the nv index").value()[..]
+                .try_into()
+                .expect("The bits value is not 8 bytes"),
+        );
+        assert_eq!(value, 0x8000_0000_0000_00f1);
+    }
+}
+
+mod test_nv_write_lock {
+    use crate::common::create_ctx_with_session;
+    use std::convert::TryFrom;
+    use tss_esapi::{
+        attributes::NvIndexAttributesBuilder,
+        constants::TpmResponseCode,
+        handles::NvIndexTpmHandle,
+        interface_types::{
+            algorithm::HashingAlgorithm,
+            resource_handles::{NvAuth, Provision},
+        },
+        nv::storage::NvPublicBuilder,
+        structures::MaxNvBuffer,
+    };
+
+    #[test]
+    fn test_nv_write_lock() {
+        let mut context = create_ctx_with_session();
+
+        let nv_index = NvIndexTpmHandle::new(0x01500030).unwrap();
+
+        let owner_nv_index_attributes = NvIndexAttributesBuilder::new()
+            .with_owner_write(true)
+            .with_owner_read(true)
+            .with_write_stclear(true)
+            .build()
+            .expect("Failed to create owner nv index attributes");
+
+        let owner_nv_public = NvPublicBuilder::new()
+            .with_nv_index(nv_index)
+            .with_index_name_algorithm(HashingAlgorithm::Sha256)
+            .with_index_attributes(owner_nv_index_attributes)
+            .with_data_area_size(32)
+            .build()
+            .expect("Failed to build NvPublic for owner");
+
+        let owner_nv_index_handle = context
+            .nv_define_space(Provision::Owner, None, &owner_nv_public)
+            .expect("Call to nv_define_space failed");
+
+        let data = MaxNvBuffer::try_from([1, 2, 3, 4, 5, 6, 7].to_vec())
+            .expect("Failed to create MaxBuffer from data");
+
+        let write_before_lock_result =
+            context.nv_write(NvAuth::Owner, owner_nv_index_handle, &data, 0);
+        let lock_result = context.nv_write_lock(NvAuth::Owner, owner_nv_index_handle);
+        let write_after_lock_result =
+            context.nv_write(NvAuth::Owner, owner_nv_index_handle, &data, 0);
+
+        context
+            .nv_undefine_space(Provision::Owner, owner_nv_index_handle)
+            .expect("Call to nv_undefine_space failed");
+
+        write_before_lock_result.expect("Failed to write the nv index before locking it");
+        lock_result.expect("Failed to write lock the nv index");
+        assert_eq!(
+            write_after_lock_result
+                .expect_err("Writing a write locked nv index should fail")
+                .tpm_response_code(),
+            Some(TpmResponseCode::NvLocked)
+        );
+    }
+}
+
+mod test_nv_read_lock {
+    use crate::common::create_ctx_with_session;
+    use std::convert::TryFrom;
+    use tss_esapi::{
+        attributes::NvIndexAttributesBuilder,
+        constants::TpmResponseCode,
+        handles::NvIndexTpmHandle,
+        interface_types::{
+            algorithm::HashingAlgorithm,
+            resource_handles::{NvAuth, Provision},
+        },
+        nv::storage::NvPublicBuilder,
+        structures::MaxNvBuffer,
+    };
+
+    #[test]
+    fn test_nv_read_lock() {
+        let mut context = create_ctx_with_session();
+
+        let nv_index = NvIndexTpmHandle::new(0x01500031).unwrap();
+
+        let owner_nv_index_attributes = NvIndexAttributesBuilder::new()
+            .with_owner_write(true)
+            .with_owner_read(true)
+            .with_read_stclear(true)
+            .build()
+            .expect("Failed to create owner nv index attributes");
+
+        let owner_nv_public = NvPublicBuilder::new()
+            .with_nv_index(nv_index)
+            .with_index_name_algorithm(HashingAlgorithm::Sha256)
+            .with_index_attributes(owner_nv_index_attributes)
+            .with_data_area_size(32)
+            .build()
+            .expect("Failed to build NvPublic for owner");
+
+        let owner_nv_index_handle = context
+            .nv_define_space(Provision::Owner, None, &owner_nv_public)
+            .expect("Call to nv_define_space failed");
+
+        let data = MaxNvBuffer::try_from([1, 2, 3, 4, 5, 6, 7].to_vec())
+            .expect("Failed to create MaxBuffer from data");
+
+        let write_result = context.nv_write(NvAuth::Owner, owner_nv_index_handle, &data, 0);
+        let read_before_lock_result = context.nv_read(NvAuth::Owner, owner_nv_index_handle, 7, 0);
+        let lock_result = context.nv_read_lock(NvAuth::Owner, owner_nv_index_handle);
+        let read_after_lock_result = context.nv_read(NvAuth::Owner, owner_nv_index_handle, 7, 0);
+
+        context
+            .nv_undefine_space(Provision::Owner, owner_nv_index_handle)
+            .expect("Call to nv_undefine_space failed");
+
+        write_result.expect("Failed to write the nv index");
+        assert_eq!(
+            read_before_lock_result.expect("Failed to read the nv index before locking it"),
+            data
+        );
+        lock_result.expect("Failed to read lock the nv index");
+        assert_eq!(
+            read_after_lock_result
+                .expect_err("Reading a read locked nv index should fail")
+                .tpm_response_code(),
+            Some(TpmResponseCode::NvLocked)
+        );
+    }
+}
+
+mod test_nv_change_auth {
+    use crate::common::create_ctx_with_session;
+    use std::convert::TryFrom;
+    use tss_esapi::{
+        attributes::NvIndexAttributesBuilder,
+        constants::{tss::TPM2_CC_NV_ChangeAuth, SessionType},
+        handles::{NvIndexTpmHandle, SessionHandle},
+        interface_types::{
+            algorithm::HashingAlgorithm,
+            resource_handles::{NvAuth, Provision},
+            session_handles::PolicySession,
+        },
+        nv::storage::NvPublicBuilder,
+        structures::{Auth, MaxNvBuffer, SymmetricDefinition},
+        Context,
+    };
+
+    /// Starts a policy session, of the session type, with
+    /// the policy allowing the change of the nv index auth.
+    fn start_change_auth_policy_session(
+        context: &mut Context,
+        session_type: SessionType,
+    ) -> PolicySession {
+        let policy_auth_session = context
+            .start_auth_session(
+                None,
+                None,
+                None,
+                session_type,
+                SymmetricDefinition::AES_256_CFB,
+                HashingAlgorithm::Sha256,
+            )
+            .expect("Start auth session failed")
+            .expect("Start auth session returned a NONE handle");
+        let policy_session = PolicySession::try_from(policy_auth_session)
+            .expect("Failed to convert auth session into policy session");
+        context
+            .policy_command_code(policy_session, TPM2_CC_NV_ChangeAuth)
+            .expect("Failed to call policy_command_code");
+        policy_session
+    }
+
+    #[test]
+    fn test_nv_change_auth() {
+        let mut context = create_ctx_with_session();
+
+        let trial_policy_session =
+            start_change_auth_policy_session(&mut context, SessionType::Trial);
+        let policy_digest = context
+            .policy_get_digest(trial_policy_session)
+            .expect("Failed to get the policy digest");
+        context
+            .flush_context(SessionHandle::from(trial_policy_session).into())
+            .expect("Failed to flush the trial session");
+
+        let nv_index = NvIndexTpmHandle::new(0x01500032).unwrap();
+
+        // The index is not subject to dictionary attack protections,
+        // so that authorizing it with the old value does not count
+        // as a failed attempt.
+        let nv_index_attributes = NvIndexAttributesBuilder::new()
+            .with_auth_write(true)
+            .with_auth_read(true)
+            .with_no_da(true)
+            .build()
+            .expect("Failed to create nv index attributes");
+
+        let nv_public = NvPublicBuilder::new()
+            .with_nv_index(nv_index)
+            .with_index_name_algorithm(HashingAlgorithm::Sha256)
+            .with_index_auth_policy(&policy_digest)
+            .with_index_attributes(nv_index_attributes)
+            .with_data_area_size(32)
+            .build()
+            .expect("Failed to build NvPublic");
+
+        let old_auth = Auth::try_from(vec![1, 2, 3, 4]).unwrap();
+        let new_auth = Auth::try_from(vec![5, 6, 7, 8]).unwrap();
+        let nv_index_handle = context
+            .nv_define_space(Provision::Owner, Some(&old_auth), &nv_public)
+            .expect("Call to nv_define_space failed");
+
+        let data = MaxNvBuffer::try_from([1, 2, 3, 4, 5, 6, 7].to_vec())
+            .expect("Failed to create MaxBuffer from data");
+
+        context
+            .tr_set_auth(nv_index_handle.into(), &old_auth)
+            .expect("Failed to set the old auth");
+        let write_with_old_auth_result =
+            context.nv_write(NvAuth::NvIndex(nv_index_handle), nv_index_handle, &data, 0);
+
+        let policy_session = start_change_auth_policy_session(&mut context, SessionType::Policy);
+        let change_auth_result = context.execute_with_session(Some(policy_session.into()), |ctx| {
+            ctx.nv_change_auth(nv_index_handle, new_auth.clone())
+        });
+
+        context
+            .tr_set_auth(nv_index_handle.into(), &old_auth)
+            .expect("Failed to set the old auth");
+        let write_with_stale_auth_result =
+            context.nv_write(NvAuth::NvIndex(nv_index_handle), nv_index_handle, &data, 0);
+        context
+            .tr_set_auth(nv_index_handle.into(), &new_auth)
+            .expect("Failed to set the new auth");
+        let write_with_new_auth_result =
+            context.nv_write(NvAuth::NvIndex(nv_index_handle), nv_index_handle, &data, 0);
+
+        context
+            .nv_undefine_space(Provision::Owner, nv_index_handle)
+            .expect("Call to nv_undefine_space failed");
+
+        write_with_old_auth_result.expect("Failed to write with the old auth");
+        change_auth_result.expect("Failed to change the nv index auth");
+        let _ = write_with_stale_auth_result
+            .expect_err("Writing with the old auth should fail after changing it");
+        write_with_new_auth_result.expect("Failed to write with the new auth");
+    }
+}
+
+mod test_nv_certify {
+    use crate::common::{create_ctx_with_session, signing_key_pub};
+    use std::convert::TryFrom;
+    use tss_esapi::{
+        attributes::NvIndexAttributesBuilder,
+        constants::StructureTag,
+        handles::{AuthHandle, NvIndexTpmHandle},
+        interface_types::{
+            algorithm::HashingAlgorithm,
+            resource_handles::{Hierarchy, NvAuth, Provision},
+        },
+        nv::storage::NvPublicBuilder,
+        structures::{AttestInfo, Data, MaxNvBuffer, SignatureScheme},
+    };
+
+    #[test]
+    fn test_nv_certify() {
+        let mut context = create_ctx_with_session();
+        // NV_Certify requires authorization for both the signing key and the NV index.
+        let (session_1, _, _) = context.sessions();
+        context.set_sessions((session_1, session_1, None));
+
+        let nv_index = NvIndexTpmHandle::new(0x01500024).unwrap();
+
+        // Create owner nv public.
+        let owner_nv_index_attributes = NvIndexAttributesBuilder::new()
+            .with_owner_write(true)
+            .with_owner_read(true)
+            .build()
+            .expect("Failed to create owner nv index attributes");
+
+        let owner_nv_public = NvPublicBuilder::new()
+            .with_nv_index(nv_index)
+            .with_index_name_algorithm(HashingAlgorithm::Sha256)
+            .with_index_attributes(owner_nv_index_attributes)
+            .with_data_area_size(32)
+            .build()
+            .expect("Failed to build NvPublic for owner");
+
+        let owner_nv_index_handle = context
+            .nv_define_space(Provision::Owner, None, &owner_nv_public)
+            .expect("Call to nv_define_space failed");
+
+        let value = [1, 2, 3, 4, 5, 6, 7, 8];
+        let expected_data =
+            MaxNvBuffer::try_from(value.to_vec()).expect("Failed to create MaxBuffer from data");
+        let qualifying_data = Data::try_from(vec![0xff; 16]).unwrap();
+
+        let write_result =
+            context.nv_write(NvAuth::Owner, owner_nv_index_handle, &expected_data, 0);
+        let certify_result = context
+            .create_primary(Hierarchy::Owner, &signing_key_pub(), None, None, None, None)
+            .and_then(|primary| {
+                context.nv_certify(
+                    primary.key_handle,
+                    AuthHandle::Owner,
+                    owner_nv_index_handle,
+                    qualifying_data.clone(),
+                    SignatureScheme::Null,
+                    value.len() as u16,
+                    0,
+                )
+            });
+        context
+            .nv_undefine_space(Provision::Owner, owner_nv_index_handle)
+            .expect("Call to nv_undefine_space failed");
+
+        // Report error
+        if let Err(e) = write_result {
+            panic!("Failed to perform nv write: {}", e);
+        }
+        let (attest, _signature) = certify_result.expect("Failed to certify nv index");
+
+        // Check result.
+        assert_eq!(StructureTag::AttestNv, attest.attestation_type());
+        assert_eq!(&qualifying_data, attest.extra_data());
+        match attest.attested() {
+            AttestInfo::Nv { info } => {
+                assert_eq!(0, info.offset());
+                assert_eq!(&expected_data, info.nv_contents());
+            }
+            _ => panic!("Attested did not contain the expected variant."),
+        }
+    }
+}
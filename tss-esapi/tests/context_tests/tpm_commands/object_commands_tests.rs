@@ -76,17 +76,216 @@ mod test_load {
     }
 }
 
+mod test_object_change_auth {
+    use crate::common::{create_ctx_with_session, decryption_key_pub};
+    use std::convert::{TryFrom, TryInto};
+    use tss_esapi::{
+        attributes::ObjectAttributesBuilder,
+        constants::{
+            response_code::Tss2ResponseCodeKind,
+            tss::{TPM2_ALG_NULL, TPM2_RH_NULL, TPM2_ST_HASHCHECK},
+        },
+        handles::KeyHandle,
+        interface_types::{
+            algorithm::{HashingAlgorithm, PublicAlgorithm},
+            ecc::EccCurve,
+            resource_handles::Hierarchy,
+        },
+        structures::{
+            Auth, Digest, EccPoint, EccScheme, HashScheme, Public, PublicBuilder,
+            PublicEccParametersBuilder,
+        },
+        tss2_esys::{TPMT_SIG_SCHEME, TPMT_TK_HASHCHECK},
+        Context, Error, Result,
+    };
+
+    // Signing key that is not subject to dictionary attack
+    // protection, so failed authorizations do not lock out the TPM.
+    fn no_da_signing_key_pub() -> Public {
+        let object_attributes = ObjectAttributesBuilder::new()
+            .with_fixed_tpm(true)
+            .with_fixed_parent(true)
+            .with_sensitive_data_origin(true)
+            .with_user_with_auth(true)
+            .with_no_da(true)
+            .with_sign_encrypt(true)
+            .build()
+            .expect("Failed to build object attributes");
+        PublicBuilder::new()
+            .with_public_algorithm(PublicAlgorithm::Ecc)
+            .with_name_hashing_algorithm(HashingAlgorithm::Sha256)
+            .with_object_attributes(object_attributes)
+            .with_ecc_parameters(
+                PublicEccParametersBuilder::new_unrestricted_signing_key(
+                    EccScheme::EcDsa(HashScheme::new(HashingAlgorithm::Sha256)),
+                    EccCurve::NistP256,
+                )
+                .build()
+                .expect("Failed to build ECC parameters"),
+            )
+            .with_ecc_unique_identifier(&EccPoint::default())
+            .build()
+            .expect("Failed to build the signing key public")
+    }
+
+    fn sign(context: &mut Context, key_handle: KeyHandle) -> Result<()> {
+        let scheme = TPMT_SIG_SCHEME {
+            scheme: TPM2_ALG_NULL,
+            details: Default::default(),
+        };
+        let validation = TPMT_TK_HASHCHECK {
+            tag: TPM2_ST_HASHCHECK,
+            hierarchy: TPM2_RH_NULL,
+            digest: Default::default(),
+        };
+        context
+            .sign(
+                key_handle,
+                &Digest::try_from(vec![0xab; 32]).unwrap(),
+                scheme,
+                validation.try_into().unwrap(),
+            )
+            .map(|_| ())
+    }
+
+    #[test]
+    fn test_object_change_auth_enforced() {
+        let mut context = create_ctx_with_session();
+        let old_auth = Auth::try_from(vec![1, 2, 3, 4]).unwrap();
+        let new_auth = Auth::try_from(vec![5, 6, 7, 8]).unwrap();
+
+        let prim_key_handle = context
+            .create_primary(
+                Hierarchy::Owner,
+                &decryption_key_pub(),
+                None,
+                None,
+                None,
+                None,
+            )
+            .unwrap()
+            .key_handle;
+        let result = context
+            .create(
+                prim_key_handle,
+                &no_da_signing_key_pub(),
+                Some(&old_auth),
+                None,
+                None,
+                None,
+            )
+            .unwrap();
+        let key_handle = context
+            .load(prim_key_handle, result.out_private, &result.out_public)
+            .unwrap();
+
+        let new_private = context
+            .object_change_auth(key_handle.into(), prim_key_handle.into(), new_auth.clone())
+            .expect("Call to object_change_auth failed");
+        context.flush_context(key_handle.into()).unwrap();
+
+        // Reload the key with the new private area under the same parent.
+        let key_handle = context
+            .load(prim_key_handle, new_private, &result.out_public)
+            .unwrap();
+
+        context
+            .tr_set_auth(key_handle.into(), &old_auth)
+            .expect("Failed to set the old auth");
+        if let Err(Error::Tss2Error(rc)) = sign(&mut context, key_handle) {
+            assert_eq!(rc.kind(), Some(Tss2ResponseCodeKind::BadAuth));
+        } else {
+            panic!("Signing with the old auth should fail");
+        }
+
+        context
+            .tr_set_auth(key_handle.into(), &new_auth)
+            .expect("Failed to set the new auth");
+        sign(&mut context, key_handle).expect("Failed to sign with the new auth");
+    }
+}
+
+mod test_create_loaded {
+    use crate::common::{create_ctx_with_session, decryption_key_pub, signing_key_pub};
+    use std::convert::{TryFrom, TryInto};
+    use tss_esapi::{
+        constants::tss::{TPM2_ALG_NULL, TPM2_RH_NULL, TPM2_ST_HASHCHECK},
+        handles::KeyHandle,
+        interface_types::resource_handles::Hierarchy,
+        structures::{Digest, SensitiveCreate, Signature},
+        traits::Marshall,
+        tss2_esys::{TPMT_SIG_SCHEME, TPMT_TK_HASHCHECK},
+    };
+
+    #[test]
+    fn test_create_loaded_and_sign() {
+        let mut context = create_ctx_with_session();
+
+        let prim_key_handle = context
+            .create_primary(
+                Hierarchy::Owner,
+                &decryption_key_pub(),
+                None,
+                None,
+                None,
+                None,
+            )
+            .unwrap()
+            .key_handle;
+
+        let (object_handle, _, out_public) = context
+            .create_loaded(
+                prim_key_handle.into(),
+                SensitiveCreate::default(),
+                signing_key_pub(),
+            )
+            .expect("Call to create_loaded failed");
+        let (read_public, _, _) = context.read_public(object_handle.into()).unwrap();
+        assert_eq!(
+            out_public.marshall().unwrap(),
+            read_public.marshall().unwrap()
+        );
+
+        let scheme = TPMT_SIG_SCHEME {
+            scheme: TPM2_ALG_NULL,
+            details: Default::default(),
+        };
+        let validation = TPMT_TK_HASHCHECK {
+            tag: TPM2_ST_HASHCHECK,
+            hierarchy: TPM2_RH_NULL,
+            digest: Default::default(),
+        };
+        let signature = context
+            .sign(
+                KeyHandle::from(object_handle),
+                &Digest::try_from(vec![0xab; 32]).unwrap(),
+                scheme,
+                validation.try_into().unwrap(),
+            )
+            .expect("Failed to sign with the created key");
+        assert!(matches!(signature, Signature::RsaSsa(_)));
+    }
+}
+
 mod test_load_external_public {
     use crate::common::{create_ctx_with_session, KEY};
+    use sha2::{Digest, Sha256};
     use std::convert::TryFrom;
     use tss_esapi::{
         attributes::ObjectAttributesBuilder,
+        constants::tss::{
+            TPM2_CC_LoadExternal, TPM2_ALG_SHA256, TPM2_RC_SUCCESS, TPM2_RH_OWNER,
+            TPM2_ST_NO_SESSIONS,
+        },
         interface_types::{
             algorithm::{HashingAlgorithm, PublicAlgorithm, RsaSchemeAlgorithm},
             key_bits::RsaKeyBits,
             resource_handles::Hierarchy,
         },
         structures::{Public, PublicBuilder, PublicKeyRsa, PublicRsaParametersBuilder, RsaScheme},
+        tcti_ldr::{MockTcti, TctiNameConf},
+        traits::Marshall,
+        Context,
     };
 
     pub fn get_ext_rsa_pub() -> Public {
@@ -129,6 +328,201 @@ mod test_load_external_public {
             .load_external_public(&pub_key, Hierarchy::Owner)
             .unwrap();
     }
+
+    #[test]
+    fn test_load_external_public_hierarchy_marshalling() {
+        let pub_key = get_ext_rsa_pub();
+        let marshalled_public = pub_key.marshall().expect("Failed to marshall public");
+        // The ESAPI checks that the name returned by the TPM is the
+        // digest of the TPMT_PUBLIC following the size of the TPM2B_PUBLIC.
+        let name = [
+            &TPM2_ALG_SHA256.to_be_bytes()[..],
+            &Sha256::digest(&marshalled_public[2..])[..],
+        ]
+        .concat();
+
+        let mock_tcti = MockTcti::new();
+        // Marshalled TPM2_LoadExternal response: header, followed by
+        // the handle of the loaded object and its name.
+        let mut response = Vec::new();
+        response.extend_from_slice(&TPM2_ST_NO_SESSIONS.to_be_bytes());
+        response.extend_from_slice(&(16 + name.len() as u32).to_be_bytes());
+        response.extend_from_slice(&TPM2_RC_SUCCESS.to_be_bytes());
+        response.extend_from_slice(&0x8000_0000u32.to_be_bytes());
+        response.extend_from_slice(&(name.len() as u16).to_be_bytes());
+        response.extend_from_slice(&name);
+        mock_tcti.push_response(response);
+        // Marshalled TPM2_FlushContext response.
+        let mut response = Vec::new();
+        response.extend_from_slice(&TPM2_ST_NO_SESSIONS.to_be_bytes());
+        response.extend_from_slice(&10u32.to_be_bytes());
+        response.extend_from_slice(&TPM2_RC_SUCCESS.to_be_bytes());
+        mock_tcti.push_response(response);
+
+        let mut context =
+            Context::new(TctiNameConf::Mock(mock_tcti.clone())).expect("Failed to create context");
+        let key_handle = context
+            .load_external_public(&pub_key, Hierarchy::Owner)
+            .expect("Failed to load external public through the mock TCTI");
+
+        // The hierarchy is passed to the ESAPI as an ESYS handle with version 3
+        // of the library and as a TPM handle with version 2; either way the TPM
+        // handle of the hierarchy has to be sent to the TPM.
+        let commands = mock_tcti.commands();
+        assert_eq!(commands.len(), 1);
+        let command = &commands[0];
+        assert_eq!(command.len(), 16 + marshalled_public.len());
+        assert_eq!(command[6..10], TPM2_CC_LoadExternal.to_be_bytes());
+        // The sensitive area is empty.
+        assert_eq!(command[10..12], 0u16.to_be_bytes());
+        assert_eq!(command[12..command.len() - 4], marshalled_public[..]);
+        assert_eq!(command[command.len() - 4..], TPM2_RH_OWNER.to_be_bytes());
+
+        context
+            .flush_context(key_handle.into())
+            .expect("Failed to flush the key through the mock TCTI");
+    }
+}
+
+mod test_load_external {
+    use crate::common::{create_ctx_with_session, signing_key_pub};
+    use p256::{
+        ecdsa::{signature::hazmat::PrehashVerifier, Signature as P256Signature, VerifyingKey},
+        elliptic_curve::sec1::ToEncodedPoint,
+    };
+    use sha2::{Digest as _, Sha256};
+    use std::convert::{TryFrom, TryInto};
+    use tss_esapi::{
+        attributes::ObjectAttributesBuilder,
+        constants::{
+            response_code::Tss2ResponseCodeKind,
+            tss::{TPM2_ALG_NULL, TPM2_RH_NULL, TPM2_ST_HASHCHECK},
+        },
+        handles::KeyHandle,
+        interface_types::{
+            algorithm::{HashingAlgorithm, PublicAlgorithm},
+            ecc::EccCurve,
+            resource_handles::Hierarchy,
+        },
+        structures::{
+            Digest, EccParameter, EccPoint, EccScheme, HashScheme, PublicBuilder,
+            PublicEccParametersBuilder, Sensitive, Signature,
+        },
+        tss2_esys::{TPMT_SIG_SCHEME, TPMT_TK_HASHCHECK},
+        Context, Error,
+    };
+
+    const MESSAGE: &[u8] = b"Message signed with an external key";
+
+    fn sign(context: &mut Context, key_handle: KeyHandle, digest: &Digest) -> Signature {
+        let scheme = TPMT_SIG_SCHEME {
+            scheme: TPM2_ALG_NULL,
+            details: Default::default(),
+        };
+        let validation = TPMT_TK_HASHCHECK {
+            tag: TPM2_ST_HASHCHECK,
+            hierarchy: TPM2_RH_NULL,
+            digest: Default::default(),
+        };
+        context
+            .sign(key_handle, digest, scheme, validation.try_into().unwrap())
+            .expect("Failed to sign the digest")
+    }
+
+    // Left pads the scalar with zeros up to the size of the P-256 scalars.
+    fn p256_scalar(value: &[u8]) -> p256::FieldBytes {
+        let mut scalar = p256::FieldBytes::default();
+        scalar[32 - value.len()..].copy_from_slice(value);
+        scalar
+    }
+
+    #[test]
+    fn test_load_external_public_and_verify() {
+        let mut context = create_ctx_with_session();
+        let digest = Digest::try_from(Sha256::digest(MESSAGE).to_vec()).unwrap();
+
+        let key = context
+            .create_primary(Hierarchy::Owner, &signing_key_pub(), None, None, None, None)
+            .unwrap();
+        let signature = sign(&mut context, key.key_handle, &digest);
+        context.flush_context(key.key_handle.into()).unwrap();
+
+        let key_handle = context
+            .load_external(None, key.out_public, Hierarchy::Null)
+            .expect("Call to load_external failed");
+        let _ = context
+            .verify_signature(key_handle, &digest, signature.clone())
+            .expect("Failed to verify the signature with the external key");
+
+        let other_digest = Digest::try_from(Sha256::digest(b"Another message").to_vec()).unwrap();
+        if let Err(Error::Tss2Error(rc)) =
+            context.verify_signature(key_handle, &other_digest, signature)
+        {
+            assert_eq!(rc.kind(), Some(Tss2ResponseCodeKind::Signature));
+        } else {
+            panic!("Verifying the signature of another digest should fail");
+        }
+    }
+
+    #[test]
+    fn test_load_external_key_pair_and_sign() {
+        let mut context = create_ctx_with_session();
+        let private_scalar = [0x42; 32];
+        let secret_key = p256::SecretKey::from_slice(&private_scalar).unwrap();
+        let public_point = secret_key.public_key().to_encoded_point(false);
+
+        // The TPM requires fixed_tpm, fixed_parent and restricted
+        // to be clear when the sensitive area is loaded.
+        let object_attributes = ObjectAttributesBuilder::new()
+            .with_user_with_auth(true)
+            .with_sign_encrypt(true)
+            .build()
+            .expect("Failed to build object attributes");
+        let public = PublicBuilder::new()
+            .with_public_algorithm(PublicAlgorithm::Ecc)
+            .with_name_hashing_algorithm(HashingAlgorithm::Sha256)
+            .with_object_attributes(object_attributes)
+            .with_ecc_parameters(
+                PublicEccParametersBuilder::new_unrestricted_signing_key(
+                    EccScheme::EcDsa(HashScheme::new(HashingAlgorithm::Sha256)),
+                    EccCurve::NistP256,
+                )
+                .build()
+                .expect("Failed to build ECC parameters"),
+            )
+            .with_ecc_unique_identifier(&EccPoint::new(
+                EccParameter::try_from(public_point.x().unwrap().to_vec()).unwrap(),
+                EccParameter::try_from(public_point.y().unwrap().to_vec()).unwrap(),
+            ))
+            .build()
+            .expect("Failed to build the external key public");
+        let sensitive = Sensitive::Ecc {
+            auth_value: Default::default(),
+            seed_value: Default::default(),
+            sensitive: EccParameter::try_from(private_scalar.to_vec()).unwrap(),
+        };
+
+        let key_handle = context
+            .load_external(Some(sensitive), public, Hierarchy::Null)
+            .expect("Call to load_external failed");
+
+        let digest = Sha256::digest(MESSAGE);
+        let signature = match sign(
+            &mut context,
+            key_handle,
+            &Digest::try_from(digest.to_vec()).unwrap(),
+        ) {
+            Signature::EcDsa(signature) => P256Signature::from_scalars(
+                p256_scalar(signature.signature_r().value()),
+                p256_scalar(signature.signature_s().value()),
+            )
+            .expect("The TPM produced an invalid signature"),
+            _ => panic!("The TPM did not produce an ECDSA signature"),
+        };
+        VerifyingKey::from(secret_key.public_key())
+            .verify_prehash(&digest, &signature)
+            .expect("Failed to verify the signature made with the external private key");
+    }
 }
 
 mod test_read_public {
@@ -276,9 +670,16 @@ mod test_activate_credential {
 }
 
 mod test_unseal {
-    use crate::common::{create_ctx_with_session, create_public_sealed_object, decryption_key_pub};
+    use crate::common::{
+        create_ctx_with_session, create_public_sealed_object, decryption_key_pub,
+        get_pcr_policy_digest,
+    };
     use std::convert::TryFrom;
-    use tss_esapi::{interface_types::resource_handles::Hierarchy, structures::SensitiveData};
+    use tss_esapi::{
+        interface_types::{algorithm::HashingAlgorithm, resource_handles::Hierarchy},
+        structures::{Public, PublicBuilder, SensitiveData},
+        Error, WrapperErrorKind,
+    };
 
     #[test]
     fn unseal() {
@@ -327,4 +728,141 @@ mod test_unseal {
         let unsealed = unsealed.value();
         assert!(unsealed == testbytes);
     }
+
+    #[test]
+    fn test_unseal_sealing_object() {
+        let secret = [0xa5u8; 32];
+        let mut context = create_ctx_with_session();
+
+        let parent = context
+            .create_primary(
+                Hierarchy::Owner,
+                &decryption_key_pub(),
+                None,
+                None,
+                None,
+                None,
+            )
+            .unwrap()
+            .key_handle;
+
+        let sealing_object = PublicBuilder::build_sealing_object(None, HashingAlgorithm::Sha256)
+            .expect("Failed to build the sealing object");
+        if let Public::KeyedHash {
+            object_attributes, ..
+        } = sealing_object
+        {
+            assert!(object_attributes.fixed_tpm());
+            assert!(object_attributes.fixed_parent());
+            assert!(object_attributes.no_da());
+            assert!(object_attributes.user_with_auth());
+            assert!(!object_attributes.sign_encrypt());
+            assert!(!object_attributes.decrypt());
+        } else {
+            panic!("The sealing object is not a keyed hash object");
+        }
+
+        let result = context
+            .create(
+                parent,
+                &sealing_object,
+                None,
+                Some(&SensitiveData::try_from(secret.to_vec()).unwrap()),
+                None,
+                None,
+            )
+            .unwrap();
+        let loaded_object = context
+            .load(parent, result.out_private, &result.out_public)
+            .unwrap();
+        let unsealed = context.unseal(loaded_object.into()).unwrap();
+        assert_eq!(unsealed.value(), secret);
+    }
+
+    #[test]
+    fn test_unseal_sealing_object_with_pcr_policy() {
+        let secret = [0xa5u8; 32];
+        let mut context = create_ctx_with_session();
+
+        let parent = context
+            .create_primary(
+                Hierarchy::Owner,
+                &decryption_key_pub(),
+                None,
+                None,
+                None,
+                None,
+            )
+            .unwrap()
+            .key_handle;
+
+        let (policy_digest, _) = get_pcr_policy_digest(&mut context, false, true);
+        let sealing_object =
+            PublicBuilder::build_sealing_object(Some(policy_digest), HashingAlgorithm::Sha256)
+                .expect("Failed to build the sealing object");
+        let result = context
+            .create(
+                parent,
+                &sealing_object,
+                None,
+                Some(&SensitiveData::try_from(secret.to_vec()).unwrap()),
+                None,
+                None,
+            )
+            .unwrap();
+        let loaded_object = context
+            .load(parent, result.out_private, &result.out_public)
+            .unwrap();
+
+        // The object cannot be unsealed with its authorization value.
+        let _ = context.unseal(loaded_object.into()).unwrap_err();
+
+        let (_, policy_session) = get_pcr_policy_digest(&mut context, false, false);
+        let unsealed = context
+            .execute_with_session(Some(policy_session.into()), |ctx| {
+                ctx.unseal(loaded_object.into())
+            })
+            .unwrap();
+        assert_eq!(unsealed.value(), secret);
+    }
+
+    #[test]
+    fn test_unseal_without_session() {
+        let mut context = create_ctx_with_session();
+
+        let parent = context
+            .create_primary(
+                Hierarchy::Owner,
+                &decryption_key_pub(),
+                None,
+                None,
+                None,
+                None,
+            )
+            .unwrap()
+            .key_handle;
+        let sealing_object = PublicBuilder::build_sealing_object(None, HashingAlgorithm::Sha256)
+            .expect("Failed to build the sealing object");
+        let result = context
+            .create(
+                parent,
+                &sealing_object,
+                None,
+                Some(&SensitiveData::try_from(vec![0xa5; 32]).unwrap()),
+                None,
+                None,
+            )
+            .unwrap();
+        let loaded_object = context
+            .load(parent, result.out_private, &result.out_public)
+            .unwrap();
+
+        if let Err(Error::WrapperError(kind)) =
+            context.execute_without_session(|ctx| ctx.unseal(loaded_object.into()))
+        {
+            assert_eq!(kind, WrapperErrorKind::MissingAuthSession);
+        } else {
+            panic!("Unsealing without a session should fail");
+        }
+    }
 }
@@ -8,6 +8,7 @@ mod test_random {
         constants::SessionType,
         interface_types::algorithm::HashingAlgorithm,
         structures::{SensitiveData, SymmetricDefinition},
+        Error, WrapperErrorKind,
     };
 
     #[test]
@@ -68,4 +69,32 @@ mod test_random {
         let additional_data = SensitiveData::try_from(vec![1, 2, 3]).unwrap();
         context.stir_random(additional_data).unwrap();
     }
+
+    #[test]
+    fn test_stir_random_then_get_random() {
+        let mut context = create_ctx_without_session();
+        let entropy = context.get_random(32).expect("Failed to get random bytes");
+        context
+            .stir_random(
+                SensitiveData::try_from(entropy.value().to_vec())
+                    .expect("Failed to create sensitive data"),
+            )
+            .expect("Failed to stir the random number generator");
+        let random = context
+            .get_random(32)
+            .expect("Failed to get random bytes after stirring");
+        assert_eq!(32, random.len());
+        assert_ne!(entropy, random);
+    }
+
+    #[test]
+    fn test_stir_random_oversized_data() {
+        if let Err(Error::WrapperError(kind)) =
+            SensitiveData::try_from(vec![0xff; SensitiveData::MAX_SIZE + 1])
+        {
+            assert_eq!(kind, WrapperErrorKind::WrongParamSize);
+        } else {
+            panic!("Oversized sensitive data was not rejected");
+        }
+    }
 }
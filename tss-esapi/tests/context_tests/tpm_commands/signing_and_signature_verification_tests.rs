@@ -4,10 +4,14 @@ mod test_verify_signature {
     use crate::common::{create_ctx_with_session, signing_key_pub, HASH};
     use std::convert::{TryFrom, TryInto};
     use tss_esapi::{
-        constants::tss::{TPM2_ALG_NULL, TPM2_RH_NULL, TPM2_ST_HASHCHECK},
+        constants::{
+            response_code::Tss2ResponseCodeKind,
+            tss::{TPM2_ALG_NULL, TPM2_RH_NULL, TPM2_ST_HASHCHECK},
+        },
         interface_types::{algorithm::HashingAlgorithm, resource_handles::Hierarchy},
         structures::{Auth, Digest, PublicKeyRsa, RsaSignature, Signature},
         tss2_esys::{TPMT_SIG_SCHEME, TPMT_TK_HASHCHECK},
+        Error,
     };
 
     #[test]
@@ -55,6 +59,46 @@ mod test_verify_signature {
             .unwrap();
     }
 
+    #[test]
+    fn test_verify_tampered_digest() {
+        let mut context = create_ctx_with_session();
+
+        let key_handle = context
+            .create_primary(Hierarchy::Owner, &signing_key_pub(), None, None, None, None)
+            .unwrap()
+            .key_handle;
+
+        let scheme = TPMT_SIG_SCHEME {
+            scheme: TPM2_ALG_NULL,
+            details: Default::default(),
+        };
+        let validation = TPMT_TK_HASHCHECK {
+            tag: TPM2_ST_HASHCHECK,
+            hierarchy: TPM2_RH_NULL,
+            digest: Default::default(),
+        };
+        let signature = context
+            .sign(
+                key_handle,
+                &Digest::try_from(HASH[..32].to_vec()).unwrap(),
+                scheme,
+                validation.try_into().unwrap(),
+            )
+            .unwrap();
+
+        let mut tampered_digest = HASH[..32].to_vec();
+        tampered_digest[0] ^= 0xff;
+        if let Err(Error::Tss2Error(rc)) = context.verify_signature(
+            key_handle,
+            &Digest::try_from(tampered_digest).unwrap(),
+            signature,
+        ) {
+            assert_eq!(rc.kind(), Some(Tss2ResponseCodeKind::Signature));
+        } else {
+            panic!("Verifying the signature of a tampered digest should fail");
+        }
+    }
+
     #[test]
     fn test_verify_wrong_signature() {
         let mut context = create_ctx_with_session();
@@ -303,3 +347,58 @@ mod test_sign {
             .unwrap_err();
     }
 }
+
+#[cfg(feature = "sm2-tests")]
+mod test_sm2_sign {
+    use crate::common::{create_ctx_with_session, HASH};
+    use std::convert::{TryFrom, TryInto};
+    use tss_esapi::{
+        constants::tss::{TPM2_ALG_NULL, TPM2_RH_NULL, TPM2_ST_HASHCHECK},
+        interface_types::{
+            algorithm::{EccSchemeAlgorithm, HashingAlgorithm},
+            ecc::EccCurve,
+            resource_handles::Hierarchy,
+        },
+        structures::{Digest, EccScheme, Signature},
+        tss2_esys::{TPMT_SIG_SCHEME, TPMT_TK_HASHCHECK},
+        utils,
+    };
+
+    #[test]
+    fn test_sm2_sign_and_verify() {
+        let mut context = create_ctx_with_session();
+        let key_template = utils::create_unrestricted_signing_ecc_public(
+            EccScheme::create(
+                EccSchemeAlgorithm::Sm2,
+                Some(HashingAlgorithm::Sm3_256),
+                None,
+            )
+            .expect("Failed to create SM2 scheme"),
+            EccCurve::Sm2P256,
+        )
+        .expect("Failed to create SM2 key template");
+        let key_handle = context
+            .create_primary(Hierarchy::Owner, &key_template, None, None, None, None)
+            .expect("Failed to create SM2 primary key")
+            .key_handle;
+
+        let digest = Digest::try_from(HASH[..32].to_vec()).unwrap();
+        let scheme = TPMT_SIG_SCHEME {
+            scheme: TPM2_ALG_NULL,
+            details: Default::default(),
+        };
+        let validation = TPMT_TK_HASHCHECK {
+            tag: TPM2_ST_HASHCHECK,
+            hierarchy: TPM2_RH_NULL,
+            digest: Default::default(),
+        };
+        let signature = context
+            .sign(key_handle, &digest, scheme, validation.try_into().unwrap())
+            .expect("Failed to sign with SM2 key");
+        assert!(matches!(signature, Signature::Sm2(_)));
+
+        let _ = context
+            .verify_signature(key_handle, &digest, signature)
+            .expect("Failed to verify SM2 signature");
+    }
+}
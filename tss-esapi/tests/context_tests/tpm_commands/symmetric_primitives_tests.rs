@@ -6,6 +6,7 @@ mod test_encrypt_decrypt_2 {
     use tss_esapi::{
         abstraction::cipher::Cipher,
         attributes::ObjectAttributesBuilder,
+        handles::KeyHandle,
         interface_types::{
             algorithm::{HashingAlgorithm, PublicAlgorithm, SymmetricMode},
             key_bits::RsaKeyBits,
@@ -16,11 +17,10 @@ mod test_encrypt_decrypt_2 {
             Auth, InitialValue, MaxBuffer, PublicBuilder, RsaExponent, SensitiveData,
             SymmetricCipherParameters,
         },
+        Context,
     };
-    #[test]
-    fn test_encrypt_decrypt_with_aes_128_cfb_symmetric_key() {
-        let mut context = create_ctx_without_session();
 
+    fn create_aes_128_cfb_symmetric_key(context: &mut Context) -> KeyHandle {
         context
             .tr_set_auth(Hierarchy::Owner.into(), &Auth::default())
             .expect("Failed to set auth to empty for owner");
@@ -120,6 +120,14 @@ mod test_encrypt_decrypt_2 {
             .tr_set_auth(symmetric_key_handle.into(), &symmetric_key_auth)
             .expect("Failed to set auth on symmetric key handle");
 
+        symmetric_key_handle
+    }
+
+    #[test]
+    fn test_encrypt_decrypt_with_aes_128_cfb_symmetric_key() {
+        let mut context = create_ctx_without_session();
+        let symmetric_key_handle = create_aes_128_cfb_symmetric_key(&mut context);
+
         let initial_value =
             InitialValue::try_from(vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16])
                 .expect("Failed to create InitialValue from data");
@@ -155,14 +163,96 @@ mod test_encrypt_decrypt_2 {
 
         debug_assert_eq!(data, decrypted_data);
     }
+
+    #[test]
+    fn test_encrypt_decrypt_2_chaining_initial_value() {
+        let mut context = create_ctx_without_session();
+        let symmetric_key_handle = create_aes_128_cfb_symmetric_key(&mut context);
+
+        let initial_value =
+            InitialValue::try_from(vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16])
+                .expect("Failed to create InitialValue from data");
+
+        let first_block: Vec<u8> = (0..16).collect();
+        let second_block: Vec<u8> = (16..32).collect();
+        let data = MaxBuffer::try_from([first_block.clone(), second_block.clone()].concat())
+            .expect("Failed to create MaxBuffer from data");
+
+        let (encrypted_data, _) =
+            context.execute_with_session(Some(AuthSession::Password), |ctx| {
+                ctx.encrypt_decrypt_2(
+                    symmetric_key_handle,
+                    false,
+                    SymmetricMode::Cfb,
+                    &data,
+                    &initial_value,
+                )
+                .expect("Call to encrypt_decrypt_2 failed when encrypting data")
+            });
+
+        // Encrypting the same data block by block, chaining the returned initial
+        // value, should produce the same cipher text.
+        let (encrypted_first_block, chained_initial_value) =
+            context.execute_with_session(Some(AuthSession::Password), |ctx| {
+                ctx.encrypt_decrypt_2(
+                    symmetric_key_handle,
+                    false,
+                    SymmetricMode::Cfb,
+                    &MaxBuffer::try_from(first_block).expect("Failed to create MaxBuffer"),
+                    &initial_value,
+                )
+                .expect("Call to encrypt_decrypt_2 failed when encrypting first block")
+            });
+
+        let (encrypted_second_block, _) =
+            context.execute_with_session(Some(AuthSession::Password), |ctx| {
+                ctx.encrypt_decrypt_2(
+                    symmetric_key_handle,
+                    false,
+                    SymmetricMode::Cfb,
+                    &MaxBuffer::try_from(second_block).expect("Failed to create MaxBuffer"),
+                    &chained_initial_value,
+                )
+                .expect("Call to encrypt_decrypt_2 failed when encrypting second block")
+            });
+
+        assert_eq!(
+            encrypted_data.value(),
+            &[
+                encrypted_first_block.value(),
+                encrypted_second_block.value()
+            ]
+            .concat()[..]
+        );
+
+        let (decrypted_data, _) =
+            context.execute_with_session(Some(AuthSession::Password), |ctx| {
+                ctx.encrypt_decrypt_2(
+                    symmetric_key_handle,
+                    true,
+                    SymmetricMode::Cfb,
+                    &encrypted_data,
+                    &initial_value,
+                )
+                .expect("Call to encrypt_decrypt_2 failed when decrypting data")
+            });
+
+        assert_eq!(data, decrypted_data);
+    }
 }
 
 mod test_hash {
     use crate::common::create_ctx_without_session;
     use std::convert::TryFrom;
     use tss_esapi::{
+        constants::tss::{
+            TPM2_CC_Hash, TPM2_ALG_SHA256, TPM2_RC_SUCCESS, TPM2_RH_OWNER, TPM2_ST_HASHCHECK,
+            TPM2_ST_NO_SESSIONS,
+        },
         interface_types::{algorithm::HashingAlgorithm, resource_handles::Hierarchy},
         structures::{MaxBuffer, Ticket},
+        tcti_ldr::{MockTcti, TctiNameConf},
+        Context,
     };
 
     #[test]
@@ -187,6 +277,49 @@ mod test_hash {
         assert_eq!(ticket.hierarchy(), expected_hierarchy);
         assert_ne!(ticket.digest().len(), 0); // Should do some better checking of the digest
     }
+
+    #[test]
+    fn test_hash_hierarchy_marshalling() {
+        let mock_tcti = MockTcti::new();
+        // Marshalled TPM2_Hash response: header, followed by the
+        // outHash and a hash check ticket with an empty digest.
+        let mut response = Vec::new();
+        response.extend_from_slice(&TPM2_ST_NO_SESSIONS.to_be_bytes());
+        response.extend_from_slice(&52u32.to_be_bytes());
+        response.extend_from_slice(&TPM2_RC_SUCCESS.to_be_bytes());
+        response.extend_from_slice(&32u16.to_be_bytes());
+        response.extend_from_slice(&[0xaa; 32]);
+        response.extend_from_slice(&TPM2_ST_HASHCHECK.to_be_bytes());
+        response.extend_from_slice(&TPM2_RH_OWNER.to_be_bytes());
+        response.extend_from_slice(&0u16.to_be_bytes());
+        mock_tcti.push_response(response);
+
+        let mut context =
+            Context::new(TctiNameConf::Mock(mock_tcti.clone())).expect("Failed to create context");
+        let data = [0x1, 0x2, 0x3];
+        let (digest, ticket) = context
+            .hash(
+                &MaxBuffer::try_from(data.to_vec()).expect("Failed to create buffer"),
+                HashingAlgorithm::Sha256,
+                Hierarchy::Owner,
+            )
+            .expect("Failed to hash through the mock TCTI");
+        assert_eq!(digest.value(), [0xaa; 32]);
+        assert_eq!(ticket.hierarchy(), Hierarchy::Owner);
+
+        // The hierarchy is passed to the ESAPI as an ESYS handle with version 3
+        // of the library and as a TPM handle with version 2; either way the TPM
+        // handle of the hierarchy has to be sent to the TPM.
+        let commands = mock_tcti.commands();
+        assert_eq!(commands.len(), 1);
+        let command = &commands[0];
+        assert_eq!(command.len(), 21);
+        assert_eq!(command[6..10], TPM2_CC_Hash.to_be_bytes());
+        assert_eq!(command[10..12], 3u16.to_be_bytes());
+        assert_eq!(command[12..15], data);
+        assert_eq!(command[15..17], TPM2_ALG_SHA256.to_be_bytes());
+        assert_eq!(command[17..21], TPM2_RH_OWNER.to_be_bytes());
+    }
 }
 
 mod test_hmac {
@@ -11,6 +11,26 @@ mod test_self_test {
     }
 }
 
+mod test_incremental_self_test {
+    use crate::common::create_ctx_without_session;
+    use tss_esapi::{constants::AlgorithmIdentifier, structures::AlgorithmListBuilder};
+
+    #[test]
+    fn test_incremental_self_test() {
+        let mut context = create_ctx_without_session();
+        let to_test = AlgorithmListBuilder::new()
+            .with_algorithm(AlgorithmIdentifier::Sha256)
+            .with_algorithm(AlgorithmIdentifier::Rsa)
+            .build()
+            .expect("Failed to build algorithm list");
+        let _ = context
+            .incremental_self_test(to_test)
+            .expect("Failed to call incremental_self_test");
+        let (_, rc) = context.get_test_result().unwrap();
+        rc.expect("The self test did not succeed");
+    }
+}
+
 mod test_get_test_result {
     use crate::common::create_ctx_without_session;
     #[test]
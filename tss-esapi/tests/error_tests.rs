@@ -0,0 +1,72 @@
+// Copyright 2021 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use tss_esapi::{
+    constants::{
+        tss::{
+            TPM2_RC_ATTRIBUTES, TPM2_RC_AUTH_FAIL, TPM2_RC_INITIALIZE, TPM2_RC_LOCKOUT,
+            TPM2_RC_RETRY, TPM2_RC_TESTING, TPM2_RC_VALUE, TPM2_RC_YIELDED,
+        },
+        TpmResponseCode, Tss2ResponseCode,
+    },
+    Error, WrapperErrorKind,
+};
+
+#[test]
+fn test_retryable_warnings() {
+    for rc in [TPM2_RC_RETRY, TPM2_RC_TESTING, TPM2_RC_YIELDED] {
+        let error = Error::Tss2Error(Tss2ResponseCode::from(rc));
+        assert!(error.is_warning(), "{:#x} should be a warning", rc);
+        assert!(error.is_retryable(), "{:#x} should be retryable", rc);
+    }
+}
+
+#[test]
+fn test_non_retryable_warning() {
+    let error = Error::Tss2Error(Tss2ResponseCode::from(TPM2_RC_LOCKOUT));
+    assert!(error.is_warning());
+    assert!(!error.is_retryable());
+}
+
+#[test]
+fn test_errors_are_not_warnings() {
+    for rc in [TPM2_RC_INITIALIZE, TPM2_RC_VALUE, TPM2_RC_AUTH_FAIL] {
+        let error = Error::Tss2Error(Tss2ResponseCode::from(rc));
+        assert!(!error.is_warning(), "{:#x} should not be a warning", rc);
+        assert!(!error.is_retryable(), "{:#x} should not be retryable", rc);
+    }
+}
+
+#[test]
+fn test_success_is_not_a_warning() {
+    let error = Error::Tss2Error(Tss2ResponseCode::from(0));
+    assert!(!error.is_warning());
+    assert!(!error.is_retryable());
+}
+
+#[test]
+fn test_wrapper_error_is_not_a_warning() {
+    let error = Error::WrapperError(WrapperErrorKind::InvalidParam);
+    assert!(!error.is_warning());
+    assert!(!error.is_retryable());
+}
+
+#[test]
+fn test_tpm_response_code_mapping() {
+    for (rc, tpm_response_code) in [
+        (TPM2_RC_AUTH_FAIL, TpmResponseCode::AuthFail),
+        (TPM2_RC_VALUE, TpmResponseCode::Value),
+        (TPM2_RC_ATTRIBUTES, TpmResponseCode::AttributesMismatch),
+        (TPM2_RC_INITIALIZE, TpmResponseCode::Initialize),
+        (TPM2_RC_LOCKOUT, TpmResponseCode::Lockout),
+        (TPM2_RC_RETRY, TpmResponseCode::Retry),
+    ] {
+        let error = Error::Tss2Error(Tss2ResponseCode::from(rc));
+        assert_eq!(error.tpm_response_code(), Some(tpm_response_code));
+    }
+}
+
+#[test]
+fn test_success_has_no_tpm_response_code() {
+    let error = Error::Tss2Error(Tss2ResponseCode::from(0));
+    assert_eq!(error.tpm_response_code(), None);
+}
@@ -110,6 +110,60 @@ mod test_pcr_handle {
     }
 }
 
+mod test_act_handle {
+    use std::convert::{From, TryFrom};
+    use tss_esapi::{
+        handles::{ActHandle, AuthHandle, ObjectHandle},
+        tss2_esys::ESYS_TR,
+    };
+
+    #[test]
+    fn test_conversion_of_invalid_handle() {
+        let invalid_value: ESYS_TR = 0xFFFFFFFF;
+        let invalid_object_handle: ObjectHandle = ObjectHandle::from(invalid_value);
+        let _ = ActHandle::try_from(invalid_value).unwrap_err();
+        let _ = ActHandle::try_from(invalid_object_handle).unwrap_err();
+        // Right past the last ACT handle.
+        let _ = ActHandle::try_from(0x130).unwrap_err();
+    }
+
+    #[test]
+    fn test_conversion_of_valid_handle() {
+        let acts = [
+            ActHandle::Act0,
+            ActHandle::Act1,
+            ActHandle::Act2,
+            ActHandle::Act3,
+            ActHandle::Act4,
+            ActHandle::Act5,
+            ActHandle::Act6,
+            ActHandle::Act7,
+            ActHandle::Act8,
+            ActHandle::Act9,
+            ActHandle::ActA,
+            ActHandle::ActB,
+            ActHandle::ActC,
+            ActHandle::ActD,
+            ActHandle::ActE,
+            ActHandle::ActF,
+        ];
+        for (offset, act_handle) in acts.iter().enumerate() {
+            // The ESYS_TR values of the ACTs start at ESYS_TR_RH_ACT_FIRST.
+            let esys_tr_value: ESYS_TR = 0x120 + offset as ESYS_TR;
+            assert_eq!(esys_tr_value, ESYS_TR::from(*act_handle));
+            assert_eq!(*act_handle, ActHandle::try_from(esys_tr_value).unwrap());
+
+            let object_handle = ObjectHandle::from(*act_handle);
+            assert_eq!(ObjectHandle::from(esys_tr_value), object_handle);
+            assert_eq!(*act_handle, ActHandle::try_from(object_handle).unwrap());
+
+            let auth_handle = AuthHandle::from(*act_handle);
+            assert_eq!(AuthHandle::from(esys_tr_value), auth_handle);
+            assert_eq!(*act_handle, ActHandle::try_from(auth_handle).unwrap());
+        }
+    }
+}
+
 mod test_auth_handle {
     use tss_esapi::{
         handles::{AuthHandle, ObjectHandle},
@@ -0,0 +1,42 @@
+// Copyright 2021 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use std::convert::TryFrom;
+use tss_esapi::{
+    constants::tss::{
+        TPM2_ECC_BN_P256, TPM2_ECC_BN_P638, TPM2_ECC_NIST_P192, TPM2_ECC_NIST_P224,
+        TPM2_ECC_NIST_P256, TPM2_ECC_NIST_P384, TPM2_ECC_NIST_P521, TPM2_ECC_SM2_P256,
+    },
+    interface_types::ecc::EccCurve,
+    tss2_esys::TPMI_ECC_CURVE,
+};
+
+#[test]
+fn test_ecc_curve_conversions() {
+    for (ecc_curve, tpmi_ecc_curve) in [
+        (EccCurve::NistP192, TPM2_ECC_NIST_P192),
+        (EccCurve::NistP224, TPM2_ECC_NIST_P224),
+        (EccCurve::NistP256, TPM2_ECC_NIST_P256),
+        (EccCurve::NistP384, TPM2_ECC_NIST_P384),
+        (EccCurve::NistP521, TPM2_ECC_NIST_P521),
+        (EccCurve::BnP256, TPM2_ECC_BN_P256),
+        (EccCurve::BnP638, TPM2_ECC_BN_P638),
+        (EccCurve::Sm2P256, TPM2_ECC_SM2_P256),
+    ] {
+        assert_eq!(tpmi_ecc_curve, TPMI_ECC_CURVE::from(ecc_curve));
+        assert_eq!(
+            ecc_curve,
+            EccCurve::try_from(tpmi_ecc_curve).expect("Failed to convert ECC curve")
+        );
+    }
+}
+
+#[test]
+fn test_sm2_p256_raw_value() {
+    // TPM_ECC_SM2_P256 is defined as 0x0020 in the TPM specification.
+    assert_eq!(0x0020, TPMI_ECC_CURVE::from(EccCurve::Sm2P256));
+}
+
+#[test]
+fn test_invalid_ecc_curve() {
+    let _ = EccCurve::try_from(0x0030).unwrap_err();
+}
@@ -0,0 +1,36 @@
+// Copyright 2021 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+mod test_rsa_key_bits {
+    use std::convert::TryFrom;
+    use tss_esapi::{
+        interface_types::key_bits::RsaKeyBits, tss2_esys::TPMI_RSA_KEY_BITS, Error,
+        WrapperErrorKind,
+    };
+
+    #[test]
+    fn test_conversions() {
+        for (key_bits, tpmi_rsa_key_bits) in [
+            (RsaKeyBits::Rsa1024, 1024),
+            (RsaKeyBits::Rsa2048, 2048),
+            (RsaKeyBits::Rsa3072, 3072),
+            (RsaKeyBits::Rsa4096, 4096),
+        ] {
+            assert_eq!(tpmi_rsa_key_bits, TPMI_RSA_KEY_BITS::from(key_bits));
+            assert_eq!(
+                key_bits,
+                RsaKeyBits::try_from(tpmi_rsa_key_bits).expect("Failed to convert key bits")
+            );
+        }
+    }
+
+    #[test]
+    fn test_unsupported_key_bits() {
+        for tpmi_rsa_key_bits in [0, 512, 1536, 8192] {
+            if let Err(Error::WrapperError(kind)) = RsaKeyBits::try_from(tpmi_rsa_key_bits) {
+                assert_eq!(kind, WrapperErrorKind::InvalidParam);
+            } else {
+                panic!("{} should not be valid RSA key bits", tpmi_rsa_key_bits);
+            }
+        }
+    }
+}
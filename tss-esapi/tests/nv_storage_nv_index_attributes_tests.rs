@@ -325,4 +325,74 @@ mod test_nv_storage_nv_index_attributes {
                 .0
         );
     }
+
+    #[test]
+    fn test_nv_index_attributes_builder_conflicting_index_types() {
+        for builder in [
+            NvIndexAttributesBuilder::new()
+                .with_nv_counter(true)
+                .with_nv_bits(true),
+            NvIndexAttributesBuilder::new()
+                .with_nv_bits(true)
+                .with_nv_extend(true),
+            NvIndexAttributesBuilder::new()
+                .with_nv_extend(true)
+                .with_nv_counter(true),
+            NvIndexAttributesBuilder::new()
+                .with_nv_index_type(NvIndexType::PinPass)
+                .with_nv_counter(true),
+        ] {
+            assert_eq!(
+                Err(Error::WrapperError(WrapperErrorKind::InconsistentParams)),
+                builder.with_owner_read(true).with_owner_write(true).build()
+            );
+        }
+    }
+
+    #[test]
+    fn test_nv_index_attributes_builder_counter() {
+        let nv_index_attributes = NvIndexAttributesBuilder::new()
+            .with_owner_read(true)
+            .with_owner_write(true)
+            .with_nv_counter(true)
+            .build()
+            .expect("Failed to build counter attributes");
+        assert_eq!(
+            NvIndexType::Counter,
+            nv_index_attributes
+                .index_type()
+                .expect("Failed to get index type")
+        );
+
+        // Clearing the counter index type makes the index ordinary again.
+        let nv_index_attributes = NvIndexAttributesBuilder::with_attributes(nv_index_attributes)
+            .with_nv_counter(false)
+            .build()
+            .expect("Failed to build ordinary attributes");
+        assert_eq!(
+            NvIndexType::Ordinary,
+            nv_index_attributes
+                .index_type()
+                .expect("Failed to get index type")
+        );
+    }
+
+    #[test]
+    fn test_nv_index_attributes_builder_cleared_conflicting_index_type() {
+        // Clearing the conflicting index type before building resolves the conflict.
+        let nv_index_attributes = NvIndexAttributesBuilder::new()
+            .with_owner_read(true)
+            .with_owner_write(true)
+            .with_nv_counter(true)
+            .with_nv_bits(true)
+            .with_nv_bits(false)
+            .build()
+            .expect("Failed to build counter attributes");
+        assert_eq!(
+            NvIndexType::Counter,
+            nv_index_attributes
+                .index_type()
+                .expect("Failed to get index type")
+        );
+    }
 }
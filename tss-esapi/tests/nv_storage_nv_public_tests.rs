@@ -0,0 +1,80 @@
+// Copyright 2021 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use tss_esapi::{
+    attributes::{NvIndexAttributes, NvIndexAttributesBuilder},
+    handles::NvIndexTpmHandle,
+    interface_types::algorithm::HashingAlgorithm,
+    nv::storage::NvPublicBuilder,
+    Error, WrapperErrorKind,
+};
+
+mod test_nv_storage_nv_public {
+    use super::*;
+
+    fn counter_attributes() -> NvIndexAttributes {
+        NvIndexAttributesBuilder::new()
+            .with_owner_read(true)
+            .with_owner_write(true)
+            .with_nv_counter(true)
+            .build()
+            .expect("Failed to build counter attributes")
+    }
+
+    fn nv_public_builder(attributes: NvIndexAttributes, data_size: usize) -> NvPublicBuilder {
+        NvPublicBuilder::new()
+            .with_nv_index(NvIndexTpmHandle::new(0x01500029).unwrap())
+            .with_index_name_algorithm(HashingAlgorithm::Sha256)
+            .with_index_attributes(attributes)
+            .with_data_area_size(data_size)
+    }
+
+    #[test]
+    fn test_nv_public_builder_counter_data_size() {
+        let nv_public = nv_public_builder(counter_attributes(), 8)
+            .build()
+            .expect("Failed to build NvPublic for counter");
+        assert_eq!(8, nv_public.data_size());
+
+        for data_size in [0, 4, 16, 32] {
+            assert_eq!(
+                Err(Error::WrapperError(WrapperErrorKind::InconsistentParams)),
+                nv_public_builder(counter_attributes(), data_size).build()
+            );
+        }
+    }
+
+    #[test]
+    fn test_nv_public_builder_bits_data_size() {
+        let bits_attributes = NvIndexAttributesBuilder::new()
+            .with_owner_read(true)
+            .with_owner_write(true)
+            .with_nv_bits(true)
+            .build()
+            .expect("Failed to build bit field attributes");
+
+        let _ = nv_public_builder(bits_attributes, 8)
+            .build()
+            .expect("Failed to build NvPublic for bit field");
+        assert_eq!(
+            Err(Error::WrapperError(WrapperErrorKind::InconsistentParams)),
+            nv_public_builder(bits_attributes, 32).build()
+        );
+    }
+
+    #[test]
+    fn test_nv_public_builder_oversized_data() {
+        let ordinary_attributes = NvIndexAttributesBuilder::new()
+            .with_owner_read(true)
+            .with_owner_write(true)
+            .build()
+            .expect("Failed to build ordinary attributes");
+
+        let _ = nv_public_builder(ordinary_attributes, u16::MAX.into())
+            .build()
+            .expect("Failed to build NvPublic with maximum data size");
+        assert_eq!(
+            Err(Error::WrapperError(WrapperErrorKind::InvalidParam)),
+            nv_public_builder(ordinary_attributes, usize::from(u16::MAX) + 1).build()
+        );
+    }
+}
@@ -0,0 +1,37 @@
+// Copyright 2021 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use tss_esapi::{structures::EncryptedSecret, Error, WrapperErrorKind};
+
+#[test]
+fn test_try_from_bytes() {
+    let bytes = [0xab; 64];
+    let encrypted_secret = EncryptedSecret::try_from_bytes(&bytes).unwrap();
+    assert_eq!(encrypted_secret.as_bytes(), &bytes[..]);
+    assert_eq!(encrypted_secret.len(), bytes.len());
+    assert!(!encrypted_secret.is_empty());
+}
+
+#[test]
+fn test_max_sized_bytes() {
+    let bytes = vec![0xab; EncryptedSecret::MAX_SIZE];
+    let encrypted_secret = EncryptedSecret::try_from_bytes(&bytes).unwrap();
+    assert_eq!(encrypted_secret.len(), EncryptedSecret::MAX_SIZE);
+}
+
+#[test]
+fn test_too_large_bytes() {
+    let bytes = vec![0xab; EncryptedSecret::MAX_SIZE + 1];
+    if let Err(Error::WrapperError(kind)) = EncryptedSecret::try_from_bytes(&bytes) {
+        assert_eq!(kind, WrapperErrorKind::WrongParamSize);
+    } else {
+        panic!("Creating an EncryptedSecret from too many bytes should fail");
+    }
+}
+
+#[test]
+fn test_default_is_empty() {
+    let encrypted_secret: EncryptedSecret = Default::default();
+    assert!(encrypted_secret.is_empty());
+    assert_eq!(encrypted_secret.len(), 0);
+    assert!(encrypted_secret.as_bytes().is_empty());
+}
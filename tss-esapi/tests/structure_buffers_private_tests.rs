@@ -0,0 +1,37 @@
+// Copyright 2021 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use tss_esapi::{structures::Private, Error, WrapperErrorKind};
+
+#[test]
+fn test_try_from_bytes() {
+    let bytes = [0xab; 64];
+    let private = Private::try_from_bytes(&bytes).unwrap();
+    assert_eq!(private.as_bytes(), &bytes[..]);
+    assert_eq!(private.len(), bytes.len());
+    assert!(!private.is_empty());
+}
+
+#[test]
+fn test_max_sized_bytes() {
+    let bytes = vec![0xab; Private::MAX_SIZE];
+    let private = Private::try_from_bytes(&bytes).unwrap();
+    assert_eq!(private.len(), Private::MAX_SIZE);
+}
+
+#[test]
+fn test_too_large_bytes() {
+    let bytes = vec![0xab; Private::MAX_SIZE + 1];
+    if let Err(Error::WrapperError(kind)) = Private::try_from_bytes(&bytes) {
+        assert_eq!(kind, WrapperErrorKind::WrongParamSize);
+    } else {
+        panic!("Creating a Private from too many bytes should fail");
+    }
+}
+
+#[test]
+fn test_default_is_empty() {
+    let private: Private = Default::default();
+    assert!(private.is_empty());
+    assert_eq!(private.len(), 0);
+    assert!(private.as_bytes().is_empty());
+}
@@ -0,0 +1,58 @@
+// Copyright 2021 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use std::convert::TryFrom;
+use tss_esapi::constants::{
+    tss::{TPM2_ALG_RSA, TPM2_ALG_SHA256},
+    AlgorithmIdentifier,
+};
+use tss_esapi::structures::{AlgorithmList, AlgorithmListBuilder};
+use tss_esapi::tss2_esys::TPML_ALG;
+
+mod test_algorithm_list {
+    use super::*;
+
+    #[test]
+    fn test_conversion_to_and_from_tss_algorithm_list() {
+        let algorithm_list = AlgorithmListBuilder::new()
+            .with_algorithm(AlgorithmIdentifier::Sha256)
+            .with_algorithm(AlgorithmIdentifier::Rsa)
+            .build()
+            .expect("Failed to build algorithm list");
+
+        let tss_algorithm_list = TPML_ALG::from(algorithm_list.clone());
+        assert_eq!(tss_algorithm_list.count, 2);
+        assert_eq!(tss_algorithm_list.algorithms[0], TPM2_ALG_SHA256);
+        assert_eq!(tss_algorithm_list.algorithms[1], TPM2_ALG_RSA);
+
+        assert_eq!(
+            algorithm_list,
+            AlgorithmList::try_from(tss_algorithm_list).expect("Failed to convert from TPML_ALG")
+        );
+    }
+
+    #[test]
+    fn test_build_exceeding_max_limit() {
+        let mut algorithm_list_builder = AlgorithmListBuilder::new();
+        for _ in 0..AlgorithmList::MAX_SIZE {
+            algorithm_list_builder =
+                algorithm_list_builder.with_algorithm(AlgorithmIdentifier::Sha256);
+        }
+        let mut algorithm_list = algorithm_list_builder
+            .clone()
+            .build()
+            .expect("Failed to build algorithm list");
+        algorithm_list.add(AlgorithmIdentifier::Sha256).unwrap_err();
+        algorithm_list_builder
+            .with_algorithm(AlgorithmIdentifier::Sha256)
+            .build()
+            .unwrap_err();
+    }
+
+    #[test]
+    fn test_invalid_tss_algorithm() {
+        let mut tss_algorithm_list: TPML_ALG = Default::default();
+        tss_algorithm_list.algorithms[0] = 0xFFFF;
+        tss_algorithm_list.count = 1;
+        AlgorithmList::try_from(tss_algorithm_list).unwrap_err();
+    }
+}
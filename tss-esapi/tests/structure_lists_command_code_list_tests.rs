@@ -0,0 +1,59 @@
+// Copyright 2021 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use std::convert::TryFrom;
+use tss_esapi::constants::{
+    tss::{TPM2_CC_GetRandom, TPM2_CC_Sign},
+    CommandCode,
+};
+use tss_esapi::structures::{CommandCodeList, CommandCodeListBuilder};
+use tss_esapi::tss2_esys::TPML_CC;
+
+mod test_command_code_list {
+    use super::*;
+
+    #[test]
+    fn test_conversion_to_and_from_tss_command_code_list() {
+        let command_code_list = CommandCodeListBuilder::new()
+            .with_command_code(CommandCode::GetRandom)
+            .with_command_code(CommandCode::Sign)
+            .build()
+            .expect("Failed to build command code list");
+
+        let tss_command_code_list = TPML_CC::from(command_code_list.clone());
+        assert_eq!(tss_command_code_list.count, 2);
+        assert_eq!(tss_command_code_list.commandCodes[0], TPM2_CC_GetRandom);
+        assert_eq!(tss_command_code_list.commandCodes[1], TPM2_CC_Sign);
+
+        assert_eq!(
+            command_code_list,
+            CommandCodeList::try_from(tss_command_code_list)
+                .expect("Failed to convert from TPML_CC")
+        );
+    }
+
+    #[test]
+    fn test_build_exceeding_max_limit() {
+        let mut command_code_list_builder = CommandCodeListBuilder::new();
+        for _ in 0..CommandCodeList::MAX_SIZE {
+            command_code_list_builder =
+                command_code_list_builder.with_command_code(CommandCode::GetRandom);
+        }
+        let mut command_code_list = command_code_list_builder
+            .clone()
+            .build()
+            .expect("Failed to build command code list");
+        command_code_list.add(CommandCode::GetRandom).unwrap_err();
+        command_code_list_builder
+            .with_command_code(CommandCode::GetRandom)
+            .build()
+            .unwrap_err();
+    }
+
+    #[test]
+    fn test_invalid_tss_command_code() {
+        let mut tss_command_code_list: TPML_CC = Default::default();
+        tss_command_code_list.commandCodes[0] = 0xFFFF;
+        tss_command_code_list.count = 1;
+        CommandCodeList::try_from(tss_command_code_list).unwrap_err();
+    }
+}
@@ -0,0 +1,48 @@
+// Copyright 2021 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use std::convert::TryFrom;
+use tss_esapi::{
+    constants::{
+        tss::{TPM2_AT_ANY, TPM2_AT_ERROR, TPM2_AT_PV1, TPM2_AT_VEND},
+        AcCapability,
+    },
+    structures::AcOutput,
+    tss2_esys::{TPMS_AC_OUTPUT, TPM_AT},
+    Error, WrapperErrorKind,
+};
+
+#[test]
+fn test_ac_capability_conversions() {
+    let conversion_check = |tpm_at: TPM_AT, ac_capability: AcCapability| {
+        assert_eq!(tpm_at, TPM_AT::from(ac_capability));
+        assert_eq!(
+            ac_capability,
+            AcCapability::try_from(tpm_at).expect("Failed to convert TPM_AT")
+        );
+    };
+    conversion_check(TPM2_AT_ANY, AcCapability::Any);
+    conversion_check(TPM2_AT_ERROR, AcCapability::Error);
+    conversion_check(TPM2_AT_PV1, AcCapability::Pv1);
+    conversion_check(TPM2_AT_VEND, AcCapability::Vendor(TPM2_AT_VEND));
+    conversion_check(TPM2_AT_VEND + 1, AcCapability::Vendor(TPM2_AT_VEND + 1));
+}
+
+#[test]
+fn test_invalid_ac_capability() {
+    match AcCapability::try_from(TPM2_AT_PV1 + 1) {
+        Err(Error::WrapperError(kind)) => assert_eq!(kind, WrapperErrorKind::InvalidParam),
+        other => panic!("Invalid TPM_AT value was converted: {:?}", other),
+    }
+}
+
+#[test]
+fn test_ac_output_conversions() {
+    let ac_output = AcOutput::new(AcCapability::Pv1, 0xdead_beef);
+    let tpms_ac_output = TPMS_AC_OUTPUT::from(ac_output);
+    assert_eq!(TPM2_AT_PV1, tpms_ac_output.tag);
+    assert_eq!(0xdead_beef, tpms_ac_output.data);
+    assert_eq!(
+        ac_output,
+        AcOutput::try_from(tpms_ac_output).expect("Failed to convert TPMS_AC_OUTPUT")
+    );
+}
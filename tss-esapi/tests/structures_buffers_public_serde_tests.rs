@@ -0,0 +1,110 @@
+// Copyright 2021 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+#![cfg(feature = "serde")]
+mod common;
+
+use std::convert::TryFrom;
+use tss_esapi::{
+    interface_types::{
+        algorithm::{EccSchemeAlgorithm, HashingAlgorithm, RsaSchemeAlgorithm},
+        ecc::EccCurve,
+        key_bits::RsaKeyBits,
+        resource_handles::Hierarchy,
+    },
+    structures::{EccScheme, Public, PublicKeyRsa, RsaExponent, RsaScheme},
+    traits::Marshall,
+    utils,
+};
+
+fn json_round_trip(public: &Public) -> Public {
+    let json = serde_json::to_string(public).expect("Failed to serialize Public");
+    let deserialized_public: Public =
+        serde_json::from_str(&json).expect("Failed to deserialize Public");
+    assert_eq!(
+        public.marshall().expect("Failed to marshall Public"),
+        deserialized_public
+            .marshall()
+            .expect("Failed to marshall the deserialized Public")
+    );
+    deserialized_public
+}
+
+#[test]
+fn test_rsa_public_json_round_trip() {
+    // Rsa template with a null scheme.
+    let _ = json_round_trip(&common::encryption_decryption_key_pub());
+
+    // Rsa template with a unique identifier.
+    let _ = json_round_trip(
+        &utils::create_unrestricted_signing_rsa_public_with_unique(
+            RsaScheme::create(RsaSchemeAlgorithm::RsaSsa, Some(HashingAlgorithm::Sha256))
+                .expect("Failed to create RSA scheme"),
+            RsaKeyBits::Rsa2048,
+            RsaExponent::default(),
+            &PublicKeyRsa::try_from(vec![0xab; 256]).expect("Failed to create unique identifier"),
+        )
+        .expect("Failed to create public structure"),
+    );
+}
+
+#[test]
+fn test_ecc_public_json_round_trip() {
+    let _ = json_round_trip(
+        &utils::create_unrestricted_signing_ecc_public(
+            EccScheme::create(
+                EccSchemeAlgorithm::EcDsa,
+                Some(HashingAlgorithm::Sha256),
+                None,
+            )
+            .expect("Failed to create ecc scheme"),
+            EccCurve::NistP256,
+        )
+        .expect("Failed to create public structure"),
+    );
+}
+
+#[test]
+fn test_public_serialized_field_by_field() {
+    let value =
+        serde_json::to_value(common::signing_key_pub()).expect("Failed to serialize Public");
+    let rsa = &value["Rsa"];
+    assert_eq!(rsa["name_hashing_algorithm"], "Sha256");
+    assert_eq!(rsa["parameters"]["key_bits"], "Rsa2048");
+    assert_eq!(rsa["parameters"]["exponent"], 65537);
+    assert_eq!(
+        rsa["parameters"]["rsa_scheme"]["RsaSsa"]["hashing_algorithm"],
+        "Sha256"
+    );
+    assert_eq!(rsa["parameters"]["symmetric_definition_object"], "Null");
+}
+
+#[test]
+fn test_invalid_public_field_deserialization() {
+    let value =
+        serde_json::to_value(common::signing_key_pub()).expect("Failed to serialize Public");
+
+    // The exponent is not a prime.
+    let mut invalid_exponent = value.clone();
+    invalid_exponent["Rsa"]["parameters"]["exponent"] = 4.into();
+    assert!(serde_json::from_value::<Public>(invalid_exponent).is_err());
+
+    // The unique identifier is larger than the maximum RSA key size.
+    let mut invalid_unique = value;
+    invalid_unique["Rsa"]["unique"] = vec![0xab; PublicKeyRsa::MAX_SIZE + 1].into();
+    assert!(serde_json::from_value::<Public>(invalid_unique).is_err());
+}
+
+#[test]
+fn test_invalid_public_deserialization() {
+    assert!(serde_json::from_str::<Public>("[0, 1, 2, 3]").is_err());
+    assert!(serde_json::from_str::<Public>("\"TPM2B_PUBLIC\"").is_err());
+}
+
+#[test]
+fn test_create_primary_with_deserialized_public() {
+    let mut context = common::create_ctx_with_session();
+    let public = json_round_trip(&common::decryption_key_pub());
+    let _ = context
+        .create_primary(Hierarchy::Owner, &public, None, None, None, None)
+        .expect("Failed to create primary key from the deserialized template");
+}
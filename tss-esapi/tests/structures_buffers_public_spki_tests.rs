@@ -0,0 +1,240 @@
+// Copyright 2021 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+#![cfg(feature = "spki")]
+mod common;
+
+use std::convert::TryFrom;
+use tss_esapi::{
+    attributes::ObjectAttributesBuilder,
+    interface_types::{
+        algorithm::{EccSchemeAlgorithm, HashingAlgorithm, PublicAlgorithm, RsaSchemeAlgorithm},
+        ecc::EccCurve,
+        key_bits::RsaKeyBits,
+        resource_handles::Hierarchy,
+    },
+    structures::{
+        EccParameter, EccPoint, EccScheme, Public, PublicBuilder, PublicEccParametersBuilder,
+        PublicKeyRsa, RsaExponent, RsaScheme,
+    },
+    utils, Error, WrapperErrorKind,
+};
+
+// RSA 2048 bit public key generated with OpenSSL.
+const RSA_MODULUS: [u8; 256] = [
+    0xA3, 0x7C, 0x3C, 0xC9, 0x22, 0x68, 0xF0, 0x54, 0xED, 0x5B, 0x57, 0x7D, 0xF7, 0xCA, 0x64, 0xC5,
+    0xF0, 0x34, 0x42, 0x2C, 0x85, 0xDB, 0x05, 0x75, 0x3B, 0x95, 0x57, 0x28, 0xE8, 0x71, 0xD4, 0xC3,
+    0x8F, 0xE0, 0xD6, 0xAE, 0x0C, 0xDE, 0x5C, 0x9F, 0xE8, 0x8B, 0xB4, 0xE6, 0x02, 0xF8, 0xAA, 0x60,
+    0x20, 0xFF, 0xDD, 0x75, 0x20, 0x40, 0xF3, 0x5F, 0x22, 0x63, 0xE9, 0xEF, 0x9C, 0xA5, 0xC9, 0x7B,
+    0xDA, 0x3C, 0xC6, 0x83, 0x41, 0xAF, 0xFC, 0x70, 0xB7, 0x15, 0x4B, 0x26, 0x23, 0x5B, 0x37, 0x4A,
+    0xE1, 0x02, 0x9E, 0x4E, 0x19, 0xCE, 0xFE, 0xE3, 0xE3, 0xDA, 0xAD, 0x32, 0xFD, 0x16, 0x8D, 0xE3,
+    0xD8, 0xED, 0x0C, 0xAD, 0x00, 0xD9, 0x66, 0xA8, 0xFB, 0x4D, 0xFC, 0x77, 0x90, 0x92, 0xA1, 0xB1,
+    0xB8, 0x9F, 0x83, 0xA3, 0xB7, 0x86, 0x34, 0xB4, 0xE2, 0x86, 0x1E, 0x39, 0x6B, 0x76, 0xB8, 0x48,
+    0x65, 0x87, 0x90, 0x8C, 0x76, 0x7F, 0xC1, 0x46, 0x24, 0xB7, 0xA8, 0xC5, 0xBC, 0x18, 0x92, 0x98,
+    0xD3, 0x26, 0x9D, 0xDB, 0xC9, 0xBF, 0x86, 0x00, 0x19, 0xF5, 0x6C, 0xD5, 0x71, 0x29, 0x0D, 0x8F,
+    0xE6, 0x88, 0x35, 0x12, 0xDC, 0xD3, 0xD8, 0xA5, 0x50, 0xA6, 0x11, 0x42, 0xEF, 0xBF, 0x09, 0x86,
+    0x39, 0x30, 0x11, 0xE4, 0x1F, 0x58, 0xC2, 0x55, 0xF7, 0x51, 0x95, 0xAF, 0x41, 0xDE, 0x3E, 0x28,
+    0x6B, 0xD1, 0x93, 0x28, 0xB6, 0x36, 0x51, 0xCA, 0xB8, 0x5A, 0xF5, 0x20, 0xE6, 0x79, 0xBC, 0xCB,
+    0xC9, 0x47, 0xF7, 0x23, 0x3D, 0xFD, 0x8B, 0x98, 0xBC, 0xFC, 0xB2, 0xEB, 0x01, 0xD4, 0x36, 0xAA,
+    0x86, 0xF4, 0x46, 0x35, 0x55, 0x85, 0x0A, 0xFF, 0xE1, 0x5C, 0xEC, 0x72, 0x25, 0xFA, 0xCC, 0x40,
+    0xC3, 0xA6, 0x44, 0x75, 0x76, 0xBD, 0x4C, 0x7A, 0xB6, 0x11, 0xB8, 0x50, 0xFC, 0xD8, 0xFA, 0x47,
+];
+
+// The `openssl rsa -pubout -outform DER` encoding of the RSA public key.
+const RSA_SPKI_DER: [u8; 294] = [
+    0x30, 0x82, 0x01, 0x22, 0x30, 0x0D, 0x06, 0x09, 0x2A, 0x86, 0x48, 0x86, 0xF7, 0x0D, 0x01, 0x01,
+    0x01, 0x05, 0x00, 0x03, 0x82, 0x01, 0x0F, 0x00, 0x30, 0x82, 0x01, 0x0A, 0x02, 0x82, 0x01, 0x01,
+    0x00, 0xA3, 0x7C, 0x3C, 0xC9, 0x22, 0x68, 0xF0, 0x54, 0xED, 0x5B, 0x57, 0x7D, 0xF7, 0xCA, 0x64,
+    0xC5, 0xF0, 0x34, 0x42, 0x2C, 0x85, 0xDB, 0x05, 0x75, 0x3B, 0x95, 0x57, 0x28, 0xE8, 0x71, 0xD4,
+    0xC3, 0x8F, 0xE0, 0xD6, 0xAE, 0x0C, 0xDE, 0x5C, 0x9F, 0xE8, 0x8B, 0xB4, 0xE6, 0x02, 0xF8, 0xAA,
+    0x60, 0x20, 0xFF, 0xDD, 0x75, 0x20, 0x40, 0xF3, 0x5F, 0x22, 0x63, 0xE9, 0xEF, 0x9C, 0xA5, 0xC9,
+    0x7B, 0xDA, 0x3C, 0xC6, 0x83, 0x41, 0xAF, 0xFC, 0x70, 0xB7, 0x15, 0x4B, 0x26, 0x23, 0x5B, 0x37,
+    0x4A, 0xE1, 0x02, 0x9E, 0x4E, 0x19, 0xCE, 0xFE, 0xE3, 0xE3, 0xDA, 0xAD, 0x32, 0xFD, 0x16, 0x8D,
+    0xE3, 0xD8, 0xED, 0x0C, 0xAD, 0x00, 0xD9, 0x66, 0xA8, 0xFB, 0x4D, 0xFC, 0x77, 0x90, 0x92, 0xA1,
+    0xB1, 0xB8, 0x9F, 0x83, 0xA3, 0xB7, 0x86, 0x34, 0xB4, 0xE2, 0x86, 0x1E, 0x39, 0x6B, 0x76, 0xB8,
+    0x48, 0x65, 0x87, 0x90, 0x8C, 0x76, 0x7F, 0xC1, 0x46, 0x24, 0xB7, 0xA8, 0xC5, 0xBC, 0x18, 0x92,
+    0x98, 0xD3, 0x26, 0x9D, 0xDB, 0xC9, 0xBF, 0x86, 0x00, 0x19, 0xF5, 0x6C, 0xD5, 0x71, 0x29, 0x0D,
+    0x8F, 0xE6, 0x88, 0x35, 0x12, 0xDC, 0xD3, 0xD8, 0xA5, 0x50, 0xA6, 0x11, 0x42, 0xEF, 0xBF, 0x09,
+    0x86, 0x39, 0x30, 0x11, 0xE4, 0x1F, 0x58, 0xC2, 0x55, 0xF7, 0x51, 0x95, 0xAF, 0x41, 0xDE, 0x3E,
+    0x28, 0x6B, 0xD1, 0x93, 0x28, 0xB6, 0x36, 0x51, 0xCA, 0xB8, 0x5A, 0xF5, 0x20, 0xE6, 0x79, 0xBC,
+    0xCB, 0xC9, 0x47, 0xF7, 0x23, 0x3D, 0xFD, 0x8B, 0x98, 0xBC, 0xFC, 0xB2, 0xEB, 0x01, 0xD4, 0x36,
+    0xAA, 0x86, 0xF4, 0x46, 0x35, 0x55, 0x85, 0x0A, 0xFF, 0xE1, 0x5C, 0xEC, 0x72, 0x25, 0xFA, 0xCC,
+    0x40, 0xC3, 0xA6, 0x44, 0x75, 0x76, 0xBD, 0x4C, 0x7A, 0xB6, 0x11, 0xB8, 0x50, 0xFC, 0xD8, 0xFA,
+    0x47, 0x02, 0x03, 0x01, 0x00, 0x01,
+];
+
+// NIST P-256 public point generated with OpenSSL.
+const ECC_POINT_X: [u8; 32] = [
+    0x5E, 0xAE, 0x26, 0x3B, 0xB6, 0x6E, 0xEA, 0xD5, 0x33, 0xAF, 0x3F, 0xFB, 0xB3, 0x3E, 0x3D, 0x86,
+    0x33, 0x12, 0x7C, 0x27, 0xD6, 0x00, 0xC1, 0x33, 0x88, 0x12, 0xB7, 0x94, 0x86, 0xEE, 0xDB, 0x92,
+];
+
+const ECC_POINT_Y: [u8; 32] = [
+    0x56, 0x1F, 0xDC, 0x5F, 0x8B, 0x87, 0x16, 0xEF, 0x44, 0x6B, 0x13, 0x93, 0x33, 0x5B, 0x20, 0x98,
+    0x08, 0x6D, 0x9A, 0x18, 0x09, 0xF5, 0x54, 0x06, 0x15, 0xCF, 0x80, 0x4E, 0xF2, 0xB0, 0xAC, 0x4B,
+];
+
+// The `openssl ec -pubout -outform DER` encoding of the ECC public key.
+const ECC_SPKI_DER: [u8; 91] = [
+    0x30, 0x59, 0x30, 0x13, 0x06, 0x07, 0x2A, 0x86, 0x48, 0xCE, 0x3D, 0x02, 0x01, 0x06, 0x08, 0x2A,
+    0x86, 0x48, 0xCE, 0x3D, 0x03, 0x01, 0x07, 0x03, 0x42, 0x00, 0x04, 0x5E, 0xAE, 0x26, 0x3B, 0xB6,
+    0x6E, 0xEA, 0xD5, 0x33, 0xAF, 0x3F, 0xFB, 0xB3, 0x3E, 0x3D, 0x86, 0x33, 0x12, 0x7C, 0x27, 0xD6,
+    0x00, 0xC1, 0x33, 0x88, 0x12, 0xB7, 0x94, 0x86, 0xEE, 0xDB, 0x92, 0x56, 0x1F, 0xDC, 0x5F, 0x8B,
+    0x87, 0x16, 0xEF, 0x44, 0x6B, 0x13, 0x93, 0x33, 0x5B, 0x20, 0x98, 0x08, 0x6D, 0x9A, 0x18, 0x09,
+    0xF5, 0x54, 0x06, 0x15, 0xCF, 0x80, 0x4E, 0xF2, 0xB0, 0xAC, 0x4B,
+];
+
+fn rsa_public(exponent: RsaExponent) -> Public {
+    utils::create_unrestricted_signing_rsa_public_with_unique(
+        RsaScheme::create(RsaSchemeAlgorithm::RsaSsa, Some(HashingAlgorithm::Sha256))
+            .expect("Failed to create RSA scheme"),
+        RsaKeyBits::Rsa2048,
+        exponent,
+        &PublicKeyRsa::try_from(RSA_MODULUS.to_vec()).expect("Failed to create RSA modulus"),
+    )
+    .expect("Failed to create RSA public structure")
+}
+
+fn ecc_public() -> Public {
+    let object_attributes = ObjectAttributesBuilder::new()
+        .with_fixed_tpm(true)
+        .with_fixed_parent(true)
+        .with_sensitive_data_origin(true)
+        .with_user_with_auth(true)
+        .with_sign_encrypt(true)
+        .build()
+        .expect("Failed to create object attributes");
+    PublicBuilder::new()
+        .with_public_algorithm(PublicAlgorithm::Ecc)
+        .with_name_hashing_algorithm(HashingAlgorithm::Sha256)
+        .with_object_attributes(object_attributes)
+        .with_ecc_parameters(
+            PublicEccParametersBuilder::new_unrestricted_signing_key(
+                EccScheme::create(
+                    EccSchemeAlgorithm::EcDsa,
+                    Some(HashingAlgorithm::Sha256),
+                    None,
+                )
+                .expect("Failed to create ECC scheme"),
+                EccCurve::NistP256,
+            )
+            .build()
+            .expect("Failed to create ECC parameters"),
+        )
+        .with_ecc_unique_identifier(&EccPoint::new(
+            EccParameter::try_from(ECC_POINT_X.to_vec()).expect("Failed to create x coordinate"),
+            EccParameter::try_from(ECC_POINT_Y.to_vec()).expect("Failed to create y coordinate"),
+        ))
+        .build()
+        .expect("Failed to create ECC public structure")
+}
+
+#[test]
+fn test_rsa_to_subject_public_key_info_der() {
+    let public = rsa_public(RsaExponent::default());
+    assert_eq!(
+        RSA_SPKI_DER.to_vec(),
+        public
+            .to_subject_public_key_info_der()
+            .expect("Failed to convert RSA public key")
+    );
+
+    // The zero exponent is the shorthand for the default exponent.
+    let public = rsa_public(RsaExponent::ZERO_EXPONENT);
+    assert_eq!(
+        RSA_SPKI_DER.to_vec(),
+        public
+            .to_subject_public_key_info_der()
+            .expect("Failed to convert RSA public key")
+    );
+}
+
+#[test]
+fn test_rsa_from_subject_public_key_info_der() {
+    let public = Public::from_subject_public_key_info_der(&RSA_SPKI_DER)
+        .expect("Failed to create RSA public key");
+    if let Public::Rsa {
+        parameters, unique, ..
+    } = &public
+    {
+        assert_eq!(RsaKeyBits::Rsa2048, parameters.key_bits());
+        assert_eq!(RsaExponent::default(), parameters.exponent());
+        assert_eq!(RSA_MODULUS.to_vec(), unique.value());
+    } else {
+        panic!("Expected an RSA public key");
+    }
+    assert_eq!(
+        RSA_SPKI_DER.to_vec(),
+        public
+            .to_subject_public_key_info_der()
+            .expect("Failed to convert RSA public key")
+    );
+}
+
+#[test]
+fn test_ecc_to_subject_public_key_info_der() {
+    assert_eq!(
+        ECC_SPKI_DER.to_vec(),
+        ecc_public()
+            .to_subject_public_key_info_der()
+            .expect("Failed to convert ECC public key")
+    );
+}
+
+#[test]
+fn test_ecc_from_subject_public_key_info_der() {
+    let public = Public::from_subject_public_key_info_der(&ECC_SPKI_DER)
+        .expect("Failed to create ECC public key");
+    if let Public::Ecc {
+        parameters, unique, ..
+    } = &public
+    {
+        assert_eq!(EccCurve::NistP256, parameters.ecc_curve());
+        assert_eq!(ECC_POINT_X.to_vec(), unique.x().value());
+        assert_eq!(ECC_POINT_Y.to_vec(), unique.y().value());
+    } else {
+        panic!("Expected an ECC public key");
+    }
+    assert_eq!(
+        ECC_SPKI_DER.to_vec(),
+        public
+            .to_subject_public_key_info_der()
+            .expect("Failed to convert ECC public key")
+    );
+}
+
+#[test]
+fn test_invalid_subject_public_key_info_der() {
+    if let Err(Error::WrapperError(kind)) =
+        Public::from_subject_public_key_info_der(&RSA_SPKI_DER[..100])
+    {
+        assert_eq!(kind, WrapperErrorKind::InvalidParam);
+    } else {
+        panic!("Truncated DER did not fail as expected");
+    }
+}
+
+#[test]
+fn test_tpm_key_subject_public_key_info_der() {
+    let mut context = common::create_ctx_with_session();
+    let public = context
+        .create_primary(
+            Hierarchy::Owner,
+            &common::signing_key_pub(),
+            None,
+            None,
+            None,
+            None,
+        )
+        .expect("Failed to create primary key")
+        .out_public;
+    let der = public
+        .to_subject_public_key_info_der()
+        .expect("Failed to convert the TPM public key");
+    let _ = context
+        .load_external_public(
+            &Public::from_subject_public_key_info_der(&der)
+                .expect("Failed to create public key from DER"),
+            Hierarchy::Owner,
+        )
+        .expect("Failed to load the public key from DER");
+}
@@ -0,0 +1,29 @@
+// Copyright 2021 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use std::convert::TryFrom;
+use tss_esapi::{
+    structures::{Auth, SensitiveCreate, SensitiveData},
+    tss2_esys::{TPM2B_SENSITIVE_CREATE, TPMS_SENSITIVE_CREATE},
+};
+
+#[test]
+fn test_conversions() {
+    let expected_auth = Auth::try_from(vec![1, 2, 3, 4]).unwrap();
+    let expected_data = SensitiveData::try_from(vec![5, 6, 7, 8, 9]).unwrap();
+    let sensitive_create = SensitiveCreate::new(expected_auth.clone(), expected_data.clone());
+    assert_eq!(&expected_auth, sensitive_create.user_auth());
+    assert_eq!(&expected_data, sensitive_create.data());
+
+    let tss_sensitive_create = TPM2B_SENSITIVE_CREATE::from(sensitive_create.clone());
+    assert_eq!(
+        std::mem::size_of::<TPMS_SENSITIVE_CREATE>(),
+        tss_sensitive_create.size as usize
+    );
+    assert_eq!(4, tss_sensitive_create.sensitive.userAuth.size);
+    assert_eq!(5, tss_sensitive_create.sensitive.data.size);
+
+    assert_eq!(
+        sensitive_create,
+        SensitiveCreate::try_from(tss_sensitive_create).unwrap()
+    );
+}
@@ -0,0 +1,78 @@
+// Copyright 2021 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use std::convert::TryFrom;
+use tss_esapi::{
+    constants::tss::{TPM2_ALG_ECC, TPM2_ALG_KEYEDHASH, TPM2_ALG_RSA, TPM2_ALG_SYMCIPHER},
+    structures::{
+        Auth, Digest, EccParameter, PrivateKeyRsa, Sensitive, SensitiveData, SymmetricKey,
+    },
+    tss2_esys::{TPM2B_SENSITIVE, TPMT_SENSITIVE},
+};
+
+fn sensitives() -> Vec<(Sensitive, u16)> {
+    let auth_value = Auth::try_from(vec![1, 2, 3, 4]).unwrap();
+    let seed_value = Digest::try_from(vec![5; 32]).unwrap();
+    vec![
+        (
+            Sensitive::Rsa {
+                auth_value: auth_value.clone(),
+                seed_value: seed_value.clone(),
+                sensitive: PrivateKeyRsa::try_from(vec![6; 128]).unwrap(),
+            },
+            TPM2_ALG_RSA,
+        ),
+        (
+            Sensitive::Ecc {
+                auth_value: auth_value.clone(),
+                seed_value: seed_value.clone(),
+                sensitive: EccParameter::try_from(vec![7; 32]).unwrap(),
+            },
+            TPM2_ALG_ECC,
+        ),
+        (
+            Sensitive::Bits {
+                auth_value: auth_value.clone(),
+                seed_value: seed_value.clone(),
+                sensitive: SensitiveData::try_from(vec![8; 16]).unwrap(),
+            },
+            TPM2_ALG_KEYEDHASH,
+        ),
+        (
+            Sensitive::Symmetric {
+                auth_value,
+                seed_value,
+                sensitive: SymmetricKey::try_from(vec![9; 16]).unwrap(),
+            },
+            TPM2_ALG_SYMCIPHER,
+        ),
+    ]
+}
+
+#[test]
+fn test_conversions() {
+    for (sensitive, expected_type) in sensitives() {
+        assert_eq!(&[1, 2, 3, 4], sensitive.auth_value().value());
+        assert_eq!(&[5; 32], sensitive.seed_value().value());
+
+        let tpmt_sensitive = TPMT_SENSITIVE::from(sensitive.clone());
+        assert_eq!(expected_type, tpmt_sensitive.sensitiveType);
+        assert_eq!(sensitive, Sensitive::try_from(tpmt_sensitive).unwrap());
+
+        let tpm2b_sensitive = TPM2B_SENSITIVE::from(sensitive.clone());
+        assert_eq!(
+            std::mem::size_of::<TPMT_SENSITIVE>(),
+            tpm2b_sensitive.size as usize
+        );
+        assert_eq!(sensitive, Sensitive::try_from(tpm2b_sensitive).unwrap());
+    }
+}
+
+#[test]
+fn test_too_large_private_key_rsa() {
+    let _ = PrivateKeyRsa::try_from(vec![0; PrivateKeyRsa::MAX_SIZE + 1]).unwrap_err();
+}
+
+#[test]
+fn test_too_large_symmetric_key() {
+    let _ = SymmetricKey::try_from(vec![0; SymmetricKey::MAX_SIZE + 1]).unwrap_err();
+}
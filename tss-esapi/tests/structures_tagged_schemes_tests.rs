@@ -0,0 +1,52 @@
+// Copyright 2021 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+mod test_sm2_schemes {
+    use std::convert::TryFrom;
+    use tss_esapi::{
+        constants::tss::{TPM2_ALG_SM2, TPM2_ALG_SM3_256},
+        interface_types::algorithm::{EccSchemeAlgorithm, HashingAlgorithm},
+        structures::{EccScheme, HashScheme, SignatureScheme},
+        tss2_esys::{TPMT_ECC_SCHEME, TPMT_SIG_SCHEME},
+    };
+
+    #[test]
+    fn test_ecc_scheme_conversions() {
+        let ecc_scheme = EccScheme::create(
+            EccSchemeAlgorithm::Sm2,
+            Some(HashingAlgorithm::Sm3_256),
+            None,
+        )
+        .expect("Failed to create SM2 scheme");
+        let tpmt_ecc_scheme = TPMT_ECC_SCHEME::from(ecc_scheme);
+        assert_eq!(TPM2_ALG_SM2, tpmt_ecc_scheme.scheme);
+        assert_eq!(TPM2_ALG_SM3_256, unsafe {
+            tpmt_ecc_scheme.details.sm2.hashAlg
+        });
+
+        match EccScheme::try_from(tpmt_ecc_scheme).expect("Failed to convert SM2 scheme") {
+            EccScheme::Sm2(hash_scheme) => {
+                assert_eq!(HashingAlgorithm::Sm3_256, hash_scheme.hashing_algorithm())
+            }
+            other => panic!("Unexpected ECC scheme {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_signature_scheme_conversions() {
+        let signature_scheme = SignatureScheme::Sm2(HashScheme::new(HashingAlgorithm::Sm3_256));
+        let tpmt_sig_scheme = TPMT_SIG_SCHEME::from(signature_scheme);
+        assert_eq!(TPM2_ALG_SM2, tpmt_sig_scheme.scheme);
+        assert_eq!(TPM2_ALG_SM3_256, unsafe {
+            tpmt_sig_scheme.details.sm2.hashAlg
+        });
+
+        match SignatureScheme::try_from(tpmt_sig_scheme)
+            .expect("Failed to convert SM2 signature scheme")
+        {
+            SignatureScheme::Sm2(hash_scheme) => {
+                assert_eq!(HashingAlgorithm::Sm3_256, hash_scheme.hashing_algorithm())
+            }
+            other => panic!("Unexpected signature scheme {:?}", other),
+        }
+    }
+}
@@ -0,0 +1,34 @@
+// Copyright 2021 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+mod test_sm4 {
+    use std::convert::TryFrom;
+    use tss_esapi::{
+        constants::tss::{TPM2_ALG_CFB, TPM2_ALG_SM4},
+        interface_types::{algorithm::SymmetricMode, key_bits::Sm4KeyBits},
+        structures::SymmetricDefinition,
+        tss2_esys::TPMT_SYM_DEF,
+    };
+
+    #[test]
+    fn test_symmetric_definition_conversions() {
+        let symmetric_definition = SymmetricDefinition::Sm4 {
+            key_bits: Sm4KeyBits::Sm4_128,
+            mode: SymmetricMode::Cfb,
+        };
+        let tpmt_sym_def = TPMT_SYM_DEF::try_from(symmetric_definition)
+            .expect("Failed to convert SM4 symmetric definition");
+        assert_eq!(TPM2_ALG_SM4, tpmt_sym_def.algorithm);
+        assert_eq!(128, unsafe { tpmt_sym_def.keyBits.sm4 });
+        assert_eq!(TPM2_ALG_CFB, unsafe { tpmt_sym_def.mode.sm4 });
+
+        match SymmetricDefinition::try_from(tpmt_sym_def)
+            .expect("Failed to convert SM4 symmetric definition")
+        {
+            SymmetricDefinition::Sm4 { key_bits, mode } => {
+                assert_eq!(Sm4KeyBits::Sm4_128, key_bits);
+                assert_eq!(SymmetricMode::Cfb, mode);
+            }
+            other => panic!("Unexpected symmetric definition {:?}", other),
+        }
+    }
+}
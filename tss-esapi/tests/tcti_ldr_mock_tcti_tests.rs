@@ -0,0 +1,59 @@
+// Copyright 2021 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use std::convert::TryFrom;
+use std::ffi::CString;
+use tss_esapi::{
+    constants::tss::{TPM2_CC_GetRandom, TPM2_RC_SUCCESS, TPM2_ST_NO_SESSIONS},
+    tcti_ldr::{MockTcti, TctiNameConf},
+    Context, Error, WrapperErrorKind,
+};
+
+#[test]
+fn test_get_random() {
+    let mock_tcti = MockTcti::new();
+    let random_bytes = [0x5a; 16];
+    // Marshalled TPM2_GetRandom response: header, followed by the TPM2B_DIGEST.
+    let mut response = Vec::new();
+    response.extend_from_slice(&TPM2_ST_NO_SESSIONS.to_be_bytes());
+    response.extend_from_slice(&28u32.to_be_bytes());
+    response.extend_from_slice(&TPM2_RC_SUCCESS.to_be_bytes());
+    response.extend_from_slice(&16u16.to_be_bytes());
+    response.extend_from_slice(&random_bytes);
+    mock_tcti.push_response(response);
+
+    let mut context =
+        Context::new(TctiNameConf::Mock(mock_tcti.clone())).expect("Failed to create context");
+    let random = context
+        .get_random(16)
+        .expect("Failed to get random bytes through the mock TCTI");
+    assert_eq!(random.value(), &random_bytes);
+
+    let commands = mock_tcti.commands();
+    assert_eq!(commands.len(), 1);
+    let command = &commands[0];
+    assert_eq!(command.len(), 12);
+    assert_eq!(command[0..2], TPM2_ST_NO_SESSIONS.to_be_bytes());
+    assert_eq!(command[2..6], 12u32.to_be_bytes());
+    assert_eq!(command[6..10], TPM2_CC_GetRandom.to_be_bytes());
+    assert_eq!(command[10..12], 16u16.to_be_bytes());
+}
+
+#[test]
+fn test_missing_response() {
+    let mock_tcti = MockTcti::new();
+    let mut context =
+        Context::new(TctiNameConf::Mock(mock_tcti.clone())).expect("Failed to create context");
+    let _ = context
+        .get_random(16)
+        .expect_err("Getting random bytes without a response should fail");
+    assert_eq!(mock_tcti.commands().len(), 1);
+}
+
+#[test]
+fn test_mock_has_no_tcti_name() {
+    if let Err(Error::WrapperError(kind)) = CString::try_from(TctiNameConf::Mock(MockTcti::new())) {
+        assert_eq!(kind, WrapperErrorKind::InvalidParam);
+    } else {
+        panic!("The mock TCTI should not be convertible into a TCTI name");
+    }
+}
@@ -0,0 +1,94 @@
+// Copyright 2021 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+mod common;
+
+use std::convert::TryFrom;
+use tss_esapi::{
+    constants::StructureTag,
+    structures::{Attest, Private, Public},
+    traits::{Marshall, UnMarshall},
+    Error, WrapperErrorKind,
+};
+
+/// Returns a marshalled TPMS_ATTEST of a session audit attestation.
+fn session_audit_attest() -> Vec<u8> {
+    let mut attest = Vec::new();
+    // magic
+    attest.extend_from_slice(&[0xff, 0x54, 0x43, 0x47]);
+    // type
+    attest.extend_from_slice(&[0x80, 0x16]);
+    // qualifiedSigner
+    attest.extend_from_slice(&[0x00, 0x04, 0x40, 0x00, 0x00, 0x01]);
+    // extraData
+    attest.extend_from_slice(&[0x00, 0x04, 0x01, 0x02, 0x03, 0x04]);
+    // clockInfo: clock, resetCount, restartCount and safe
+    attest.extend_from_slice(&0x1000u64.to_be_bytes());
+    attest.extend_from_slice(&1u32.to_be_bytes());
+    attest.extend_from_slice(&0u32.to_be_bytes());
+    attest.push(0x01);
+    // firmwareVersion
+    attest.extend_from_slice(&0x0001_0002_0003_0004u64.to_be_bytes());
+    // exclusiveSession
+    attest.push(0x01);
+    // sessionDigest
+    attest.extend_from_slice(&[0x00, 0x20]);
+    attest.extend_from_slice(&[0xaa; 32]);
+    attest
+}
+
+#[test]
+fn test_public_marshall_round_trip() {
+    let public = common::signing_key_pub();
+    let marshalled_public = public.marshall().expect("Failed to marshall Public");
+    let unmarshalled_public =
+        Public::unmarshall(&marshalled_public).expect("Failed to unmarshall Public");
+    assert_eq!(
+        marshalled_public,
+        unmarshalled_public
+            .marshall()
+            .expect("Failed to marshall the unmarshalled Public")
+    );
+}
+
+#[test]
+fn test_private_marshall_round_trip() {
+    let private = Private::try_from(vec![0xff; 64]).expect("Failed to create Private");
+    let marshalled_private = private.marshall().expect("Failed to marshall Private");
+    assert_eq!(66, marshalled_private.len());
+    assert_eq!(&[0x00, 0x40], &marshalled_private[..2]);
+    assert_eq!(
+        private,
+        Private::unmarshall(&marshalled_private).expect("Failed to unmarshall Private")
+    );
+}
+
+#[test]
+fn test_attest_marshall_round_trip() {
+    let attest = Attest::unmarshall(&session_audit_attest()).expect("Failed to unmarshall Attest");
+    assert_eq!(StructureTag::AttestSessionAudit, attest.attestation_type());
+    assert_eq!(&[0x01, 0x02, 0x03, 0x04], attest.extra_data().value());
+    assert_eq!(0x0001_0002_0003_0004, attest.firmware_version());
+    assert_eq!(
+        session_audit_attest(),
+        attest.marshall().expect("Failed to marshall Attest")
+    );
+}
+
+#[test]
+fn test_unmarshall_trailing_data() {
+    let mut marshalled_private = Private::try_from(vec![0xff; 16])
+        .expect("Failed to create Private")
+        .marshall()
+        .expect("Failed to marshall Private");
+    marshalled_private.push(0x00);
+    if let Err(Error::WrapperError(kind)) = Private::unmarshall(&marshalled_private) {
+        assert_eq!(kind, WrapperErrorKind::WrongParamSize);
+    } else {
+        panic!("Unmarshalling data with trailing bytes did not fail as expected");
+    }
+}
+
+#[test]
+fn test_unmarshall_truncated_data() {
+    assert!(Attest::unmarshall(&session_audit_attest()[..40]).is_err());
+}
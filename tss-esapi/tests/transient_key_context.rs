@@ -564,3 +564,72 @@ fn ctx_migration_test() {
         panic!("Got wrong type of key from TPM");
     }
 }
+
+#[cfg(feature = "der-signature")]
+#[test]
+fn sign_der_rsa() {
+    use rsa::{BigUint, Pkcs1v15Sign, RsaPublicKey};
+
+    let mut ctx = create_ctx();
+    let key_params = KeyParams::Rsa {
+        size: RsaKeyBits::Rsa2048,
+        scheme: RsaScheme::create(RsaSchemeAlgorithm::RsaSsa, Some(HashingAlgorithm::Sha256))
+            .expect("Failed to create RSA scheme"),
+        pub_exponent: RsaExponent::default(),
+    };
+    let (key, auth) = ctx.create_key(key_params, 16).unwrap();
+    let signature = ctx
+        .sign_der(key.clone(), key_params, auth, &HASH)
+        .expect("Failed to create DER signature");
+
+    let modulus = match key.public() {
+        PublicKey::Rsa(modulus) => modulus,
+        _ => panic!("Got wrong type of key"),
+    };
+    let public_key = RsaPublicKey::new(
+        BigUint::from_bytes_be(modulus),
+        BigUint::from(RsaExponent::default().value()),
+    )
+    .expect("Failed to create RSA public key");
+    public_key
+        .verify(Pkcs1v15Sign::new::<sha2::Sha256>(), &HASH, &signature)
+        .expect("Failed to verify the DER signature");
+}
+
+#[cfg(feature = "der-signature")]
+#[test]
+fn sign_der_ecc() {
+    use p256::ecdsa::{signature::hazmat::PrehashVerifier, Signature, VerifyingKey};
+
+    let mut ctx = create_ctx();
+    let key_params = KeyParams::Ecc {
+        curve: EccCurve::NistP256,
+        scheme: EccScheme::create(
+            EccSchemeAlgorithm::EcDsa,
+            Some(HashingAlgorithm::Sha256),
+            None,
+        )
+        .expect("Failed to create ecc scheme"),
+    };
+    let (key, auth) = ctx.create_key(key_params, 16).unwrap();
+    let signature = ctx
+        .sign_der(key.clone(), key_params, auth, &HASH)
+        .expect("Failed to create DER signature");
+
+    let mut sec1_point = vec![0x04];
+    match key.public() {
+        PublicKey::Ecc { x, y } => {
+            for coordinate in &[x, y] {
+                sec1_point.resize(sec1_point.len() + 32 - coordinate.len(), 0);
+                sec1_point.extend_from_slice(coordinate);
+            }
+        }
+        _ => panic!("Got wrong type of key"),
+    }
+    let verifying_key =
+        VerifyingKey::from_sec1_bytes(&sec1_point).expect("Failed to create ECC public key");
+    let signature = Signature::from_der(&signature).expect("Failed to parse the DER signature");
+    verifying_key
+        .verify_prehash(&HASH, &signature)
+        .expect("Failed to verify the DER signature");
+}